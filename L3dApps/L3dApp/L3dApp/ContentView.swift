@@ -179,23 +179,11 @@ struct L3DSceneKitView: NSViewRepresentable {
         luminaireNode.name = "luminaire"
 
         let parts = l3dFile.getParts()
-        let assets = l3dFile.getAssets()
-
-        for part in parts {
-            // Find matching OBJ asset
-            let partFilename = (part.path as NSString).lastPathComponent.lowercased()
-            if let asset = assets.first(where: {
-                let assetFilename = ($0.name as NSString).lastPathComponent.lowercased()
-                return assetFilename == partFilename ||
-                       $0.name.lowercased().contains(partFilename) ||
-                       part.path.lowercased().contains(assetFilename)
-            }) {
-                let objData = Data(asset.content)
-                if let partNode = OBJLoader.loadOBJ(from: objData, name: part.name) {
-                    // Apply transformation matrix
-                    OBJLoader.applyTransform(partNode, matrix: part.transform)
-                    luminaireNode.addChildNode(partNode)
-                }
+
+        // Meshes are parsed by l3d-ffi and placed with the part transforms
+        for mesh in l3dFile.getMeshes(worldSpace: false) {
+            if let partNode = MeshLoader.loadMesh(mesh) {
+                luminaireNode.addChildNode(partNode)
             }
         }
 
@@ -205,7 +193,7 @@ struct L3DSceneKitView: NSViewRepresentable {
                 let box = SCNBox(width: 0.05, height: 0.05, length: 0.05, chamferRadius: 0.005)
                 box.firstMaterial?.diffuse.contents = NSColor.systemBlue
                 let node = SCNNode(geometry: box)
-                OBJLoader.applyTransform(node, matrix: part.transform)
+                MeshLoader.applyTransform(node, matrix: part.transform)
                 luminaireNode.addChildNode(node)
             }
         }
@@ -271,19 +259,9 @@ struct L3DSceneKitView: UIViewRepresentable {
         let scene = SCNScene()
         let luminaireNode = SCNNode()
 
-        let parts = l3dFile.getParts()
-        let assets = l3dFile.getAssets()
-
-        for part in parts {
-            let partFilename = (part.path as NSString).lastPathComponent.lowercased()
-            if let asset = assets.first(where: {
-                ($0.name as NSString).lastPathComponent.lowercased() == partFilename
-            }) {
-                let objData = Data(asset.content)
-                if let partNode = OBJLoader.loadOBJ(from: objData, name: part.name) {
-                    OBJLoader.applyTransform(partNode, matrix: part.transform)
-                    luminaireNode.addChildNode(partNode)
-                }
+        for mesh in l3dFile.getMeshes(worldSpace: false) {
+            if let partNode = MeshLoader.loadMesh(mesh) {
+                luminaireNode.addChildNode(partNode)
             }
         }
 
@@ -0,0 +1,79 @@
+import SceneKit
+import Foundation
+import L3dKit
+
+/// Builds SceneKit nodes from the meshes parsed by l3d-ffi
+class MeshLoader {
+    static func loadMesh(_ mesh: L3dMesh) -> SCNNode? {
+        let vertexCount = mesh.positions.count / 3
+        guard vertexCount > 0, !mesh.indices.isEmpty else { return nil }
+
+        // Corners without a normal in the OBJ file get their face normal
+        var sources = [
+            floatSource(mesh.positions, semantic: .vertex, componentsPerVector: 3),
+            floatSource(mesh.normals, semantic: .normal, componentsPerVector: 3)
+        ]
+
+        if mesh.uvs.contains(where: { $0 != 0 }) {
+            sources.append(floatSource(mesh.uvs, semantic: .texcoord, componentsPerVector: 2))
+        }
+
+        let indexData = mesh.indices.withUnsafeBufferPointer { Data(buffer: $0) }
+        let element = SCNGeometryElement(
+            data: indexData,
+            primitiveType: .triangles,
+            primitiveCount: mesh.indices.count / 3,
+            bytesPerIndex: MemoryLayout<UInt32>.size
+        )
+
+        let geometry = SCNGeometry(sources: sources, elements: [element])
+
+        let material = SCNMaterial()
+        #if os(macOS)
+        material.diffuse.contents = NSColor(red: 0.8, green: 0.8, blue: 0.8, alpha: 1.0)
+        #else
+        material.diffuse.contents = UIColor(red: 0.8, green: 0.8, blue: 0.8, alpha: 1.0)
+        #endif
+        material.lightingModel = .physicallyBased
+        material.roughness.contents = 0.5
+        material.metalness.contents = 0.1
+        material.isDoubleSided = true
+        geometry.materials = [material]
+
+        let node = SCNNode(geometry: geometry)
+        node.name = mesh.name
+        applyTransform(node, matrix: mesh.transform)
+        return node
+    }
+
+    private static func floatSource(
+        _ values: [Float],
+        semantic: SCNGeometrySource.Semantic,
+        componentsPerVector: Int
+    ) -> SCNGeometrySource {
+        let data = values.withUnsafeBufferPointer { Data(buffer: $0) }
+        return SCNGeometrySource(
+            data: data,
+            semantic: semantic,
+            vectorCount: values.count / componentsPerVector,
+            usesFloatComponents: true,
+            componentsPerVector: componentsPerVector,
+            bytesPerComponent: MemoryLayout<Float>.size,
+            dataOffset: 0,
+            dataStride: MemoryLayout<Float>.size * componentsPerVector
+        )
+    }
+
+    static func applyTransform(_ node: SCNNode, matrix: [Float]) {
+        guard matrix.count == 16 else { return }
+
+        let m = SCNMatrix4(
+            m11: CGFloat(matrix[0]), m12: CGFloat(matrix[1]), m13: CGFloat(matrix[2]), m14: CGFloat(matrix[3]),
+            m21: CGFloat(matrix[4]), m22: CGFloat(matrix[5]), m23: CGFloat(matrix[6]), m24: CGFloat(matrix[7]),
+            m31: CGFloat(matrix[8]), m32: CGFloat(matrix[9]), m33: CGFloat(matrix[10]), m34: CGFloat(matrix[11]),
+            m41: CGFloat(matrix[12]), m42: CGFloat(matrix[13]), m43: CGFloat(matrix[14]), m44: CGFloat(matrix[15])
+        )
+
+        node.transform = m
+    }
+}
@@ -0,0 +1,10 @@
+fn main() {
+    // The C test program is compiled for the same target as the library
+    for var in ["TARGET", "HOST"] {
+        println!(
+            "cargo:rustc-env=L3D_CAPI_{}={}",
+            var,
+            std::env::var(var).unwrap()
+        );
+    }
+}
@@ -0,0 +1,159 @@
+/* L3D luminaire file parser - C API of l3d_rs. Generated by cbindgen, do not edit. */
+
+#ifndef L3D_H
+#define L3D_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A parsed L3D file
+ *
+ * Created by [`l3d_parse`] or [`l3d_open`] and released with [`l3d_free`].
+ */
+typedef struct L3dHandle L3dHandle;
+
+/**
+ * A geometry part with its world transformation
+ *
+ * The strings are owned by the handle the part was read from.
+ */
+typedef struct {
+  /**
+   * Part name from structure.xml
+   */
+  const char *name;
+  /**
+   * Path to the OBJ file within the archive, empty if the geometry is
+   * not defined
+   */
+  const char *path;
+  /**
+   * 4x4 world matrix including the unit scale, column-major
+   */
+  float matrix[16];
+  /**
+   * The same matrix in double precision
+   */
+  double matrix_f64[16];
+} L3dPart;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Version of the library as a static string
+ */
+const char *l3d_version(void);
+
+/**
+ * Description of the last failed call on this thread, or `NULL`
+ *
+ * The string stays valid until the next failing call on the same thread.
+ */
+const char *l3d_last_error(void);
+
+/**
+ * Parse an L3D archive from memory
+ *
+ * Returns `NULL` if the data is not a readable L3D file.
+ *
+ * # Safety
+ *
+ * `data` must point to `len` readable bytes. It is not used after the
+ * call returns.
+ */
+L3dHandle *l3d_parse(const uint8_t *data, size_t len);
+
+/**
+ * Parse an L3D archive from a file
+ *
+ * Returns `NULL` if the file cannot be read or is not a readable L3D file.
+ *
+ * # Safety
+ *
+ * `path` must be a NUL-terminated UTF-8 string.
+ */
+L3dHandle *l3d_open(const char *path);
+
+/**
+ * Release a handle and everything returned for it
+ *
+ * Does nothing for `NULL`.
+ *
+ * # Safety
+ *
+ * `handle` must be `NULL` or a handle from [`l3d_parse`] or [`l3d_open`]
+ * that has not been freed yet.
+ */
+void l3d_free(L3dHandle *handle);
+
+/**
+ * Number of geometry parts
+ *
+ * # Safety
+ *
+ * `handle` must be a valid handle.
+ */
+size_t l3d_part_count(const L3dHandle *handle);
+
+/**
+ * Read the part at `index` into `out`
+ *
+ * Returns `false` if `index` is out of range.
+ *
+ * # Safety
+ *
+ * `handle` must be a valid handle and `out` must point to writable memory
+ * for an [`L3dPart`].
+ */
+bool l3d_get_part(const L3dHandle *handle, size_t index, L3dPart *out);
+
+/**
+ * The raw structure.xml
+ *
+ * # Safety
+ *
+ * `handle` must be a valid handle.
+ */
+const char *l3d_structure_xml(const L3dHandle *handle);
+
+/**
+ * Number of asset files (OBJ files, textures, ...)
+ *
+ * # Safety
+ *
+ * `handle` must be a valid handle.
+ */
+size_t l3d_asset_count(const L3dHandle *handle);
+
+/**
+ * Name of the asset at `index`, or `NULL` if `index` is out of range
+ *
+ * # Safety
+ *
+ * `handle` must be a valid handle.
+ */
+const char *l3d_asset_name(const L3dHandle *handle, size_t index);
+
+/**
+ * Contents of the asset called `name`, e.g. a part's `path`
+ *
+ * Stores the size in `len` and returns `NULL` if there is no such asset.
+ *
+ * # Safety
+ *
+ * `handle` must be a valid handle, `name` a NUL-terminated UTF-8 string
+ * and `len` must point to writable memory for a `size_t`.
+ */
+const uint8_t *l3d_asset_data(const L3dHandle *handle, const char *name, size_t *len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* L3D_H */
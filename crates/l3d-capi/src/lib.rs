@@ -0,0 +1,304 @@
+//! # l3d-capi
+//!
+//! C API for l3d_rs - L3D luminaire file format parser.
+//!
+//! For C and C++ programs that cannot use the UniFFI bindings: parse an
+//! archive into an opaque [`L3dHandle`], iterate its parts with their world
+//! matrices and look up assets. The header is `include/l3d.h`, generated by
+//! cbindgen from this file.
+//!
+//! ## Conventions
+//!
+//! - Functions that can fail return `NULL` or `false`; [`l3d_last_error`]
+//!   then describes the failure.
+//! - Strings and buffers returned for a handle are owned by it and stay
+//!   valid until [`l3d_free`]. Nothing returned by this API needs to be
+//!   freed except the handle itself.
+//! - Matrices are column-major, in meters and in the L3D coordinate system
+//!   (Z-up, right-handed).
+//!
+//! ## Usage
+//!
+//! ```c
+//! #include "l3d.h"
+//!
+//! L3dHandle *l3d = l3d_parse(data, size);
+//! if (!l3d) {
+//!     fprintf(stderr, "%s\n", l3d_last_error());
+//!     return 1;
+//! }
+//! for (size_t i = 0; i < l3d_part_count(l3d); i++) {
+//!     L3dPart part;
+//!     l3d_get_part(l3d, i, &part);
+//!     printf("%s: %s at %f %f %f\n", part.name, part.path,
+//!            part.matrix[12], part.matrix[13], part.matrix[14]);
+//! }
+//! l3d_free(l3d);
+//! ```
+
+use l3d_rs::{try_from_buffer_with_options, BuildOptions, L3d};
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+
+thread_local! {
+    /// Message of the last failed call on this thread
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// A parsed L3D file
+///
+/// Created by [`l3d_parse`] or [`l3d_open`] and released with [`l3d_free`].
+pub struct L3dHandle {
+    l3d: L3d,
+    part_names: Vec<CString>,
+    part_paths: Vec<CString>,
+    asset_names: Vec<CString>,
+    structure: CString,
+}
+
+/// A geometry part with its world transformation
+///
+/// The strings are owned by the handle the part was read from.
+#[repr(C)]
+pub struct L3dPart {
+    /// Part name from structure.xml
+    pub name: *const c_char,
+    /// Path to the OBJ file within the archive, empty if the geometry is
+    /// not defined
+    pub path: *const c_char,
+    /// 4x4 world matrix including the unit scale, column-major
+    pub matrix: [f32; 16],
+    /// The same matrix in double precision
+    pub matrix_f64: [f64; 16],
+}
+
+impl L3dHandle {
+    fn new(l3d: L3d) -> Self {
+        let parts = &l3d.model.parts;
+        Self {
+            part_names: parts.iter().map(|p| c_string(&p.name)).collect(),
+            part_paths: parts.iter().map(|p| c_string(&p.path)).collect(),
+            asset_names: l3d.file.assets.iter().map(|a| c_string(&a.name)).collect(),
+            structure: c_string(&l3d.file.structure),
+            l3d,
+        }
+    }
+}
+
+/// Version of the library as a static string
+#[no_mangle]
+pub extern "C" fn l3d_version() -> *const c_char {
+    concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr().cast()
+}
+
+/// Description of the last failed call on this thread, or `NULL`
+///
+/// The string stays valid until the next failing call on the same thread.
+#[no_mangle]
+pub extern "C" fn l3d_last_error() -> *const c_char {
+    LAST_ERROR.with(|e| e.borrow().as_ref().map_or(ptr::null(), |e| e.as_ptr()))
+}
+
+/// Parse an L3D archive from memory
+///
+/// Returns `NULL` if the data is not a readable L3D file.
+///
+/// # Safety
+///
+/// `data` must point to `len` readable bytes. It is not used after the
+/// call returns.
+#[no_mangle]
+pub unsafe extern "C" fn l3d_parse(data: *const u8, len: usize) -> *mut L3dHandle {
+    if data.is_null() {
+        set_error("data is NULL");
+        return ptr::null_mut();
+    }
+    parse(std::slice::from_raw_parts(data, len))
+}
+
+/// Parse an L3D archive from a file
+///
+/// Returns `NULL` if the file cannot be read or is not a readable L3D file.
+///
+/// # Safety
+///
+/// `path` must be a NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn l3d_open(path: *const c_char) -> *mut L3dHandle {
+    let Some(path) = str_arg(path, "path") else {
+        return ptr::null_mut();
+    };
+    match std::fs::read(path) {
+        Ok(data) => parse(&data),
+        Err(e) => {
+            set_error(&format!("failed to read {}: {}", path, e));
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Release a handle and everything returned for it
+///
+/// Does nothing for `NULL`.
+///
+/// # Safety
+///
+/// `handle` must be `NULL` or a handle from [`l3d_parse`] or [`l3d_open`]
+/// that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn l3d_free(handle: *mut L3dHandle) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+/// Number of geometry parts
+///
+/// # Safety
+///
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn l3d_part_count(handle: *const L3dHandle) -> usize {
+    (&*handle).l3d.model.parts.len()
+}
+
+/// Read the part at `index` into `out`
+///
+/// Returns `false` if `index` is out of range.
+///
+/// # Safety
+///
+/// `handle` must be a valid handle and `out` must point to writable memory
+/// for an [`L3dPart`].
+#[no_mangle]
+pub unsafe extern "C" fn l3d_get_part(
+    handle: *const L3dHandle,
+    index: usize,
+    out: *mut L3dPart,
+) -> bool {
+    let handle = &*handle;
+    let Some(part) = handle.l3d.model.parts.get(index) else {
+        set_error(&format!("part index {} out of range", index));
+        return false;
+    };
+    out.write(L3dPart {
+        name: handle.part_names[index].as_ptr(),
+        path: handle.part_paths[index].as_ptr(),
+        matrix: part.mat,
+        matrix_f64: part.world_matrix_f64(),
+    });
+    true
+}
+
+/// The raw structure.xml
+///
+/// # Safety
+///
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn l3d_structure_xml(handle: *const L3dHandle) -> *const c_char {
+    (&*handle).structure.as_ptr()
+}
+
+/// Number of asset files (OBJ files, textures, ...)
+///
+/// # Safety
+///
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn l3d_asset_count(handle: *const L3dHandle) -> usize {
+    (&*handle).asset_names.len()
+}
+
+/// Name of the asset at `index`, or `NULL` if `index` is out of range
+///
+/// # Safety
+///
+/// `handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn l3d_asset_name(handle: *const L3dHandle, index: usize) -> *const c_char {
+    match (&*handle).asset_names.get(index) {
+        Some(name) => name.as_ptr(),
+        None => {
+            set_error(&format!("asset index {} out of range", index));
+            ptr::null()
+        }
+    }
+}
+
+/// Contents of the asset called `name`, e.g. a part's `path`
+///
+/// Stores the size in `len` and returns `NULL` if there is no such asset.
+///
+/// # Safety
+///
+/// `handle` must be a valid handle, `name` a NUL-terminated UTF-8 string
+/// and `len` must point to writable memory for a `size_t`.
+#[no_mangle]
+pub unsafe extern "C" fn l3d_asset_data(
+    handle: *const L3dHandle,
+    name: *const c_char,
+    len: *mut usize,
+) -> *const u8 {
+    len.write(0);
+    let Some(name) = str_arg(name, "name") else {
+        return ptr::null();
+    };
+    match (&*handle).l3d.asset(name) {
+        Some(asset) => {
+            len.write(asset.content.len());
+            asset.content.as_ptr()
+        }
+        None => {
+            set_error(&format!("no asset named {:?}", name));
+            ptr::null()
+        }
+    }
+}
+
+fn parse(data: &[u8]) -> *mut L3dHandle {
+    let options = BuildOptions {
+        double_precision: true,
+        ..Default::default()
+    };
+    // Never unwind into C, whatever the archive contains
+    match catch_unwind(AssertUnwindSafe(|| {
+        try_from_buffer_with_options(data, &options)
+    })) {
+        Ok(Ok(l3d)) => Box::into_raw(Box::new(L3dHandle::new(l3d))),
+        Ok(Err(e)) => {
+            set_error(&format!("{:#}", e));
+            ptr::null_mut()
+        }
+        Err(_) => {
+            set_error("internal error while parsing");
+            ptr::null_mut()
+        }
+    }
+}
+
+fn set_error(message: &str) {
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(c_string(message)));
+}
+
+/// Convert to a C string, dropping interior NUL bytes
+fn c_string(s: &str) -> CString {
+    CString::new(s.replace('\0', "")).unwrap_or_default()
+}
+
+/// Borrow a string argument, recording an error if it is `NULL` or not UTF-8
+unsafe fn str_arg<'a>(s: *const c_char, what: &str) -> Option<&'a str> {
+    if s.is_null() {
+        set_error(&format!("{} is NULL", what));
+        return None;
+    }
+    match CStr::from_ptr(s).to_str() {
+        Ok(s) => Some(s),
+        Err(_) => {
+            set_error(&format!("{} is not valid UTF-8", what));
+            None
+        }
+    }
+}
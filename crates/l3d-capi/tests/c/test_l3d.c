@@ -0,0 +1,62 @@
+/* Exercises the C API against the test data of l3d_rs. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "l3d.h"
+
+#define CHECK(cond)                                                      \
+    do {                                                                 \
+        if (!(cond)) {                                                   \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,       \
+                    __LINE__, #cond);                                    \
+            return 1;                                                    \
+        }                                                                \
+    } while (0)
+
+int main(int argc, char **argv) {
+    CHECK(argc == 2);
+    CHECK(strlen(l3d_version()) > 0);
+
+    /* Errors are reported through l3d_last_error */
+    const uint8_t garbage[] = {1, 2, 3, 4};
+    CHECK(l3d_parse(garbage, sizeof garbage) == NULL);
+    CHECK(l3d_last_error() != NULL);
+    CHECK(l3d_open("does-not-exist.l3d") == NULL);
+    CHECK(strstr(l3d_last_error(), "does-not-exist.l3d") != NULL);
+    l3d_free(NULL);
+
+    L3dHandle *l3d = l3d_open(argv[1]);
+    if (!l3d) {
+        fprintf(stderr, "%s\n", l3d_last_error());
+        return 1;
+    }
+    CHECK(strstr(l3d_structure_xml(l3d), "<Luminaire") != NULL);
+
+    /* minimal.l3d: one cube in millimeters */
+    CHECK(l3d_part_count(l3d) == 1);
+    L3dPart part;
+    CHECK(l3d_get_part(l3d, 0, &part));
+    CHECK(strcmp(part.name, "body") == 0);
+    CHECK(strcmp(part.path, "geom_1/cube.obj") == 0);
+    CHECK(part.matrix[0] == 0.001f && part.matrix[15] == 1.0f);
+    CHECK(part.matrix_f64[0] == 0.001);
+    CHECK(!l3d_get_part(l3d, 1, &part));
+
+    /* Every part's geometry is an asset */
+    size_t len = 0;
+    const uint8_t *obj = l3d_asset_data(l3d, part.path, &len);
+    CHECK(obj != NULL && len > 0);
+    CHECK(l3d_asset_data(l3d, "missing.obj", &len) == NULL && len == 0);
+
+    int found = 0;
+    for (size_t i = 0; i < l3d_asset_count(l3d); i++) {
+        found |= strcmp(l3d_asset_name(l3d, i), part.path) == 0;
+    }
+    CHECK(found);
+    CHECK(l3d_asset_name(l3d, l3d_asset_count(l3d)) == NULL);
+
+    l3d_free(l3d);
+    printf("ok\n");
+    return 0;
+}
@@ -0,0 +1,53 @@
+//! Compiles and runs `tests/c/test_l3d.c` against the shared library
+
+#![cfg(unix)]
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Directory with the `cdylib` built for this test run
+fn library_dir() -> PathBuf {
+    // The library is built next to the test binary, target/<profile>/deps
+    let exe = std::env::current_exe().unwrap();
+    exe.parent().unwrap().to_path_buf()
+}
+
+#[test]
+fn c_program() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let lib_dir = library_dir();
+    let out = std::env::temp_dir().join(format!("l3d_capi_test_{}", std::process::id()));
+
+    let compiler = cc::Build::new()
+        .target(env!("L3D_CAPI_TARGET"))
+        .host(env!("L3D_CAPI_HOST"))
+        .opt_level(0)
+        .cargo_metadata(false)
+        .get_compiler();
+    let status = compiler
+        .to_command()
+        .arg(dir.join("tests/c/test_l3d.c"))
+        .arg("-I")
+        .arg(dir.join("include"))
+        .arg("-o")
+        .arg(&out)
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg("-ll3d_capi")
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .status()
+        .expect("failed to run the C compiler");
+    assert!(status.success(), "compiling test_l3d.c failed");
+
+    let output = Command::new(&out)
+        .arg(dir.join("../l3d_rs/tests/data/minimal.l3d"))
+        .output()
+        .unwrap();
+    std::fs::remove_file(&out).ok();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "ok\n");
+}
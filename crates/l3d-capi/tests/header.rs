@@ -0,0 +1,30 @@
+//! Keeps `include/l3d.h` in sync with the exported functions
+
+use std::path::Path;
+
+#[test]
+fn header_is_up_to_date() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let config = cbindgen::Config::from_file(dir.join("cbindgen.toml")).unwrap();
+    let mut generated = Vec::new();
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(dir.join("src/lib.rs"))
+        .generate()
+        .expect("failed to generate the header")
+        .write(&mut generated);
+
+    let path = dir.join("include/l3d.h");
+    if std::env::var_os("L3D_UPDATE_HEADER").is_some() {
+        std::fs::write(&path, &generated).unwrap();
+    } else {
+        assert_eq!(
+            // Checkouts may have CRLF line endings
+            std::fs::read_to_string(&path)
+                .unwrap_or_default()
+                .replace("\r\n", "\n"),
+            String::from_utf8(generated).unwrap(),
+            "include/l3d.h is out of date, run `L3D_UPDATE_HEADER=1 cargo test -p l3d-capi`"
+        );
+    }
+}
@@ -8,6 +8,7 @@ type LoadResult = Result<
     (
         Vec<Model<PhysicalMaterial>>,
         Option<(Vec3, Vec3)>,
+        l3d_rs::PickScene,
         LoadedModelInfo,
     ),
     String,
@@ -28,6 +29,8 @@ pub struct SceneModel {
     pub id: usize,
     pub meshes: Vec<Model<PhysicalMaterial>>,
     pub bounds: Option<(Vec3, Vec3)>,
+    /// Triangles of all parts for click picking
+    pub picker: l3d_rs::PickScene,
 }
 
 /// A single viewer pane with its own camera and models
@@ -92,6 +95,8 @@ pub struct L3dViewerState {
     pub remove_pane_requested: Option<usize>,
     pub active_pane: usize,
     pub clear_all_requested: bool,
+    /// Name of the part picked with the last click
+    pub selected_part: Option<String>,
     next_id: usize,
     next_pane_id: usize,
 }
@@ -108,6 +113,7 @@ impl Default for L3dViewerState {
             remove_pane_requested: None,
             active_pane: 0,
             clear_all_requested: false,
+            selected_part: None,
             next_id: 0,
             next_pane_id: 0,
         }
@@ -224,6 +230,9 @@ fn run_viewer_wasm_inner(window: Window) {
     // Track load mode: 0=replace in active, 1=add to active, 2=new viewer per file
     let load_mode = Rc::new(RefCell::new(0u8));
 
+    // Left button press position, to tell clicks from camera drags
+    let mut press_position: Option<PhysicalPoint> = None;
+
     window.render_loop(move |mut frame_input| {
         let total_vp = frame_input.viewport;
 
@@ -232,7 +241,7 @@ fn run_viewer_wasm_inner(window: Window) {
             state.status = "Loading...".to_string();
             let mode = *load_mode.borrow();
             match load_l3d_models(&context, &data, None) {
-                Ok((meshes, bounds, mut info)) => {
+                Ok((meshes, bounds, picker, mut info)) => {
                     info.id = state.next_model_id();
 
                     match mode {
@@ -247,6 +256,7 @@ fn run_viewer_wasm_inner(window: Window) {
                                     id: info.id,
                                     meshes,
                                     bounds,
+                                    picker,
                                 });
                                 pane.title = info.file_name.clone();
                                 let vps =
@@ -266,6 +276,7 @@ fn run_viewer_wasm_inner(window: Window) {
                                     id: info.id,
                                     meshes,
                                     bounds,
+                                    picker,
                                 });
                                 let vps =
                                     calculate_grid(pane_count, total_vp.width, total_vp.height);
@@ -286,6 +297,7 @@ fn run_viewer_wasm_inner(window: Window) {
                                 id: info.id,
                                 meshes,
                                 bounds,
+                                picker,
                             });
                             panes.push(new_pane);
                             state.active_pane = panes.len() - 1;
@@ -414,6 +426,7 @@ fn run_viewer_wasm_inner(window: Window) {
 
         // Determine which pane is hovered for input
         let mut hovered_pane = state.active_pane;
+        let mut click = None;
         for event in &frame_input.events {
             if let Event::MouseMotion { position, .. } = event {
                 for (i, vp) in viewports.iter().enumerate() {
@@ -426,6 +439,30 @@ fn run_viewer_wasm_inner(window: Window) {
             if let Event::MousePress { .. } = event {
                 state.active_pane = hovered_pane;
             }
+            if let Event::MousePress {
+                button: MouseButton::Left,
+                position,
+                handled: false,
+                ..
+            } = event
+            {
+                press_position = Some(*position);
+            }
+            if let Event::MouseRelease {
+                button: MouseButton::Left,
+                position,
+                handled: false,
+                ..
+            } = event
+            {
+                // Releasing close to the press is a click, not a camera drag
+                if press_position
+                    .take()
+                    .is_some_and(|p| (p.x - position.x).abs() + (p.y - position.y).abs() < 4.0)
+                {
+                    click = Some(*position);
+                }
+            }
         }
 
         // Update cameras and handle input for hovered pane
@@ -439,6 +476,17 @@ fn run_viewer_wasm_inner(window: Window) {
             }
         }
 
+        // Select the part under a click
+        if let Some(position) = click {
+            if let Some(pane) = panes.get(hovered_pane) {
+                state.selected_part = pick_part(pane, position).map(|hit| hit.part_name);
+                state.status = match &state.selected_part {
+                    Some(name) => format!("Selected: {}", name),
+                    None => "Nothing selected".to_string(),
+                };
+            }
+        }
+
         // Render
         let screen = frame_input.screen();
         screen.clear(ClearState::color_and_depth(0.15, 0.15, 0.2, 1.0, 1.0));
@@ -477,7 +525,7 @@ fn run_viewer_inner(window: Window, content: Option<Vec<u8>>) {
     // Load initial content
     if let Some(data) = content {
         match load_l3d_models(&context, &data, None) {
-            Ok((meshes, bounds, mut info)) => {
+            Ok((meshes, bounds, picker, mut info)) => {
                 info.id = state.next_model_id();
                 if let Some(pane) = panes.get_mut(0) {
                     pane.model_infos.push(info.clone());
@@ -485,6 +533,7 @@ fn run_viewer_inner(window: Window, content: Option<Vec<u8>>) {
                         id: info.id,
                         meshes,
                         bounds,
+                        picker,
                     });
                     pane.title = info.file_name.clone();
                     pane.fit_to_bounds(&window.viewport());
@@ -497,6 +546,9 @@ fn run_viewer_inner(window: Window, content: Option<Vec<u8>>) {
         }
     }
 
+    // Left button press position, to tell clicks from camera drags
+    let mut press_position: Option<PhysicalPoint> = None;
+
     window.render_loop(move |mut frame_input| {
         let total_vp = frame_input.viewport;
 
@@ -526,7 +578,7 @@ fn run_viewer_inner(window: Window, content: Option<Vec<u8>>) {
                     state.status = "Loading...".to_string();
                     let file_name = path.file_name().map(|n| n.to_string_lossy().to_string());
                     match load_l3d_models(&context, &data, file_name) {
-                        Ok((meshes, bounds, mut info)) => {
+                        Ok((meshes, bounds, picker, mut info)) => {
                             info.id = state.next_model_id();
                             if let Some(pane) = panes.get_mut(state.active_pane) {
                                 pane.models.clear();
@@ -536,6 +588,7 @@ fn run_viewer_inner(window: Window, content: Option<Vec<u8>>) {
                                     id: info.id,
                                     meshes,
                                     bounds,
+                                    picker,
                                 });
                                 pane.title = info.file_name.clone();
                                 if let Some(vp) = viewports.get(state.active_pane) {
@@ -564,7 +617,7 @@ fn run_viewer_inner(window: Window, content: Option<Vec<u8>>) {
                     state.status = "Loading...".to_string();
                     let file_name = path.file_name().map(|n| n.to_string_lossy().to_string());
                     match load_l3d_models(&context, &data, file_name) {
-                        Ok((meshes, bounds, mut info)) => {
+                        Ok((meshes, bounds, picker, mut info)) => {
                             info.id = state.next_model_id();
                             if let Some(pane) = panes.get_mut(state.active_pane) {
                                 pane.model_infos.push(info.clone());
@@ -572,6 +625,7 @@ fn run_viewer_inner(window: Window, content: Option<Vec<u8>>) {
                                     id: info.id,
                                     meshes,
                                     bounds,
+                                    picker,
                                 });
                                 if let Some(vp) = viewports.get(state.active_pane) {
                                     pane.fit_to_bounds(vp);
@@ -599,7 +653,7 @@ fn run_viewer_inner(window: Window, content: Option<Vec<u8>>) {
                     state.status = "Loading...".to_string();
                     let file_name = path.file_name().map(|n| n.to_string_lossy().to_string());
                     match load_l3d_models(&context, &data, file_name) {
-                        Ok((meshes, bounds, mut info)) => {
+                        Ok((meshes, bounds, picker, mut info)) => {
                             info.id = state.next_model_id();
                             state.view_mode = ViewMode::Multi;
                             let pane_id = state.next_pane_id();
@@ -610,6 +664,7 @@ fn run_viewer_inner(window: Window, content: Option<Vec<u8>>) {
                                 id: info.id,
                                 meshes,
                                 bounds,
+                                picker,
                             });
                             panes.push(new_pane);
                             state.active_pane = panes.len() - 1;
@@ -668,6 +723,7 @@ fn run_viewer_inner(window: Window, content: Option<Vec<u8>>) {
 
         // Determine which pane is hovered for input
         let mut hovered_pane = state.active_pane;
+        let mut click = None;
         for event in &frame_input.events {
             if let Event::MouseMotion { position, .. } = event {
                 for (i, vp) in viewports.iter().enumerate() {
@@ -680,6 +736,30 @@ fn run_viewer_inner(window: Window, content: Option<Vec<u8>>) {
             if let Event::MousePress { .. } = event {
                 state.active_pane = hovered_pane;
             }
+            if let Event::MousePress {
+                button: MouseButton::Left,
+                position,
+                handled: false,
+                ..
+            } = event
+            {
+                press_position = Some(*position);
+            }
+            if let Event::MouseRelease {
+                button: MouseButton::Left,
+                position,
+                handled: false,
+                ..
+            } = event
+            {
+                // Releasing close to the press is a click, not a camera drag
+                if press_position
+                    .take()
+                    .is_some_and(|p| (p.x - position.x).abs() + (p.y - position.y).abs() < 4.0)
+                {
+                    click = Some(*position);
+                }
+            }
         }
 
         // Update cameras and handle input for hovered pane
@@ -693,6 +773,17 @@ fn run_viewer_inner(window: Window, content: Option<Vec<u8>>) {
             }
         }
 
+        // Select the part under a click
+        if let Some(position) = click {
+            if let Some(pane) = panes.get(hovered_pane) {
+                state.selected_part = pick_part(pane, position).map(|hit| hit.part_name);
+                state.status = match &state.selected_part {
+                    Some(name) => format!("Selected: {}", name),
+                    None => "Nothing selected".to_string(),
+                };
+            }
+        }
+
         // Render
         let screen = frame_input.screen();
         screen.clear(ClearState::color_and_depth(0.15, 0.15, 0.2, 1.0, 1.0));
@@ -843,23 +934,34 @@ fn render_ui(ctx: &three_d::egui::Context, state: &mut L3dViewerState, panes: &[
                 });
             }
 
+            if let Some(part) = &state.selected_part {
+                ui.separator();
+                ui.label(format!("Selected part: {}", part));
+            }
+
             ui.separator();
             ui.heading("Controls");
             ui.label("• Drag: Rotate");
             ui.label("• Scroll: Zoom");
             ui.label("• Shift+Drag: Pan");
-            ui.label("• Click: Select viewer");
+            ui.label("• Click: Select viewer and part");
         });
 }
 
 fn load_l3d_models(context: &Context, content: &[u8], file_name: Option<String>) -> LoadResult {
-    let l3d = l3d_rs::from_buffer(content);
+    // three-d is Y-up, so let the core convert the L3D Z-up transforms
+    let options = l3d_rs::BuildOptions {
+        coordinate_system: l3d_rs::CoordinateSystem::YUpRightHanded,
+        ..Default::default()
+    };
+    let l3d = l3d_rs::from_buffer_with_options(content, &options);
 
     if l3d.model.parts.is_empty() {
         return Err("No parts found".to_string());
     }
 
     let parts_count = l3d.model.parts.len();
+    let picker = l3d.pick_scene();
     let assets_count = l3d.file.assets.len();
 
     let display_name = file_name.unwrap_or_else(|| {
@@ -879,7 +981,6 @@ fn load_l3d_models(context: &Context, content: &[u8], file_name: Option<String>)
     }
     add_stub_mtls(&l3d, &mut raw_assets);
 
-    let rotation = Mat4::from_angle_x(Deg(-90.0));
     let mut cpu_models: HashMap<String, CpuModel> = HashMap::new();
     let mut models: Vec<Model<PhysicalMaterial>> = Vec::new();
     let mut min_b = vec3(f32::MAX, f32::MAX, f32::MAX);
@@ -901,13 +1002,12 @@ fn load_l3d_models(context: &Context, content: &[u8], file_name: Option<String>)
         };
 
         if let Ok(mut mdl) = Model::<PhysicalMaterial>::new(context, cpu_mdl) {
-            let part_mat = Mat4::from_cols(
+            let mat = Mat4::from_cols(
                 vec4(part.mat[0], part.mat[1], part.mat[2], part.mat[3]),
                 vec4(part.mat[4], part.mat[5], part.mat[6], part.mat[7]),
                 vec4(part.mat[8], part.mat[9], part.mat[10], part.mat[11]),
                 vec4(part.mat[12], part.mat[13], part.mat[14], part.mat[15]),
             );
-            let mat = rotation * part_mat;
 
             mdl.iter_mut().for_each(|m| {
                 m.set_transformation(mat);
@@ -938,7 +1038,18 @@ fn load_l3d_models(context: &Context, content: &[u8], file_name: Option<String>)
         parts_count,
         assets_count,
     };
-    Ok((models, bounds, info))
+    Ok((models, bounds, picker, info))
+}
+
+/// Cast a ray through a pixel of the pane and return the closest part hit
+fn pick_part(pane: &ViewerPane, position: PhysicalPoint) -> Option<l3d_rs::PickHit> {
+    let origin = pane.camera.position_at_pixel(position);
+    let direction = pane.camera.view_direction_at_pixel(position);
+    let ray = l3d_rs::Ray::new(origin.into(), direction.into());
+    pane.models
+        .iter()
+        .filter_map(|model| model.picker.pick(&ray))
+        .min_by(|a, b| a.distance.total_cmp(&b.distance))
 }
 
 fn calculate_total_bounds(scene_models: &[SceneModel]) -> Option<(Vec3, Vec3)> {
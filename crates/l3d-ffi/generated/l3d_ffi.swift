@@ -414,7 +414,29 @@ fileprivate final class UniffiHandleMap<T>: @unchecked Sendable {
 
 
 // Public interface members begin here.
+// Magic number for the Rust proxy to call using the same mechanism as every other method,
+// to free the callback once it's dropped by Rust.
+private let IDX_CALLBACK_FREE: Int32 = 0
+// Callback return codes
+private let UNIFFI_CALLBACK_SUCCESS: Int32 = 0
+private let UNIFFI_CALLBACK_ERROR: Int32 = 1
+private let UNIFFI_CALLBACK_UNEXPECTED_ERROR: Int32 = 2
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt32: FfiConverterPrimitive {
+    typealias FfiType = UInt32
+    typealias SwiftType = UInt32
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt32 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
@@ -464,6 +486,30 @@ fileprivate struct FfiConverterDouble: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterBool : FfiConverter {
+    typealias FfiType = Int8
+    typealias SwiftType = Bool
+
+    public static func lift(_ value: Int8) throws -> Bool {
+        return value != 0
+    }
+
+    public static func lower(_ value: Bool) -> Int8 {
+        return value ? 1 : 0
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Bool {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: Bool, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -541,31 +587,94 @@ public protocol L3dFileProtocol: AnyObject, Sendable {
      */
     func getAssets()  -> [L3dAsset]
     
+    /**
+     * Get the metadata from the structure.xml header
+     */
+    func getHeader() throws  -> L3dHeader
+    
+    /**
+     * Get all joints in document order
+     */
+    func getJoints()  -> [L3dJoint]
+    
     /**
      * Get all light emitting objects
      */
     func getLightEmitters()  -> [L3dLightEmitter]
     
+    /**
+     * Get the parsed geometry of every part as separate position, normal,
+     * texture coordinate and index arrays
+     *
+     * With `world_space` the part transforms are baked into positions and
+     * normals; otherwise place each mesh with its `transform`. Parts whose
+     * geometry is missing or cannot be parsed are left out.
+     */
+    func getMeshes(worldSpace: Bool)  -> [L3dMesh]
+    
+    /**
+     * Get the geometry and joint hierarchy as a flattened tree
+     */
+    func getNodes()  -> [L3dNode]
+    
     /**
      * Get the number of geometry parts
      */
     func getPartCount()  -> UInt64
     
+    /**
+     * Recompute the world transforms of all parts for the given joint
+     * angles in degrees, keyed by joint name
+     *
+     * Angles rotate about the joint's own axes and are clamped to its axis
+     * ranges; axes without a range stay fixed. Joints that are not listed
+     * keep the pose from structure.xml.
+     */
+    func getPartTransforms(jointAngles: [String: L3dVec3]) throws  -> [L3dPartTransform]
+    
     /**
      * Get all geometry parts with their transformations
+     *
+     * Parts are in the same order as `get_part_count`, `pick` and
+     * `get_render_buffers`; a part whose geometry ID is not defined has an
+     * empty path.
      */
     func getParts()  -> [L3dPart]
     
+    /**
+     * Get GPU-ready vertex and index buffers for every part
+     *
+     * With `world_space` the part transforms are baked into the vertices;
+     * otherwise draw each buffer with its `transform`.
+     */
+    func getRenderBuffers(worldSpace: Bool)  -> [L3dRenderBuffer]
+    
     /**
      * Get the raw structure.xml content
      */
     func getStructureXml()  -> String
     
+    /**
+     * Find the part hit by a ray, e.g. through a tapped pixel
+     *
+     * Origin and direction are in model coordinates (Z-up, meters), the
+     * same space as the part transforms. Returns `None` if nothing is hit.
+     */
+    func pick(origin: L3dVec3, direction: L3dVec3)  -> L3dPickHit?
+    
     /**
      * Convert the luminaire data to JSON
      */
     func toJson() throws  -> String
     
+    /**
+     * Check the file for problems such as missing or unreadable geometry,
+     * unknown geometry IDs and duplicate part names
+     *
+     * An empty list means the file is fine.
+     */
+    func validate()  -> [L3dDiagnostic]
+    
 }
 /**
  * Main L3D file interface
@@ -638,6 +747,23 @@ public static func fromPath(path: String)throws  -> L3dFile  {
 })
 }
     
+    /**
+     * Parse L3D data from bytes, reporting progress to `observer` and
+     * messages to `logger`
+     *
+     * Call it off the UI thread. Fails with `Cancelled` as soon as the
+     * observer cancels, and with `ParseError` for unreadable files.
+     */
+public static func load(data: Data, observer: L3dLoadObserver?, logger: L3dLogger?)throws  -> L3dFile  {
+    return try  FfiConverterTypeL3dFile_lift(try rustCallWithError(FfiConverterTypeL3dError_lift) {
+    uniffi_l3d_ffi_fn_constructor_l3dfile_load(
+        FfiConverterData.lower(data),
+        FfiConverterOptionCallbackInterfaceL3dLoadObserver.lower(observer),
+        FfiConverterOptionCallbackInterfaceL3dLogger.lower(logger),$0
+    )
+})
+}
+    
 
     
     /**
@@ -660,6 +786,28 @@ open func getAssets() -> [L3dAsset]  {
             self.uniffiCloneHandle(),$0
     )
 })
+}
+    
+    /**
+     * Get the metadata from the structure.xml header
+     */
+open func getHeader()throws  -> L3dHeader  {
+    return try  FfiConverterTypeL3dHeader_lift(try rustCallWithError(FfiConverterTypeL3dError_lift) {
+    uniffi_l3d_ffi_fn_method_l3dfile_get_header(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+    /**
+     * Get all joints in document order
+     */
+open func getJoints() -> [L3dJoint]  {
+    return try!  FfiConverterSequenceTypeL3dJoint.lift(try! rustCall() {
+    uniffi_l3d_ffi_fn_method_l3dfile_get_joints(
+            self.uniffiCloneHandle(),$0
+    )
+})
 }
     
     /**
@@ -671,6 +819,34 @@ open func getLightEmitters() -> [L3dLightEmitter]  {
             self.uniffiCloneHandle(),$0
     )
 })
+}
+    
+    /**
+     * Get the parsed geometry of every part as separate position, normal,
+     * texture coordinate and index arrays
+     *
+     * With `world_space` the part transforms are baked into positions and
+     * normals; otherwise place each mesh with its `transform`. Parts whose
+     * geometry is missing or cannot be parsed are left out.
+     */
+open func getMeshes(worldSpace: Bool) -> [L3dMesh]  {
+    return try!  FfiConverterSequenceTypeL3dMesh.lift(try! rustCall() {
+    uniffi_l3d_ffi_fn_method_l3dfile_get_meshes(
+            self.uniffiCloneHandle(),
+        FfiConverterBool.lower(worldSpace),$0
+    )
+})
+}
+    
+    /**
+     * Get the geometry and joint hierarchy as a flattened tree
+     */
+open func getNodes() -> [L3dNode]  {
+    return try!  FfiConverterSequenceTypeL3dNode.lift(try! rustCall() {
+    uniffi_l3d_ffi_fn_method_l3dfile_get_nodes(
+            self.uniffiCloneHandle(),$0
+    )
+})
 }
     
     /**
@@ -682,10 +858,31 @@ open func getPartCount() -> UInt64  {
             self.uniffiCloneHandle(),$0
     )
 })
+}
+    
+    /**
+     * Recompute the world transforms of all parts for the given joint
+     * angles in degrees, keyed by joint name
+     *
+     * Angles rotate about the joint's own axes and are clamped to its axis
+     * ranges; axes without a range stay fixed. Joints that are not listed
+     * keep the pose from structure.xml.
+     */
+open func getPartTransforms(jointAngles: [String: L3dVec3])throws  -> [L3dPartTransform]  {
+    return try  FfiConverterSequenceTypeL3dPartTransform.lift(try rustCallWithError(FfiConverterTypeL3dError_lift) {
+    uniffi_l3d_ffi_fn_method_l3dfile_get_part_transforms(
+            self.uniffiCloneHandle(),
+        FfiConverterDictionaryStringTypeL3dVec3.lower(jointAngles),$0
+    )
+})
 }
     
     /**
      * Get all geometry parts with their transformations
+     *
+     * Parts are in the same order as `get_part_count`, `pick` and
+     * `get_render_buffers`; a part whose geometry ID is not defined has an
+     * empty path.
      */
 open func getParts() -> [L3dPart]  {
     return try!  FfiConverterSequenceTypeL3dPart.lift(try! rustCall() {
@@ -693,6 +890,21 @@ open func getParts() -> [L3dPart]  {
             self.uniffiCloneHandle(),$0
     )
 })
+}
+    
+    /**
+     * Get GPU-ready vertex and index buffers for every part
+     *
+     * With `world_space` the part transforms are baked into the vertices;
+     * otherwise draw each buffer with its `transform`.
+     */
+open func getRenderBuffers(worldSpace: Bool) -> [L3dRenderBuffer]  {
+    return try!  FfiConverterSequenceTypeL3dRenderBuffer.lift(try! rustCall() {
+    uniffi_l3d_ffi_fn_method_l3dfile_get_render_buffers(
+            self.uniffiCloneHandle(),
+        FfiConverterBool.lower(worldSpace),$0
+    )
+})
 }
     
     /**
@@ -704,6 +916,22 @@ open func getStructureXml() -> String  {
             self.uniffiCloneHandle(),$0
     )
 })
+}
+    
+    /**
+     * Find the part hit by a ray, e.g. through a tapped pixel
+     *
+     * Origin and direction are in model coordinates (Z-up, meters), the
+     * same space as the part transforms. Returns `None` if nothing is hit.
+     */
+open func pick(origin: L3dVec3, direction: L3dVec3) -> L3dPickHit?  {
+    return try!  FfiConverterOptionTypeL3dPickHit.lift(try! rustCall() {
+    uniffi_l3d_ffi_fn_method_l3dfile_pick(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeL3dVec3_lower(origin),
+        FfiConverterTypeL3dVec3_lower(direction),$0
+    )
+})
 }
     
     /**
@@ -717,6 +945,20 @@ open func toJson()throws  -> String  {
 })
 }
     
+    /**
+     * Check the file for problems such as missing or unreadable geometry,
+     * unknown geometry IDs and duplicate part names
+     *
+     * An empty list means the file is fine.
+     */
+open func validate() -> [L3dDiagnostic]  {
+    return try!  FfiConverterSequenceTypeL3dDiagnostic.lift(try! rustCall() {
+    uniffi_l3d_ffi_fn_method_l3dfile_validate(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
 
     
 }
@@ -833,93 +1075,132 @@ public func FfiConverterTypeL3dAsset_lower(_ value: L3dAsset) -> RustBuffer {
 
 
 /**
- * A light emitting object with position and direction
+ * Range of an adjustable joint axis in degrees
  */
-public struct L3dLightEmitter: Equatable, Hashable {
-    /**
-     * Part name
-     */
-    public var name: String
-    /**
-     * Position of the light (x, y, z)
-     */
-    public var position: L3dVec3
+public struct L3dAxis: Equatable, Hashable {
+    public var min: Double
+    public var max: Double
+    public var step: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(min: Double, max: Double, step: Double) {
+        self.min = min
+        self.max = max
+        self.step = step
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension L3dAxis: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeL3dAxis: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dAxis {
+        return
+            try L3dAxis(
+                min: FfiConverterDouble.read(from: &buf), 
+                max: FfiConverterDouble.read(from: &buf), 
+                step: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: L3dAxis, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.min, into: &buf)
+        FfiConverterDouble.write(value.max, into: &buf)
+        FfiConverterDouble.write(value.step, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dAxis_lift(_ buf: RustBuffer) throws -> L3dAxis {
+    return try FfiConverterTypeL3dAxis.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dAxis_lower(_ value: L3dAxis) -> RustBuffer {
+    return FfiConverterTypeL3dAxis.lower(value)
+}
+
+
+/**
+ * A problem found by `validate`
+ */
+public struct L3dDiagnostic: Equatable, Hashable {
     /**
-     * Rotation/direction of the light (x, y, z in degrees)
+     * How serious the finding is
      */
-    public var rotation: L3dVec3
+    public var severity: L3dSeverity
     /**
-     * Shape type: "rectangle" or "circle"
+     * Stable, machine-readable identifier (e.g. "unknown-geometry-id")
      */
-    public var shape: String
+    public var code: String
     /**
-     * Width (for rectangle) or diameter (for circle)
+     * Human-readable description
      */
-    public var sizeX: Double
+    public var message: String
     /**
-     * Height (for rectangle only)
+     * Part name from structure.xml the finding relates to, if any
      */
-    public var sizeY: Double
+    public var partName: String?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * Part name
-         */name: String, 
-        /**
-         * Position of the light (x, y, z)
-         */position: L3dVec3, 
-        /**
-         * Rotation/direction of the light (x, y, z in degrees)
-         */rotation: L3dVec3, 
+         * How serious the finding is
+         */severity: L3dSeverity, 
         /**
-         * Shape type: "rectangle" or "circle"
-         */shape: String, 
+         * Stable, machine-readable identifier (e.g. "unknown-geometry-id")
+         */code: String, 
         /**
-         * Width (for rectangle) or diameter (for circle)
-         */sizeX: Double, 
+         * Human-readable description
+         */message: String, 
         /**
-         * Height (for rectangle only)
-         */sizeY: Double) {
-        self.name = name
-        self.position = position
-        self.rotation = rotation
-        self.shape = shape
-        self.sizeX = sizeX
-        self.sizeY = sizeY
+         * Part name from structure.xml the finding relates to, if any
+         */partName: String?) {
+        self.severity = severity
+        self.code = code
+        self.message = message
+        self.partName = partName
     }
 
     
 }
 
 #if compiler(>=6)
-extension L3dLightEmitter: Sendable {}
+extension L3dDiagnostic: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeL3dLightEmitter: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dLightEmitter {
+public struct FfiConverterTypeL3dDiagnostic: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dDiagnostic {
         return
-            try L3dLightEmitter(
-                name: FfiConverterString.read(from: &buf), 
-                position: FfiConverterTypeL3dVec3.read(from: &buf), 
-                rotation: FfiConverterTypeL3dVec3.read(from: &buf), 
-                shape: FfiConverterString.read(from: &buf), 
-                sizeX: FfiConverterDouble.read(from: &buf), 
-                sizeY: FfiConverterDouble.read(from: &buf)
+            try L3dDiagnostic(
+                severity: FfiConverterTypeL3dSeverity.read(from: &buf), 
+                code: FfiConverterString.read(from: &buf), 
+                message: FfiConverterString.read(from: &buf), 
+                partName: FfiConverterOptionString.read(from: &buf)
         )
     }
 
-    public static func write(_ value: L3dLightEmitter, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.name, into: &buf)
-        FfiConverterTypeL3dVec3.write(value.position, into: &buf)
-        FfiConverterTypeL3dVec3.write(value.rotation, into: &buf)
-        FfiConverterString.write(value.shape, into: &buf)
-        FfiConverterDouble.write(value.sizeX, into: &buf)
-        FfiConverterDouble.write(value.sizeY, into: &buf)
+    public static func write(_ value: L3dDiagnostic, into buf: inout [UInt8]) {
+        FfiConverterTypeL3dSeverity.write(value.severity, into: &buf)
+        FfiConverterString.write(value.code, into: &buf)
+        FfiConverterString.write(value.message, into: &buf)
+        FfiConverterOptionString.write(value.partName, into: &buf)
     }
 }
 
@@ -927,96 +1208,96 @@ public struct FfiConverterTypeL3dLightEmitter: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeL3dLightEmitter_lift(_ buf: RustBuffer) throws -> L3dLightEmitter {
-    return try FfiConverterTypeL3dLightEmitter.lift(buf)
+public func FfiConverterTypeL3dDiagnostic_lift(_ buf: RustBuffer) throws -> L3dDiagnostic {
+    return try FfiConverterTypeL3dDiagnostic.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeL3dLightEmitter_lower(_ value: L3dLightEmitter) -> RustBuffer {
-    return FfiConverterTypeL3dLightEmitter.lower(value)
+public func FfiConverterTypeL3dDiagnostic_lower(_ value: L3dDiagnostic) -> RustBuffer {
+    return FfiConverterTypeL3dDiagnostic.lower(value)
 }
 
 
 /**
- * A geometry part with its transformation matrix
+ * Metadata from the `Header` of structure.xml
  */
-public struct L3dPart: Equatable, Hashable {
+public struct L3dHeader: Equatable, Hashable {
     /**
-     * Part name from structure.xml
+     * Name of the luminaire, if given
      */
-    public var name: String
+    public var name: String?
     /**
-     * Path to the geometry file (e.g., "geom_1/luminaire.obj")
+     * Description of the luminaire, if given
      */
-    public var path: String
+    public var description: String?
     /**
-     * Position offset (x, y, z)
+     * Application that wrote the file
      */
-    public var position: L3dVec3
+    public var createdWithApplication: String
     /**
-     * Rotation in degrees (x, y, z)
+     * Creation time as written in the file (ISO 8601)
      */
-    public var rotation: L3dVec3
+    public var creationTimeCode: String
     /**
-     * 4x4 transformation matrix (16 floats, column-major)
+     * Format version as `major.minor` or `major.minor-pre`, if given
      */
-    public var transform: [Float]
+    public var formatVersion: String?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * Part name from structure.xml
-         */name: String, 
+         * Name of the luminaire, if given
+         */name: String?, 
         /**
-         * Path to the geometry file (e.g., "geom_1/luminaire.obj")
-         */path: String, 
+         * Description of the luminaire, if given
+         */description: String?, 
         /**
-         * Position offset (x, y, z)
-         */position: L3dVec3, 
+         * Application that wrote the file
+         */createdWithApplication: String, 
         /**
-         * Rotation in degrees (x, y, z)
-         */rotation: L3dVec3, 
+         * Creation time as written in the file (ISO 8601)
+         */creationTimeCode: String, 
         /**
-         * 4x4 transformation matrix (16 floats, column-major)
-         */transform: [Float]) {
+         * Format version as `major.minor` or `major.minor-pre`, if given
+         */formatVersion: String?) {
         self.name = name
-        self.path = path
-        self.position = position
-        self.rotation = rotation
-        self.transform = transform
+        self.description = description
+        self.createdWithApplication = createdWithApplication
+        self.creationTimeCode = creationTimeCode
+        self.formatVersion = formatVersion
     }
 
     
 }
 
 #if compiler(>=6)
-extension L3dPart: Sendable {}
+extension L3dHeader: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeL3dPart: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dPart {
+public struct FfiConverterTypeL3dHeader: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dHeader {
         return
-            try L3dPart(
-                name: FfiConverterString.read(from: &buf), 
-                path: FfiConverterString.read(from: &buf), 
-                position: FfiConverterTypeL3dVec3.read(from: &buf), 
-                rotation: FfiConverterTypeL3dVec3.read(from: &buf), 
-                transform: FfiConverterSequenceFloat.read(from: &buf)
+            try L3dHeader(
+                name: FfiConverterOptionString.read(from: &buf), 
+                description: FfiConverterOptionString.read(from: &buf), 
+                createdWithApplication: FfiConverterString.read(from: &buf), 
+                creationTimeCode: FfiConverterString.read(from: &buf), 
+                formatVersion: FfiConverterOptionString.read(from: &buf)
         )
     }
 
-    public static func write(_ value: L3dPart, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.name, into: &buf)
-        FfiConverterString.write(value.path, into: &buf)
-        FfiConverterTypeL3dVec3.write(value.position, into: &buf)
-        FfiConverterTypeL3dVec3.write(value.rotation, into: &buf)
-        FfiConverterSequenceFloat.write(value.transform, into: &buf)
+    public static func write(_ value: L3dHeader, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.name, into: &buf)
+        FfiConverterOptionString.write(value.description, into: &buf)
+        FfiConverterString.write(value.createdWithApplication, into: &buf)
+        FfiConverterString.write(value.creationTimeCode, into: &buf)
+        FfiConverterOptionString.write(value.formatVersion, into: &buf)
     }
 }
 
@@ -1024,58 +1305,116 @@ public struct FfiConverterTypeL3dPart: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeL3dPart_lift(_ buf: RustBuffer) throws -> L3dPart {
-    return try FfiConverterTypeL3dPart.lift(buf)
+public func FfiConverterTypeL3dHeader_lift(_ buf: RustBuffer) throws -> L3dHeader {
+    return try FfiConverterTypeL3dHeader.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeL3dPart_lower(_ value: L3dPart) -> RustBuffer {
-    return FfiConverterTypeL3dPart.lower(value)
+public func FfiConverterTypeL3dHeader_lower(_ value: L3dHeader) -> RustBuffer {
+    return FfiConverterTypeL3dHeader.lower(value)
 }
 
 
 /**
- * 3D vector (x, y, z)
+ * An adjustable joint between a geometry and its child geometries
  */
-public struct L3dVec3: Equatable, Hashable {
-    public var x: Float
-    public var y: Float
-    public var z: Float
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(x: Float, y: Float, z: Float) {
-        self.x = x
-        self.y = y
-        self.z = z
+public struct L3dJoint: Equatable, Hashable {
+    /**
+     * Joint part name from structure.xml
+     */
+    public var name: String
+    /**
+     * Position relative to the parent geometry
+     */
+    public var position: L3dVec3
+    /**
+     * Rotation in degrees relative to the parent geometry
+     */
+    public var rotation: L3dVec3
+    /**
+     * Range of rotation about the joint's X axis, if adjustable
+     */
+    public var xAxis: L3dAxis?
+    /**
+     * Range of rotation about the joint's Y axis, if adjustable
+     */
+    public var yAxis: L3dAxis?
+    /**
+     * Range of rotation about the joint's Z axis, if adjustable
+     */
+    public var zAxis: L3dAxis?
+    /**
+     * Initial joint angles in degrees
+     */
+    public var defaultRotation: L3dVec3?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Joint part name from structure.xml
+         */name: String, 
+        /**
+         * Position relative to the parent geometry
+         */position: L3dVec3, 
+        /**
+         * Rotation in degrees relative to the parent geometry
+         */rotation: L3dVec3, 
+        /**
+         * Range of rotation about the joint's X axis, if adjustable
+         */xAxis: L3dAxis?, 
+        /**
+         * Range of rotation about the joint's Y axis, if adjustable
+         */yAxis: L3dAxis?, 
+        /**
+         * Range of rotation about the joint's Z axis, if adjustable
+         */zAxis: L3dAxis?, 
+        /**
+         * Initial joint angles in degrees
+         */defaultRotation: L3dVec3?) {
+        self.name = name
+        self.position = position
+        self.rotation = rotation
+        self.xAxis = xAxis
+        self.yAxis = yAxis
+        self.zAxis = zAxis
+        self.defaultRotation = defaultRotation
     }
 
     
 }
 
 #if compiler(>=6)
-extension L3dVec3: Sendable {}
+extension L3dJoint: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeL3dVec3: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dVec3 {
+public struct FfiConverterTypeL3dJoint: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dJoint {
         return
-            try L3dVec3(
-                x: FfiConverterFloat.read(from: &buf), 
-                y: FfiConverterFloat.read(from: &buf), 
-                z: FfiConverterFloat.read(from: &buf)
+            try L3dJoint(
+                name: FfiConverterString.read(from: &buf), 
+                position: FfiConverterTypeL3dVec3.read(from: &buf), 
+                rotation: FfiConverterTypeL3dVec3.read(from: &buf), 
+                xAxis: FfiConverterOptionTypeL3dAxis.read(from: &buf), 
+                yAxis: FfiConverterOptionTypeL3dAxis.read(from: &buf), 
+                zAxis: FfiConverterOptionTypeL3dAxis.read(from: &buf), 
+                defaultRotation: FfiConverterOptionTypeL3dVec3.read(from: &buf)
         )
     }
 
-    public static func write(_ value: L3dVec3, into buf: inout [UInt8]) {
-        FfiConverterFloat.write(value.x, into: &buf)
-        FfiConverterFloat.write(value.y, into: &buf)
-        FfiConverterFloat.write(value.z, into: &buf)
+    public static func write(_ value: L3dJoint, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterTypeL3dVec3.write(value.position, into: &buf)
+        FfiConverterTypeL3dVec3.write(value.rotation, into: &buf)
+        FfiConverterOptionTypeL3dAxis.write(value.xAxis, into: &buf)
+        FfiConverterOptionTypeL3dAxis.write(value.yAxis, into: &buf)
+        FfiConverterOptionTypeL3dAxis.write(value.zAxis, into: &buf)
+        FfiConverterOptionTypeL3dVec3.write(value.defaultRotation, into: &buf)
     }
 }
 
@@ -1083,112 +1422,1887 @@ public struct FfiConverterTypeL3dVec3: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeL3dVec3_lift(_ buf: RustBuffer) throws -> L3dVec3 {
-    return try FfiConverterTypeL3dVec3.lift(buf)
+public func FfiConverterTypeL3dJoint_lift(_ buf: RustBuffer) throws -> L3dJoint {
+    return try FfiConverterTypeL3dJoint.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dJoint_lower(_ value: L3dJoint) -> RustBuffer {
+    return FfiConverterTypeL3dJoint.lower(value)
+}
+
+
+/**
+ * A light emitting object with position and direction
+ */
+public struct L3dLightEmitter: Equatable, Hashable {
+    /**
+     * Part name
+     */
+    public var name: String
+    /**
+     * Position of the light (x, y, z)
+     */
+    public var position: L3dVec3
+    /**
+     * Rotation/direction of the light (x, y, z in degrees)
+     */
+    public var rotation: L3dVec3
+    /**
+     * Shape type: "rectangle" or "circle"
+     */
+    public var shape: String
+    /**
+     * Width (for rectangle) or diameter (for circle)
+     */
+    public var sizeX: Double
+    /**
+     * Height (for rectangle only)
+     */
+    public var sizeY: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Part name
+         */name: String, 
+        /**
+         * Position of the light (x, y, z)
+         */position: L3dVec3, 
+        /**
+         * Rotation/direction of the light (x, y, z in degrees)
+         */rotation: L3dVec3, 
+        /**
+         * Shape type: "rectangle" or "circle"
+         */shape: String, 
+        /**
+         * Width (for rectangle) or diameter (for circle)
+         */sizeX: Double, 
+        /**
+         * Height (for rectangle only)
+         */sizeY: Double) {
+        self.name = name
+        self.position = position
+        self.rotation = rotation
+        self.shape = shape
+        self.sizeX = sizeX
+        self.sizeY = sizeY
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension L3dLightEmitter: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeL3dLightEmitter: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dLightEmitter {
+        return
+            try L3dLightEmitter(
+                name: FfiConverterString.read(from: &buf), 
+                position: FfiConverterTypeL3dVec3.read(from: &buf), 
+                rotation: FfiConverterTypeL3dVec3.read(from: &buf), 
+                shape: FfiConverterString.read(from: &buf), 
+                sizeX: FfiConverterDouble.read(from: &buf), 
+                sizeY: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: L3dLightEmitter, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterTypeL3dVec3.write(value.position, into: &buf)
+        FfiConverterTypeL3dVec3.write(value.rotation, into: &buf)
+        FfiConverterString.write(value.shape, into: &buf)
+        FfiConverterDouble.write(value.sizeX, into: &buf)
+        FfiConverterDouble.write(value.sizeY, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dLightEmitter_lift(_ buf: RustBuffer) throws -> L3dLightEmitter {
+    return try FfiConverterTypeL3dLightEmitter.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dLightEmitter_lower(_ value: L3dLightEmitter) -> RustBuffer {
+    return FfiConverterTypeL3dLightEmitter.lower(value)
+}
+
+
+/**
+ * Progress of `L3dFile.load`
+ */
+public struct L3dLoadProgress: Equatable, Hashable {
+    /**
+     * Current stage
+     */
+    public var stage: L3dLoadStage
+    /**
+     * Archive entries read so far
+     */
+    public var entriesRead: UInt64
+    /**
+     * Number of entries in the archive
+     */
+    public var entryCount: UInt64
+    /**
+     * Uncompressed bytes read so far
+     */
+    public var bytesRead: UInt64
+    /**
+     * Uncompressed size of all entries
+     */
+    public var totalBytes: UInt64
+    /**
+     * Fraction of the archive read, from 0 to 1
+     */
+    public var fraction: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Current stage
+         */stage: L3dLoadStage, 
+        /**
+         * Archive entries read so far
+         */entriesRead: UInt64, 
+        /**
+         * Number of entries in the archive
+         */entryCount: UInt64, 
+        /**
+         * Uncompressed bytes read so far
+         */bytesRead: UInt64, 
+        /**
+         * Uncompressed size of all entries
+         */totalBytes: UInt64, 
+        /**
+         * Fraction of the archive read, from 0 to 1
+         */fraction: Double) {
+        self.stage = stage
+        self.entriesRead = entriesRead
+        self.entryCount = entryCount
+        self.bytesRead = bytesRead
+        self.totalBytes = totalBytes
+        self.fraction = fraction
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension L3dLoadProgress: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeL3dLoadProgress: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dLoadProgress {
+        return
+            try L3dLoadProgress(
+                stage: FfiConverterTypeL3dLoadStage.read(from: &buf), 
+                entriesRead: FfiConverterUInt64.read(from: &buf), 
+                entryCount: FfiConverterUInt64.read(from: &buf), 
+                bytesRead: FfiConverterUInt64.read(from: &buf), 
+                totalBytes: FfiConverterUInt64.read(from: &buf), 
+                fraction: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: L3dLoadProgress, into buf: inout [UInt8]) {
+        FfiConverterTypeL3dLoadStage.write(value.stage, into: &buf)
+        FfiConverterUInt64.write(value.entriesRead, into: &buf)
+        FfiConverterUInt64.write(value.entryCount, into: &buf)
+        FfiConverterUInt64.write(value.bytesRead, into: &buf)
+        FfiConverterUInt64.write(value.totalBytes, into: &buf)
+        FfiConverterDouble.write(value.fraction, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dLoadProgress_lift(_ buf: RustBuffer) throws -> L3dLoadProgress {
+    return try FfiConverterTypeL3dLoadProgress.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dLoadProgress_lower(_ value: L3dLoadProgress) -> RustBuffer {
+    return FfiConverterTypeL3dLoadProgress.lower(value)
+}
+
+
+/**
+ * Parsed geometry of one part as separate attribute arrays
+ *
+ * OBJ faces are triangulated and every vertex has a position, normal and
+ * texture coordinate, so the arrays map directly to e.g. SceneKit
+ * geometry sources or Filament vertex buffer attributes.
+ */
+public struct L3dMesh: Equatable, Hashable {
+    /**
+     * Index into the model parts (same order as `get_part_count`)
+     */
+    public var partIndex: UInt64
+    /**
+     * Part name from structure.xml
+     */
+    public var name: String
+    /**
+     * Path to the geometry file of the part
+     */
+    public var path: String
+    /**
+     * Vertex positions (x, y, z per vertex)
+     */
+    public var positions: [Float]
+    /**
+     * Vertex normals (x, y, z per vertex); corners without a normal in the
+     * OBJ get the normal of their face, zero only for degenerate faces
+     */
+    public var normals: [Float]
+    /**
+     * Texture coordinates (u, v per vertex), zero if the OBJ has none
+     */
+    public var uvs: [Float]
+    /**
+     * Triangle list indices into the vertices
+     */
+    public var indices: [UInt32]
+    /**
+     * 4x4 model matrix (16 floats, column-major); identity in world space
+     */
+    public var transform: [Float]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Index into the model parts (same order as `get_part_count`)
+         */partIndex: UInt64, 
+        /**
+         * Part name from structure.xml
+         */name: String, 
+        /**
+         * Path to the geometry file of the part
+         */path: String, 
+        /**
+         * Vertex positions (x, y, z per vertex)
+         */positions: [Float], 
+        /**
+         * Vertex normals (x, y, z per vertex); corners without a normal in the
+         * OBJ get the normal of their face, zero only for degenerate faces
+         */normals: [Float], 
+        /**
+         * Texture coordinates (u, v per vertex), zero if the OBJ has none
+         */uvs: [Float], 
+        /**
+         * Triangle list indices into the vertices
+         */indices: [UInt32], 
+        /**
+         * 4x4 model matrix (16 floats, column-major); identity in world space
+         */transform: [Float]) {
+        self.partIndex = partIndex
+        self.name = name
+        self.path = path
+        self.positions = positions
+        self.normals = normals
+        self.uvs = uvs
+        self.indices = indices
+        self.transform = transform
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension L3dMesh: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeL3dMesh: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dMesh {
+        return
+            try L3dMesh(
+                partIndex: FfiConverterUInt64.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf), 
+                path: FfiConverterString.read(from: &buf), 
+                positions: FfiConverterSequenceFloat.read(from: &buf), 
+                normals: FfiConverterSequenceFloat.read(from: &buf), 
+                uvs: FfiConverterSequenceFloat.read(from: &buf), 
+                indices: FfiConverterSequenceUInt32.read(from: &buf), 
+                transform: FfiConverterSequenceFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: L3dMesh, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.partIndex, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterSequenceFloat.write(value.positions, into: &buf)
+        FfiConverterSequenceFloat.write(value.normals, into: &buf)
+        FfiConverterSequenceFloat.write(value.uvs, into: &buf)
+        FfiConverterSequenceUInt32.write(value.indices, into: &buf)
+        FfiConverterSequenceFloat.write(value.transform, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dMesh_lift(_ buf: RustBuffer) throws -> L3dMesh {
+    return try FfiConverterTypeL3dMesh.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dMesh_lower(_ value: L3dMesh) -> RustBuffer {
+    return FfiConverterTypeL3dMesh.lower(value)
+}
+
+
+/**
+ * A geometry or joint in the structure tree
+ *
+ * `get_nodes` returns the tree flattened in depth-first order with the
+ * root at index 0; `parent` and `children` are indices into that list.
+ */
+public struct L3dNode: Equatable, Hashable {
+    public var kind: L3dNodeKind
+    /**
+     * Part name from structure.xml
+     */
+    public var name: String
+    /**
+     * Index of the parent node, `None` for the root
+     */
+    public var parent: UInt32?
+    /**
+     * Indices of the child nodes
+     */
+    public var children: [UInt32]
+    /**
+     * Position relative to the parent
+     */
+    public var position: L3dVec3
+    /**
+     * Rotation in degrees relative to the parent
+     */
+    public var rotation: L3dVec3
+    /**
+     * Geometry file path; `None` for joints
+     */
+    public var path: String?
+    /**
+     * Index into the model parts (same order as `get_part_count`) for
+     * geometries; `None` for joints
+     */
+    public var partIndex: UInt64?
+    /**
+     * Axis ranges for joints; `None` for geometries
+     */
+    public var joint: L3dJoint?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(kind: L3dNodeKind, 
+        /**
+         * Part name from structure.xml
+         */name: String, 
+        /**
+         * Index of the parent node, `None` for the root
+         */parent: UInt32?, 
+        /**
+         * Indices of the child nodes
+         */children: [UInt32], 
+        /**
+         * Position relative to the parent
+         */position: L3dVec3, 
+        /**
+         * Rotation in degrees relative to the parent
+         */rotation: L3dVec3, 
+        /**
+         * Geometry file path; `None` for joints
+         */path: String?, 
+        /**
+         * Index into the model parts (same order as `get_part_count`) for
+         * geometries; `None` for joints
+         */partIndex: UInt64?, 
+        /**
+         * Axis ranges for joints; `None` for geometries
+         */joint: L3dJoint?) {
+        self.kind = kind
+        self.name = name
+        self.parent = parent
+        self.children = children
+        self.position = position
+        self.rotation = rotation
+        self.path = path
+        self.partIndex = partIndex
+        self.joint = joint
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension L3dNode: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeL3dNode: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dNode {
+        return
+            try L3dNode(
+                kind: FfiConverterTypeL3dNodeKind.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf), 
+                parent: FfiConverterOptionUInt32.read(from: &buf), 
+                children: FfiConverterSequenceUInt32.read(from: &buf), 
+                position: FfiConverterTypeL3dVec3.read(from: &buf), 
+                rotation: FfiConverterTypeL3dVec3.read(from: &buf), 
+                path: FfiConverterOptionString.read(from: &buf), 
+                partIndex: FfiConverterOptionUInt64.read(from: &buf), 
+                joint: FfiConverterOptionTypeL3dJoint.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: L3dNode, into buf: inout [UInt8]) {
+        FfiConverterTypeL3dNodeKind.write(value.kind, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterOptionUInt32.write(value.parent, into: &buf)
+        FfiConverterSequenceUInt32.write(value.children, into: &buf)
+        FfiConverterTypeL3dVec3.write(value.position, into: &buf)
+        FfiConverterTypeL3dVec3.write(value.rotation, into: &buf)
+        FfiConverterOptionString.write(value.path, into: &buf)
+        FfiConverterOptionUInt64.write(value.partIndex, into: &buf)
+        FfiConverterOptionTypeL3dJoint.write(value.joint, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dNode_lift(_ buf: RustBuffer) throws -> L3dNode {
+    return try FfiConverterTypeL3dNode.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dNode_lower(_ value: L3dNode) -> RustBuffer {
+    return FfiConverterTypeL3dNode.lower(value)
+}
+
+
+/**
+ * A geometry part with its transformation matrix
+ */
+public struct L3dPart: Equatable, Hashable {
+    /**
+     * Part name from structure.xml
+     */
+    public var name: String
+    /**
+     * Path to the geometry file (e.g., "geom_1/luminaire.obj")
+     */
+    public var path: String
+    /**
+     * World position of the part origin (x, y, z)
+     */
+    public var position: L3dVec3
+    /**
+     * Rotation in degrees relative to the parent (x, y, z)
+     */
+    public var rotation: L3dVec3
+    /**
+     * 4x4 world transformation matrix including the unit scale (16 floats, column-major)
+     */
+    public var transform: [Float]
+    /**
+     * 4x4 translation and rotation relative to the parent (16 floats, column-major)
+     */
+    public var localTransform: [Float]
+    /**
+     * Unit scale of the geometry file to meters
+     */
+    public var scale: Float
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Part name from structure.xml
+         */name: String, 
+        /**
+         * Path to the geometry file (e.g., "geom_1/luminaire.obj")
+         */path: String, 
+        /**
+         * World position of the part origin (x, y, z)
+         */position: L3dVec3, 
+        /**
+         * Rotation in degrees relative to the parent (x, y, z)
+         */rotation: L3dVec3, 
+        /**
+         * 4x4 world transformation matrix including the unit scale (16 floats, column-major)
+         */transform: [Float], 
+        /**
+         * 4x4 translation and rotation relative to the parent (16 floats, column-major)
+         */localTransform: [Float], 
+        /**
+         * Unit scale of the geometry file to meters
+         */scale: Float) {
+        self.name = name
+        self.path = path
+        self.position = position
+        self.rotation = rotation
+        self.transform = transform
+        self.localTransform = localTransform
+        self.scale = scale
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension L3dPart: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeL3dPart: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dPart {
+        return
+            try L3dPart(
+                name: FfiConverterString.read(from: &buf), 
+                path: FfiConverterString.read(from: &buf), 
+                position: FfiConverterTypeL3dVec3.read(from: &buf), 
+                rotation: FfiConverterTypeL3dVec3.read(from: &buf), 
+                transform: FfiConverterSequenceFloat.read(from: &buf), 
+                localTransform: FfiConverterSequenceFloat.read(from: &buf), 
+                scale: FfiConverterFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: L3dPart, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterTypeL3dVec3.write(value.position, into: &buf)
+        FfiConverterTypeL3dVec3.write(value.rotation, into: &buf)
+        FfiConverterSequenceFloat.write(value.transform, into: &buf)
+        FfiConverterSequenceFloat.write(value.localTransform, into: &buf)
+        FfiConverterFloat.write(value.scale, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dPart_lift(_ buf: RustBuffer) throws -> L3dPart {
+    return try FfiConverterTypeL3dPart.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dPart_lower(_ value: L3dPart) -> RustBuffer {
+    return FfiConverterTypeL3dPart.lower(value)
+}
+
+
+/**
+ * World transform of a model part
+ */
+public struct L3dPartTransform: Equatable, Hashable {
+    /**
+     * Index into the model parts (same order as `get_part_count`)
+     */
+    public var partIndex: UInt64
+    /**
+     * Part name from structure.xml
+     */
+    public var name: String
+    /**
+     * Path to the geometry file of the part
+     */
+    public var path: String
+    /**
+     * 4x4 transformation matrix (16 floats, column-major)
+     */
+    public var transform: [Float]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Index into the model parts (same order as `get_part_count`)
+         */partIndex: UInt64, 
+        /**
+         * Part name from structure.xml
+         */name: String, 
+        /**
+         * Path to the geometry file of the part
+         */path: String, 
+        /**
+         * 4x4 transformation matrix (16 floats, column-major)
+         */transform: [Float]) {
+        self.partIndex = partIndex
+        self.name = name
+        self.path = path
+        self.transform = transform
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension L3dPartTransform: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeL3dPartTransform: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dPartTransform {
+        return
+            try L3dPartTransform(
+                partIndex: FfiConverterUInt64.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf), 
+                path: FfiConverterString.read(from: &buf), 
+                transform: FfiConverterSequenceFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: L3dPartTransform, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.partIndex, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterSequenceFloat.write(value.transform, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dPartTransform_lift(_ buf: RustBuffer) throws -> L3dPartTransform {
+    return try FfiConverterTypeL3dPartTransform.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dPartTransform_lower(_ value: L3dPartTransform) -> RustBuffer {
+    return FfiConverterTypeL3dPartTransform.lower(value)
+}
+
+
+/**
+ * Closest intersection of a ray with the luminaire
+ */
+public struct L3dPickHit: Equatable, Hashable {
+    /**
+     * Index into the model parts (same order as `get_part_count`)
+     */
+    public var partIndex: UInt64
+    /**
+     * Part name from structure.xml
+     */
+    public var partName: String
+    /**
+     * Path to the geometry file of the part
+     */
+    public var path: String
+    /**
+     * Index of the hit face in the OBJ file
+     */
+    public var faceIndex: UInt64
+    /**
+     * World position of the hit
+     */
+    public var position: L3dVec3
+    /**
+     * Unit surface normal at the hit, facing the ray origin
+     */
+    public var normal: L3dVec3
+    /**
+     * Distance from the ray origin to the hit
+     */
+    public var distance: Float
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Index into the model parts (same order as `get_part_count`)
+         */partIndex: UInt64, 
+        /**
+         * Part name from structure.xml
+         */partName: String, 
+        /**
+         * Path to the geometry file of the part
+         */path: String, 
+        /**
+         * Index of the hit face in the OBJ file
+         */faceIndex: UInt64, 
+        /**
+         * World position of the hit
+         */position: L3dVec3, 
+        /**
+         * Unit surface normal at the hit, facing the ray origin
+         */normal: L3dVec3, 
+        /**
+         * Distance from the ray origin to the hit
+         */distance: Float) {
+        self.partIndex = partIndex
+        self.partName = partName
+        self.path = path
+        self.faceIndex = faceIndex
+        self.position = position
+        self.normal = normal
+        self.distance = distance
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension L3dPickHit: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeL3dPickHit: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dPickHit {
+        return
+            try L3dPickHit(
+                partIndex: FfiConverterUInt64.read(from: &buf), 
+                partName: FfiConverterString.read(from: &buf), 
+                path: FfiConverterString.read(from: &buf), 
+                faceIndex: FfiConverterUInt64.read(from: &buf), 
+                position: FfiConverterTypeL3dVec3.read(from: &buf), 
+                normal: FfiConverterTypeL3dVec3.read(from: &buf), 
+                distance: FfiConverterFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: L3dPickHit, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.partIndex, into: &buf)
+        FfiConverterString.write(value.partName, into: &buf)
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterUInt64.write(value.faceIndex, into: &buf)
+        FfiConverterTypeL3dVec3.write(value.position, into: &buf)
+        FfiConverterTypeL3dVec3.write(value.normal, into: &buf)
+        FfiConverterFloat.write(value.distance, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dPickHit_lift(_ buf: RustBuffer) throws -> L3dPickHit {
+    return try FfiConverterTypeL3dPickHit.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dPickHit_lower(_ value: L3dPickHit) -> RustBuffer {
+    return FfiConverterTypeL3dPickHit.lower(value)
+}
+
+
+/**
+ * GPU-ready geometry of one part
+ *
+ * Vertices are interleaved position, normal and texture coordinate
+ * (8 little-endian `f32` values, `vertex_stride` bytes per vertex);
+ * indices are a little-endian `u32` triangle list. Both can be uploaded
+ * to a vertex and index buffer as-is.
+ */
+public struct L3dRenderBuffer: Equatable, Hashable {
+    /**
+     * Part name from structure.xml
+     */
+    public var name: String
+    /**
+     * Path to the geometry file of the part
+     */
+    public var path: String
+    /**
+     * Interleaved vertex data
+     */
+    public var vertices: Data
+    /**
+     * Triangle indices
+     */
+    public var indices: Data
+    /**
+     * Bytes per vertex
+     */
+    public var vertexStride: UInt32
+    /**
+     * Number of vertices
+     */
+    public var vertexCount: UInt64
+    /**
+     * Number of indices
+     */
+    public var indexCount: UInt64
+    /**
+     * 4x4 model matrix (16 floats, column-major); identity in world space
+     */
+    public var transform: [Float]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Part name from structure.xml
+         */name: String, 
+        /**
+         * Path to the geometry file of the part
+         */path: String, 
+        /**
+         * Interleaved vertex data
+         */vertices: Data, 
+        /**
+         * Triangle indices
+         */indices: Data, 
+        /**
+         * Bytes per vertex
+         */vertexStride: UInt32, 
+        /**
+         * Number of vertices
+         */vertexCount: UInt64, 
+        /**
+         * Number of indices
+         */indexCount: UInt64, 
+        /**
+         * 4x4 model matrix (16 floats, column-major); identity in world space
+         */transform: [Float]) {
+        self.name = name
+        self.path = path
+        self.vertices = vertices
+        self.indices = indices
+        self.vertexStride = vertexStride
+        self.vertexCount = vertexCount
+        self.indexCount = indexCount
+        self.transform = transform
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension L3dRenderBuffer: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeL3dRenderBuffer: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dRenderBuffer {
+        return
+            try L3dRenderBuffer(
+                name: FfiConverterString.read(from: &buf), 
+                path: FfiConverterString.read(from: &buf), 
+                vertices: FfiConverterData.read(from: &buf), 
+                indices: FfiConverterData.read(from: &buf), 
+                vertexStride: FfiConverterUInt32.read(from: &buf), 
+                vertexCount: FfiConverterUInt64.read(from: &buf), 
+                indexCount: FfiConverterUInt64.read(from: &buf), 
+                transform: FfiConverterSequenceFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: L3dRenderBuffer, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterData.write(value.vertices, into: &buf)
+        FfiConverterData.write(value.indices, into: &buf)
+        FfiConverterUInt32.write(value.vertexStride, into: &buf)
+        FfiConverterUInt64.write(value.vertexCount, into: &buf)
+        FfiConverterUInt64.write(value.indexCount, into: &buf)
+        FfiConverterSequenceFloat.write(value.transform, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dRenderBuffer_lift(_ buf: RustBuffer) throws -> L3dRenderBuffer {
+    return try FfiConverterTypeL3dRenderBuffer.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dRenderBuffer_lower(_ value: L3dRenderBuffer) -> RustBuffer {
+    return FfiConverterTypeL3dRenderBuffer.lower(value)
+}
+
+
+/**
+ * 3D vector (x, y, z)
+ */
+public struct L3dVec3: Equatable, Hashable {
+    public var x: Float
+    public var y: Float
+    public var z: Float
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(x: Float, y: Float, z: Float) {
+        self.x = x
+        self.y = y
+        self.z = z
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension L3dVec3: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeL3dVec3: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dVec3 {
+        return
+            try L3dVec3(
+                x: FfiConverterFloat.read(from: &buf), 
+                y: FfiConverterFloat.read(from: &buf), 
+                z: FfiConverterFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: L3dVec3, into buf: inout [UInt8]) {
+        FfiConverterFloat.write(value.x, into: &buf)
+        FfiConverterFloat.write(value.y, into: &buf)
+        FfiConverterFloat.write(value.z, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dVec3_lift(_ buf: RustBuffer) throws -> L3dVec3 {
+    return try FfiConverterTypeL3dVec3.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dVec3_lower(_ value: L3dVec3) -> RustBuffer {
+    return FfiConverterTypeL3dVec3.lower(value)
+}
+
+
+/**
+ * Error types for L3D operations
+ */
+public enum L3dError: Swift.Error, Equatable, Hashable, Foundation.LocalizedError {
+
+    
+    
+    case ParseError
+    case FileError(String
+    )
+    case JsonError(String
+    )
+    case InvalidData
+    case Cancelled
+
+    
+
+    
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+    
+}
+
+#if compiler(>=6)
+extension L3dError: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeL3dError: FfiConverterRustBuffer {
+    typealias SwiftType = L3dError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .ParseError
+        case 2: return .FileError(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 3: return .JsonError(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 4: return .InvalidData
+        case 5: return .Cancelled
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: L3dError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case .ParseError:
+            writeInt(&buf, Int32(1))
+        
+        
+        case let .FileError(v1):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .JsonError(v1):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case .InvalidData:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .Cancelled:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dError_lift(_ buf: RustBuffer) throws -> L3dError {
+    return try FfiConverterTypeL3dError.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dError_lower(_ value: L3dError) -> RustBuffer {
+    return FfiConverterTypeL3dError.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Stage of loading an L3D archive, see [`L3dLoadProgress`]
+ */
+
+public enum L3dLoadStage: Equatable, Hashable {
+    
+    /**
+     * Decompressing the entries of the ZIP archive
+     */
+    case readingArchive
+    /**
+     * Parsing structure.xml
+     */
+    case parsingStructure
+    /**
+     * Computing the part matrices
+     */
+    case buildingModel
+    /**
+     * Loading is complete
+     */
+    case done
+
+
+
+}
+
+#if compiler(>=6)
+extension L3dLoadStage: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeL3dLoadStage: FfiConverterRustBuffer {
+    typealias SwiftType = L3dLoadStage
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dLoadStage {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .readingArchive
+        
+        case 2: return .parsingStructure
+        
+        case 3: return .buildingModel
+        
+        case 4: return .done
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: L3dLoadStage, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .readingArchive:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .parsingStructure:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .buildingModel:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .done:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dLoadStage_lift(_ buf: RustBuffer) throws -> L3dLoadStage {
+    return try FfiConverterTypeL3dLoadStage.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dLoadStage_lower(_ value: L3dLoadStage) -> RustBuffer {
+    return FfiConverterTypeL3dLoadStage.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Kind of an [`L3dNode`]
+ */
+
+public enum L3dNodeKind: Equatable, Hashable {
+    
+    case geometry
+    case joint
+
+
+
+}
+
+#if compiler(>=6)
+extension L3dNodeKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeL3dNodeKind: FfiConverterRustBuffer {
+    typealias SwiftType = L3dNodeKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dNodeKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .geometry
+        
+        case 2: return .joint
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: L3dNodeKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .geometry:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .joint:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dNodeKind_lift(_ buf: RustBuffer) throws -> L3dNodeKind {
+    return try FfiConverterTypeL3dNodeKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dNodeKind_lower(_ value: L3dNodeKind) -> RustBuffer {
+    return FfiConverterTypeL3dNodeKind.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Severity of an [`L3dDiagnostic`]
+ */
+
+public enum L3dSeverity: Equatable, Hashable {
+    
+    /**
+     * Worth knowing, but the file renders correctly
+     */
+    case info
+    /**
+     * The file is usable but probably not what the author intended
+     */
+    case warning
+    /**
+     * The file (or a part of it) cannot be rendered correctly
+     */
+    case error
+
+
+
+}
+
+#if compiler(>=6)
+extension L3dSeverity: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeL3dSeverity: FfiConverterRustBuffer {
+    typealias SwiftType = L3dSeverity
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dSeverity {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .info
+        
+        case 2: return .warning
+        
+        case 3: return .error
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: L3dSeverity, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .info:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .warning:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .error:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dSeverity_lift(_ buf: RustBuffer) throws -> L3dSeverity {
+    return try FfiConverterTypeL3dSeverity.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL3dSeverity_lower(_ value: L3dSeverity) -> RustBuffer {
+    return FfiConverterTypeL3dSeverity.lower(value)
+}
+
+
+
+
+
+/**
+ * Receives the progress of `L3dFile.load` and can cancel it
+ *
+ * Implemented in Kotlin or Swift; called on the thread that loads.
+ */
+public protocol L3dLoadObserver: AnyObject, Sendable {
+    
+    /**
+     * Called when a stage starts, after every archive entry and between
+     * the chunks of a large entry
+     */
+    func onProgress(progress: L3dLoadProgress) 
+    
+    /**
+     * Checked after every progress report; return `true` to stop loading
+     */
+    func isCancelled()  -> Bool
+    
+}
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceL3dLoadObserver {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceL3dLoadObserver] = [UniffiVTableCallbackInterfaceL3dLoadObserver(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterCallbackInterfaceL3dLoadObserver.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface L3dLoadObserver: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterCallbackInterfaceL3dLoadObserver.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface L3dLoadObserver: handle missing in uniffiClone")
+            }
+        },
+        onProgress: { (
+            uniffiHandle: UInt64,
+            progress: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceL3dLoadObserver.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onProgress(
+                     progress: try FfiConverterTypeL3dLoadProgress_lift(progress)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        isCancelled: { (
+            uniffiHandle: UInt64,
+            uniffiOutReturn: UnsafeMutablePointer<Int8>,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> Bool in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceL3dLoadObserver.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.isCancelled(
+                )
+            }
+
+            
+            let writeReturn = { uniffiOutReturn.pointee = FfiConverterBool.lower($0) }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        }
+    )]
+}
+
+private func uniffiCallbackInitL3dLoadObserver() {
+    uniffi_l3d_ffi_fn_init_callback_vtable_l3dloadobserver(UniffiCallbackInterfaceL3dLoadObserver.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceL3dLoadObserver {
+    fileprivate static let handleMap = UniffiHandleMap<L3dLoadObserver>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceL3dLoadObserver : FfiConverter {
+    typealias SwiftType = L3dLoadObserver
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceL3dLoadObserver_lift(_ handle: UInt64) throws -> L3dLoadObserver {
+    return try FfiConverterCallbackInterfaceL3dLoadObserver.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceL3dLoadObserver_lower(_ v: L3dLoadObserver) -> UInt64 {
+    return FfiConverterCallbackInterfaceL3dLoadObserver.lower(v)
+}
+
+
+
+
+/**
+ * Receives the log messages of `L3dFile.load`
+ */
+public protocol L3dLogger: AnyObject, Sendable {
+    
+    func log(message: String) 
+    
+}
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceL3dLogger {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceL3dLogger] = [UniffiVTableCallbackInterfaceL3dLogger(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterCallbackInterfaceL3dLogger.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface L3dLogger: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterCallbackInterfaceL3dLogger.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface L3dLogger: handle missing in uniffiClone")
+            }
+        },
+        log: { (
+            uniffiHandle: UInt64,
+            message: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceL3dLogger.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.log(
+                     message: try FfiConverterString.lift(message)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        }
+    )]
+}
+
+private func uniffiCallbackInitL3dLogger() {
+    uniffi_l3d_ffi_fn_init_callback_vtable_l3dlogger(UniffiCallbackInterfaceL3dLogger.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceL3dLogger {
+    fileprivate static let handleMap = UniffiHandleMap<L3dLogger>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceL3dLogger : FfiConverter {
+    typealias SwiftType = L3dLogger
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceL3dLogger_lift(_ handle: UInt64) throws -> L3dLogger {
+    return try FfiConverterCallbackInterfaceL3dLogger.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceL3dLogger_lower(_ v: L3dLogger) -> UInt64 {
+    return FfiConverterCallbackInterfaceL3dLogger.lower(v)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = UInt32?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt32.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt32.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeL3dAxis: FfiConverterRustBuffer {
+    typealias SwiftType = L3dAxis?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeL3dAxis.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeL3dAxis.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeL3dJoint: FfiConverterRustBuffer {
+    typealias SwiftType = L3dJoint?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeL3dJoint.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeL3dJoint.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeL3dVec3_lower(_ value: L3dVec3) -> RustBuffer {
-    return FfiConverterTypeL3dVec3.lower(value)
-}
-
-
-/**
- * Error types for L3D operations
- */
-public enum L3dError: Swift.Error, Equatable, Hashable, Foundation.LocalizedError {
-
-    
-    
-    case ParseError
-    case FileError(String
-    )
-    case JsonError(String
-    )
-    case InvalidData
+fileprivate struct FfiConverterOptionTypeL3dPickHit: FfiConverterRustBuffer {
+    typealias SwiftType = L3dPickHit?
 
-    
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeL3dPickHit.write(value, into: &buf)
+    }
 
-    
-    public var errorDescription: String? {
-        String(reflecting: self)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeL3dPickHit.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
-    
 }
 
-#if compiler(>=6)
-extension L3dError: Sendable {}
-#endif
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeL3dError: FfiConverterRustBuffer {
-    typealias SwiftType = L3dError
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L3dError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-
-        
+fileprivate struct FfiConverterOptionTypeL3dVec3: FfiConverterRustBuffer {
+    typealias SwiftType = L3dVec3?
 
-        
-        case 1: return .ParseError
-        case 2: return .FileError(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 3: return .JsonError(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 4: return .InvalidData
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeL3dVec3.write(value, into: &buf)
+    }
 
-         default: throw UniffiInternalError.unexpectedEnumCase
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeL3dVec3.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
+}
 
-    public static func write(_ value: L3dError, into buf: inout [UInt8]) {
-        switch value {
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionCallbackInterfaceL3dLoadObserver: FfiConverterRustBuffer {
+    typealias SwiftType = L3dLoadObserver?
 
-        
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterCallbackInterfaceL3dLoadObserver.write(value, into: &buf)
+    }
 
-        
-        
-        case .ParseError:
-            writeInt(&buf, Int32(1))
-        
-        
-        case let .FileError(v1):
-            writeInt(&buf, Int32(2))
-            FfiConverterString.write(v1, into: &buf)
-            
-        
-        case let .JsonError(v1):
-            writeInt(&buf, Int32(3))
-            FfiConverterString.write(v1, into: &buf)
-            
-        
-        case .InvalidData:
-            writeInt(&buf, Int32(4))
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterCallbackInterfaceL3dLoadObserver.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeL3dError_lift(_ buf: RustBuffer) throws -> L3dError {
-    return try FfiConverterTypeL3dError.lift(buf)
+fileprivate struct FfiConverterOptionCallbackInterfaceL3dLogger: FfiConverterRustBuffer {
+    typealias SwiftType = L3dLogger?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterCallbackInterfaceL3dLogger.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterCallbackInterfaceL3dLogger.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeL3dError_lower(_ value: L3dError) -> RustBuffer {
-    return FfiConverterTypeL3dError.lower(value)
+fileprivate struct FfiConverterSequenceUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = [UInt32]
+
+    public static func write(_ value: [UInt32], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterUInt32.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UInt32] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UInt32]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterUInt32.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
@@ -1241,6 +3355,56 @@ fileprivate struct FfiConverterSequenceTypeL3dAsset: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeL3dDiagnostic: FfiConverterRustBuffer {
+    typealias SwiftType = [L3dDiagnostic]
+
+    public static func write(_ value: [L3dDiagnostic], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeL3dDiagnostic.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [L3dDiagnostic] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [L3dDiagnostic]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeL3dDiagnostic.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeL3dJoint: FfiConverterRustBuffer {
+    typealias SwiftType = [L3dJoint]
+
+    public static func write(_ value: [L3dJoint], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeL3dJoint.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [L3dJoint] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [L3dJoint]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeL3dJoint.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -1266,6 +3430,56 @@ fileprivate struct FfiConverterSequenceTypeL3dLightEmitter: FfiConverterRustBuff
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeL3dMesh: FfiConverterRustBuffer {
+    typealias SwiftType = [L3dMesh]
+
+    public static func write(_ value: [L3dMesh], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeL3dMesh.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [L3dMesh] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [L3dMesh]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeL3dMesh.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeL3dNode: FfiConverterRustBuffer {
+    typealias SwiftType = [L3dNode]
+
+    public static func write(_ value: [L3dNode], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeL3dNode.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [L3dNode] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [L3dNode]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeL3dNode.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -1290,6 +3504,82 @@ fileprivate struct FfiConverterSequenceTypeL3dPart: FfiConverterRustBuffer {
         return seq
     }
 }
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeL3dPartTransform: FfiConverterRustBuffer {
+    typealias SwiftType = [L3dPartTransform]
+
+    public static func write(_ value: [L3dPartTransform], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeL3dPartTransform.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [L3dPartTransform] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [L3dPartTransform]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeL3dPartTransform.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeL3dRenderBuffer: FfiConverterRustBuffer {
+    typealias SwiftType = [L3dRenderBuffer]
+
+    public static func write(_ value: [L3dRenderBuffer], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeL3dRenderBuffer.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [L3dRenderBuffer] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [L3dRenderBuffer]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeL3dRenderBuffer.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDictionaryStringTypeL3dVec3: FfiConverterRustBuffer {
+    public static func write(_ value: [String: L3dVec3], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for (key, value) in value {
+            FfiConverterString.write(key, into: &buf)
+            FfiConverterTypeL3dVec3.write(value, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String: L3dVec3] {
+        let len: Int32 = try readInt(&buf)
+        var dict = [String: L3dVec3]()
+        dict.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            let key = try FfiConverterString.read(from: &buf)
+            let value = try FfiConverterTypeL3dVec3.read(from: &buf)
+            dict[key] = value
+        }
+        return dict
+    }
+}
 /**
  * Get the library version
  */
@@ -1324,28 +3614,66 @@ private let initializationResult: InitializationResult = {
     if (uniffi_l3d_ffi_checksum_method_l3dfile_get_assets() != 31708) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_l3d_ffi_checksum_method_l3dfile_get_header() != 65472) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_l3d_ffi_checksum_method_l3dfile_get_joints() != 28880) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_l3d_ffi_checksum_method_l3dfile_get_light_emitters() != 6160) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_l3d_ffi_checksum_method_l3dfile_get_meshes() != 1552) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_l3d_ffi_checksum_method_l3dfile_get_nodes() != 53319) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_l3d_ffi_checksum_method_l3dfile_get_part_count() != 30682) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_l3d_ffi_checksum_method_l3dfile_get_parts() != 55447) {
+    if (uniffi_l3d_ffi_checksum_method_l3dfile_get_part_transforms() != 56447) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_l3d_ffi_checksum_method_l3dfile_get_parts() != 17518) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_l3d_ffi_checksum_method_l3dfile_get_render_buffers() != 9069) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_l3d_ffi_checksum_method_l3dfile_get_structure_xml() != 46302) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_l3d_ffi_checksum_method_l3dfile_pick() != 34943) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_l3d_ffi_checksum_method_l3dfile_to_json() != 64554) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_l3d_ffi_checksum_method_l3dfile_validate() != 10373) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_l3d_ffi_checksum_constructor_l3dfile_from_path() != 46012) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_l3d_ffi_checksum_constructor_l3dfile_load() != 39678) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_l3d_ffi_checksum_constructor_l3dfile_new() != 53902) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_l3d_ffi_checksum_method_l3dloadobserver_on_progress() != 20834) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_l3d_ffi_checksum_method_l3dloadobserver_is_cancelled() != 43942) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_l3d_ffi_checksum_method_l3dlogger_log() != 61462) {
+        return InitializationResult.apiChecksumMismatch
+    }
 
+    uniffiCallbackInitL3dLoadObserver()
+    uniffiCallbackInitL3dLogger()
     return InitializationResult.ok
 }()
 
@@ -242,6 +242,46 @@ typedef struct UniffiForeignFutureResultVoid {
 typedef void (*UniffiForeignFutureCompleteVoid)(uint64_t, UniffiForeignFutureResultVoid
     );
 
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_L3D_LOAD_OBSERVER_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_L3D_LOAD_OBSERVER_METHOD0
+typedef void (*UniffiCallbackInterfaceL3dLoadObserverMethod0)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_L3D_LOAD_OBSERVER_METHOD1
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_L3D_LOAD_OBSERVER_METHOD1
+typedef void (*UniffiCallbackInterfaceL3dLoadObserverMethod1)(uint64_t, int8_t* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_L3D_LOGGER_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_L3D_LOGGER_METHOD0
+typedef void (*UniffiCallbackInterfaceL3dLoggerMethod0)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_L3D_LOAD_OBSERVER
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_L3D_LOAD_OBSERVER
+typedef struct UniffiVTableCallbackInterfaceL3dLoadObserver {
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+    UniffiCallbackInterfaceClone _Nonnull uniffiClone;
+    UniffiCallbackInterfaceL3dLoadObserverMethod0 _Nonnull onProgress;
+    UniffiCallbackInterfaceL3dLoadObserverMethod1 _Nonnull isCancelled;
+} UniffiVTableCallbackInterfaceL3dLoadObserver;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_L3D_LOGGER
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_L3D_LOGGER
+typedef struct UniffiVTableCallbackInterfaceL3dLogger {
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+    UniffiCallbackInterfaceClone _Nonnull uniffiClone;
+    UniffiCallbackInterfaceL3dLoggerMethod0 _Nonnull log;
+} UniffiVTableCallbackInterfaceL3dLogger;
+
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_CLONE_L3DFILE
 #define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_CLONE_L3DFILE
@@ -258,6 +298,11 @@ void uniffi_l3d_ffi_fn_free_l3dfile(uint64_t handle, RustCallStatus *_Nonnull ou
 uint64_t uniffi_l3d_ffi_fn_constructor_l3dfile_from_path(RustBuffer path, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_CONSTRUCTOR_L3DFILE_LOAD
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_CONSTRUCTOR_L3DFILE_LOAD
+uint64_t uniffi_l3d_ffi_fn_constructor_l3dfile_load(RustBuffer data, RustBuffer observer, RustBuffer logger, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_CONSTRUCTOR_L3DFILE_NEW
 #define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_CONSTRUCTOR_L3DFILE_NEW
 uint64_t uniffi_l3d_ffi_fn_constructor_l3dfile_new(RustBuffer data, RustCallStatus *_Nonnull out_status
@@ -273,31 +318,81 @@ uint64_t uniffi_l3d_ffi_fn_method_l3dfile_get_asset_count(uint64_t ptr, RustCall
 RustBuffer uniffi_l3d_ffi_fn_method_l3dfile_get_assets(uint64_t ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_HEADER
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_HEADER
+RustBuffer uniffi_l3d_ffi_fn_method_l3dfile_get_header(uint64_t ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_JOINTS
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_JOINTS
+RustBuffer uniffi_l3d_ffi_fn_method_l3dfile_get_joints(uint64_t ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_LIGHT_EMITTERS
 #define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_LIGHT_EMITTERS
 RustBuffer uniffi_l3d_ffi_fn_method_l3dfile_get_light_emitters(uint64_t ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_MESHES
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_MESHES
+RustBuffer uniffi_l3d_ffi_fn_method_l3dfile_get_meshes(uint64_t ptr, int8_t world_space, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_NODES
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_NODES
+RustBuffer uniffi_l3d_ffi_fn_method_l3dfile_get_nodes(uint64_t ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_PART_COUNT
 #define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_PART_COUNT
 uint64_t uniffi_l3d_ffi_fn_method_l3dfile_get_part_count(uint64_t ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_PART_TRANSFORMS
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_PART_TRANSFORMS
+RustBuffer uniffi_l3d_ffi_fn_method_l3dfile_get_part_transforms(uint64_t ptr, RustBuffer joint_angles, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_PARTS
 #define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_PARTS
 RustBuffer uniffi_l3d_ffi_fn_method_l3dfile_get_parts(uint64_t ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_RENDER_BUFFERS
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_RENDER_BUFFERS
+RustBuffer uniffi_l3d_ffi_fn_method_l3dfile_get_render_buffers(uint64_t ptr, int8_t world_space, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_STRUCTURE_XML
 #define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_GET_STRUCTURE_XML
 RustBuffer uniffi_l3d_ffi_fn_method_l3dfile_get_structure_xml(uint64_t ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_PICK
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_PICK
+RustBuffer uniffi_l3d_ffi_fn_method_l3dfile_pick(uint64_t ptr, RustBuffer origin, RustBuffer direction, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_TO_JSON
 #define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_TO_JSON
 RustBuffer uniffi_l3d_ffi_fn_method_l3dfile_to_json(uint64_t ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_VALIDATE
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_METHOD_L3DFILE_VALIDATE
+RustBuffer uniffi_l3d_ffi_fn_method_l3dfile_validate(uint64_t ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_INIT_CALLBACK_VTABLE_L3DLOADOBSERVER
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_INIT_CALLBACK_VTABLE_L3DLOADOBSERVER
+void uniffi_l3d_ffi_fn_init_callback_vtable_l3dloadobserver(const UniffiVTableCallbackInterfaceL3dLoadObserver* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_INIT_CALLBACK_VTABLE_L3DLOGGER
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_INIT_CALLBACK_VTABLE_L3DLOGGER
+void uniffi_l3d_ffi_fn_init_callback_vtable_l3dlogger(const UniffiVTableCallbackInterfaceL3dLogger* _Nonnull vtable
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_FUNC_VERSION
 #define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_FN_FUNC_VERSION
 RustBuffer uniffi_l3d_ffi_fn_func_version(RustCallStatus *_Nonnull out_status
@@ -580,48 +675,120 @@ uint16_t uniffi_l3d_ffi_checksum_method_l3dfile_get_asset_count(void
 #define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_ASSETS
 uint16_t uniffi_l3d_ffi_checksum_method_l3dfile_get_assets(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_HEADER
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_HEADER
+uint16_t uniffi_l3d_ffi_checksum_method_l3dfile_get_header(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_JOINTS
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_JOINTS
+uint16_t uniffi_l3d_ffi_checksum_method_l3dfile_get_joints(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_LIGHT_EMITTERS
 #define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_LIGHT_EMITTERS
 uint16_t uniffi_l3d_ffi_checksum_method_l3dfile_get_light_emitters(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_MESHES
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_MESHES
+uint16_t uniffi_l3d_ffi_checksum_method_l3dfile_get_meshes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_NODES
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_NODES
+uint16_t uniffi_l3d_ffi_checksum_method_l3dfile_get_nodes(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_PART_COUNT
 #define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_PART_COUNT
 uint16_t uniffi_l3d_ffi_checksum_method_l3dfile_get_part_count(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_PART_TRANSFORMS
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_PART_TRANSFORMS
+uint16_t uniffi_l3d_ffi_checksum_method_l3dfile_get_part_transforms(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_PARTS
 #define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_PARTS
 uint16_t uniffi_l3d_ffi_checksum_method_l3dfile_get_parts(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_RENDER_BUFFERS
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_RENDER_BUFFERS
+uint16_t uniffi_l3d_ffi_checksum_method_l3dfile_get_render_buffers(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_STRUCTURE_XML
 #define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_GET_STRUCTURE_XML
 uint16_t uniffi_l3d_ffi_checksum_method_l3dfile_get_structure_xml(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_PICK
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_PICK
+uint16_t uniffi_l3d_ffi_checksum_method_l3dfile_pick(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_TO_JSON
 #define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_TO_JSON
 uint16_t uniffi_l3d_ffi_checksum_method_l3dfile_to_json(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_VALIDATE
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DFILE_VALIDATE
+uint16_t uniffi_l3d_ffi_checksum_method_l3dfile_validate(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_CONSTRUCTOR_L3DFILE_FROM_PATH
 #define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_CONSTRUCTOR_L3DFILE_FROM_PATH
 uint16_t uniffi_l3d_ffi_checksum_constructor_l3dfile_from_path(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_CONSTRUCTOR_L3DFILE_LOAD
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_CONSTRUCTOR_L3DFILE_LOAD
+uint16_t uniffi_l3d_ffi_checksum_constructor_l3dfile_load(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_CONSTRUCTOR_L3DFILE_NEW
 #define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_CONSTRUCTOR_L3DFILE_NEW
 uint16_t uniffi_l3d_ffi_checksum_constructor_l3dfile_new(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DLOADOBSERVER_ON_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DLOADOBSERVER_ON_PROGRESS
+uint16_t uniffi_l3d_ffi_checksum_method_l3dloadobserver_on_progress(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DLOADOBSERVER_IS_CANCELLED
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DLOADOBSERVER_IS_CANCELLED
+uint16_t uniffi_l3d_ffi_checksum_method_l3dloadobserver_is_cancelled(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DLOGGER_LOG
+#define UNIFFI_FFIDEF_UNIFFI_L3D_FFI_CHECKSUM_METHOD_L3DLOGGER_LOG
+uint16_t uniffi_l3d_ffi_checksum_method_l3dlogger_log(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_FFI_L3D_FFI_UNIFFI_CONTRACT_VERSION
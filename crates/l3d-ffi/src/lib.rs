@@ -23,9 +23,28 @@
 //! let parts = l3d.getParts()
 //! let json = try l3d.toJson()
 //! ```
+//!
+//! ### Adjustable joints
+//!
+//! ```swift
+//! let nodes = l3d.getNodes()
+//! let transforms = l3d.getPartTransforms(jointAngles: ["tilt": L3dVec3(x: 0, y: 30, z: 0)])
+//! ```
+//!
+//! ### Picking
+//!
+//! ```kotlin
+//! val hit = l3d.pick(origin, direction)
+//! hit?.let { println("Tapped ${it.partName}") }
+//! ```
 
-use l3d_rs::{from_buffer, L3d, Luminaire};
-use std::sync::Arc;
+use l3d_rs::load::{load, Cancelled, LoadObserver, LoadOptions, LoadProgress, LoadStage};
+use l3d_rs::{
+    dmat4_to_mat4, from_buffer, validate, Axis, BuildOptions, Geometry, Joint, L3d, Logger,
+    Luminaire, PickScene, Ray, RenderOptions, Severity, TransformSpace, Vec3f, VERTEX_STRIDE,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
 
 uniffi::setup_scaffolding!();
 
@@ -40,6 +59,8 @@ pub enum L3dError {
     JsonError(String),
     #[error("Invalid data")]
     InvalidData,
+    #[error("Loading was cancelled")]
+    Cancelled,
 }
 
 /// 3D vector (x, y, z)
@@ -57,12 +78,16 @@ pub struct L3dPart {
     pub name: String,
     /// Path to the geometry file (e.g., "geom_1/luminaire.obj")
     pub path: String,
-    /// Position offset (x, y, z)
+    /// World position of the part origin (x, y, z)
     pub position: L3dVec3,
-    /// Rotation in degrees (x, y, z)
+    /// Rotation in degrees relative to the parent (x, y, z)
     pub rotation: L3dVec3,
-    /// 4x4 transformation matrix (16 floats, column-major)
+    /// 4x4 world transformation matrix including the unit scale (16 floats, column-major)
     pub transform: Vec<f32>,
+    /// 4x4 translation and rotation relative to the parent (16 floats, column-major)
+    pub local_transform: Vec<f32>,
+    /// Unit scale of the geometry file to meters
+    pub scale: f32,
 }
 
 /// A light emitting object with position and direction
@@ -82,6 +107,150 @@ pub struct L3dLightEmitter {
     pub size_y: f64,
 }
 
+/// Range of an adjustable joint axis in degrees
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct L3dAxis {
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+}
+
+/// An adjustable joint between a geometry and its child geometries
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct L3dJoint {
+    /// Joint part name from structure.xml
+    pub name: String,
+    /// Position relative to the parent geometry
+    pub position: L3dVec3,
+    /// Rotation in degrees relative to the parent geometry
+    pub rotation: L3dVec3,
+    /// Range of rotation about the joint's X axis, if adjustable
+    pub x_axis: Option<L3dAxis>,
+    /// Range of rotation about the joint's Y axis, if adjustable
+    pub y_axis: Option<L3dAxis>,
+    /// Range of rotation about the joint's Z axis, if adjustable
+    pub z_axis: Option<L3dAxis>,
+    /// Initial joint angles in degrees
+    pub default_rotation: Option<L3dVec3>,
+}
+
+/// Kind of an [`L3dNode`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum L3dNodeKind {
+    Geometry,
+    Joint,
+}
+
+/// A geometry or joint in the structure tree
+///
+/// `get_nodes` returns the tree flattened in depth-first order with the
+/// root at index 0; `parent` and `children` are indices into that list.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct L3dNode {
+    pub kind: L3dNodeKind,
+    /// Part name from structure.xml
+    pub name: String,
+    /// Index of the parent node, `None` for the root
+    pub parent: Option<u32>,
+    /// Indices of the child nodes
+    pub children: Vec<u32>,
+    /// Position relative to the parent
+    pub position: L3dVec3,
+    /// Rotation in degrees relative to the parent
+    pub rotation: L3dVec3,
+    /// Geometry file path; `None` for joints
+    pub path: Option<String>,
+    /// Index into the model parts (same order as `get_part_count`) for
+    /// geometries; `None` for joints
+    pub part_index: Option<u64>,
+    /// Axis ranges for joints; `None` for geometries
+    pub joint: Option<L3dJoint>,
+}
+
+/// World transform of a model part
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct L3dPartTransform {
+    /// Index into the model parts (same order as `get_part_count`)
+    pub part_index: u64,
+    /// Part name from structure.xml
+    pub name: String,
+    /// Path to the geometry file of the part
+    pub path: String,
+    /// 4x4 transformation matrix (16 floats, column-major)
+    pub transform: Vec<f32>,
+}
+
+/// Closest intersection of a ray with the luminaire
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct L3dPickHit {
+    /// Index into the model parts (same order as `get_part_count`)
+    pub part_index: u64,
+    /// Part name from structure.xml
+    pub part_name: String,
+    /// Path to the geometry file of the part
+    pub path: String,
+    /// Index of the hit face in the OBJ file
+    pub face_index: u64,
+    /// World position of the hit
+    pub position: L3dVec3,
+    /// Unit surface normal at the hit, facing the ray origin
+    pub normal: L3dVec3,
+    /// Distance from the ray origin to the hit
+    pub distance: f32,
+}
+
+/// GPU-ready geometry of one part
+///
+/// Vertices are interleaved position, normal and texture coordinate
+/// (8 little-endian `f32` values, `vertex_stride` bytes per vertex);
+/// indices are a little-endian `u32` triangle list. Both can be uploaded
+/// to a vertex and index buffer as-is.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct L3dRenderBuffer {
+    /// Part name from structure.xml
+    pub name: String,
+    /// Path to the geometry file of the part
+    pub path: String,
+    /// Interleaved vertex data
+    pub vertices: Vec<u8>,
+    /// Triangle indices
+    pub indices: Vec<u8>,
+    /// Bytes per vertex
+    pub vertex_stride: u32,
+    /// Number of vertices
+    pub vertex_count: u64,
+    /// Number of indices
+    pub index_count: u64,
+    /// 4x4 model matrix (16 floats, column-major); identity in world space
+    pub transform: Vec<f32>,
+}
+
+/// Parsed geometry of one part as separate attribute arrays
+///
+/// OBJ faces are triangulated and every vertex has a position, normal and
+/// texture coordinate, so the arrays map directly to e.g. SceneKit
+/// geometry sources or Filament vertex buffer attributes.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct L3dMesh {
+    /// Index into the model parts (same order as `get_part_count`)
+    pub part_index: u64,
+    /// Part name from structure.xml
+    pub name: String,
+    /// Path to the geometry file of the part
+    pub path: String,
+    /// Vertex positions (x, y, z per vertex)
+    pub positions: Vec<f32>,
+    /// Vertex normals (x, y, z per vertex); corners without a normal in the
+    /// OBJ get the normal of their face, zero only for degenerate faces
+    pub normals: Vec<f32>,
+    /// Texture coordinates (u, v per vertex), zero if the OBJ has none
+    pub uvs: Vec<f32>,
+    /// Triangle list indices into the vertices
+    pub indices: Vec<u32>,
+    /// 4x4 model matrix (16 floats, column-major); identity in world space
+    pub transform: Vec<f32>,
+}
+
 /// An asset file from the L3D archive
 #[derive(Debug, Clone, uniffi::Record)]
 pub struct L3dAsset {
@@ -91,10 +260,99 @@ pub struct L3dAsset {
     pub content: Vec<u8>,
 }
 
+/// Metadata from the `Header` of structure.xml
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct L3dHeader {
+    /// Name of the luminaire, if given
+    pub name: Option<String>,
+    /// Description of the luminaire, if given
+    pub description: Option<String>,
+    /// Application that wrote the file
+    pub created_with_application: String,
+    /// Creation time as written in the file (ISO 8601)
+    pub creation_time_code: String,
+    /// Format version as `major.minor` or `major.minor-pre`, if given
+    pub format_version: Option<String>,
+}
+
+/// Severity of an [`L3dDiagnostic`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum L3dSeverity {
+    /// Worth knowing, but the file renders correctly
+    Info,
+    /// The file is usable but probably not what the author intended
+    Warning,
+    /// The file (or a part of it) cannot be rendered correctly
+    Error,
+}
+
+/// A problem found by `validate`
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct L3dDiagnostic {
+    /// How serious the finding is
+    pub severity: L3dSeverity,
+    /// Stable, machine-readable identifier (e.g. "unknown-geometry-id")
+    pub code: String,
+    /// Human-readable description
+    pub message: String,
+    /// Part name from structure.xml the finding relates to, if any
+    pub part_name: Option<String>,
+}
+
+/// Stage of loading an L3D archive, see [`L3dLoadProgress`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum L3dLoadStage {
+    /// Decompressing the entries of the ZIP archive
+    ReadingArchive,
+    /// Parsing structure.xml
+    ParsingStructure,
+    /// Computing the part matrices
+    BuildingModel,
+    /// Loading is complete
+    Done,
+}
+
+/// Progress of `L3dFile.load`
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct L3dLoadProgress {
+    /// Current stage
+    pub stage: L3dLoadStage,
+    /// Archive entries read so far
+    pub entries_read: u64,
+    /// Number of entries in the archive
+    pub entry_count: u64,
+    /// Uncompressed bytes read so far
+    pub bytes_read: u64,
+    /// Uncompressed size of all entries
+    pub total_bytes: u64,
+    /// Fraction of the archive read, from 0 to 1
+    pub fraction: f64,
+}
+
+/// Receives the progress of `L3dFile.load` and can cancel it
+///
+/// Implemented in Kotlin or Swift; called on the thread that loads.
+#[uniffi::export(callback_interface)]
+pub trait L3dLoadObserver: Send + Sync {
+    /// Called when a stage starts, after every archive entry and between
+    /// the chunks of a large entry
+    fn on_progress(&self, progress: L3dLoadProgress);
+    /// Checked after every progress report; return `true` to stop loading
+    fn is_cancelled(&self) -> bool;
+}
+
+/// Receives the log messages of `L3dFile.load`
+#[uniffi::export(callback_interface)]
+pub trait L3dLogger: Send + Sync {
+    fn log(&self, message: String);
+}
+
 /// Main L3D file interface
 #[derive(uniffi::Object)]
 pub struct L3dFile {
     inner: L3d,
+    /// Built on the first call to `pick`
+    pick_scene: OnceLock<PickScene>,
 }
 
 #[uniffi::export]
@@ -106,7 +364,41 @@ impl L3dFile {
         if inner.file.structure.is_empty() {
             return Err(L3dError::ParseError);
         }
-        Ok(Arc::new(Self { inner }))
+        Ok(Arc::new(Self {
+            inner,
+            pick_scene: OnceLock::new(),
+        }))
+    }
+
+    /// Parse L3D data from bytes, reporting progress to `observer` and
+    /// messages to `logger`
+    ///
+    /// Call it off the UI thread. Fails with `Cancelled` as soon as the
+    /// observer cancels, and with `ParseError` for unreadable files.
+    #[uniffi::constructor]
+    pub fn load(
+        data: Vec<u8>,
+        observer: Option<Box<dyn L3dLoadObserver>>,
+        logger: Option<Box<dyn L3dLogger>>,
+    ) -> Result<Arc<Self>, L3dError> {
+        let observer = observer.map(ObserverAdapter);
+        let logger = logger.map(LoggerAdapter);
+        let options = LoadOptions {
+            observer: observer.as_ref().map(|o| o as &dyn LoadObserver),
+            logger: logger.as_ref().map(|l| l as &dyn Logger),
+            ..Default::default()
+        };
+        let inner = load(&data, &options).map_err(|e| {
+            if e.is::<Cancelled>() {
+                L3dError::Cancelled
+            } else {
+                L3dError::ParseError
+            }
+        })?;
+        Ok(Arc::new(Self {
+            inner,
+            pick_scene: OnceLock::new(),
+        }))
     }
 
     /// Parse L3D data from a file path
@@ -130,51 +422,148 @@ impl L3dFile {
             .map_err(|e| L3dError::JsonError(e.to_string()))
     }
 
-    /// Get all geometry parts with their transformations
-    pub fn get_parts(&self) -> Vec<L3dPart> {
-        // Parse the structure to get detailed position/rotation info
-        if let Ok(luminaire) = Luminaire::from_xml(&self.inner.file.structure) {
-            let mut parts = Vec::new();
-            // Start with identity matrix as root transform
-            extract_geometry_parts(
-                &luminaire.structure.geometry,
-                &mut parts,
-                &luminaire.geometry_definitions.geometry_file_definition,
-                &l3d_rs::MAT4_IDENTITY,
-            );
-            return parts;
-        }
+    /// Get the metadata from the structure.xml header
+    pub fn get_header(&self) -> Result<L3dHeader, L3dError> {
+        let header = Luminaire::from_xml(&self.inner.file.structure)
+            .map_err(|_| L3dError::ParseError)?
+            .header;
+        Ok(L3dHeader {
+            name: header.name().map(str::to_string),
+            description: header.description().map(str::to_string),
+            created_with_application: header.created_with_application().to_string(),
+            creation_time_code: header.creation_time_code().to_string(),
+            format_version: header.format_version(),
+        })
+    }
 
-        // Fallback to basic model parts
-        self.inner
-            .model
-            .parts
-            .iter()
-            .map(|p| L3dPart {
-                name: String::new(),
-                path: p.path.clone(),
-                position: L3dVec3 {
-                    x: p.mat[12],
-                    y: p.mat[13],
-                    z: p.mat[14],
-                },
-                rotation: L3dVec3 {
-                    x: 0.0,
-                    y: 0.0,
-                    z: 0.0,
+    /// Check the file for problems such as missing or unreadable geometry,
+    /// unknown geometry IDs and duplicate part names
+    ///
+    /// An empty list means the file is fine.
+    pub fn validate(&self) -> Vec<L3dDiagnostic> {
+        validate(&self.inner)
+            .into_iter()
+            .map(|d| L3dDiagnostic {
+                severity: match d.severity {
+                    Severity::Info => L3dSeverity::Info,
+                    Severity::Warning => L3dSeverity::Warning,
+                    Severity::Error => L3dSeverity::Error,
                 },
-                transform: p.mat.to_vec(),
+                code: d.code,
+                message: d.message,
+                part_name: d.part_name,
+            })
+            .collect()
+    }
+
+    /// Get all geometry parts with their transformations
+    ///
+    /// Parts are in the same order as `get_part_count`, `pick` and
+    /// `get_render_buffers`; a part whose geometry ID is not defined has an
+    /// empty path.
+    pub fn get_parts(&self) -> Vec<L3dPart> {
+        let Ok(luminaire) = Luminaire::from_xml(&self.inner.file.structure) else {
+            return Vec::new();
+        };
+        luminaire
+            .resolve_parts(&BuildOptions::default())
+            .into_iter()
+            .map(|part| {
+                let [x, y, z] = part.world_position();
+                L3dPart {
+                    position: L3dVec3 {
+                        x: x as f32,
+                        y: y as f32,
+                        z: z as f32,
+                    },
+                    rotation: vec3_from(&part.rotation),
+                    transform: dmat4_to_mat4(&part.world).to_vec(),
+                    local_transform: dmat4_to_mat4(&part.local).to_vec(),
+                    scale: part.scale as f32,
+                    name: part.name,
+                    path: part.path,
+                }
             })
             .collect()
     }
 
     /// Get all light emitting objects
     pub fn get_light_emitters(&self) -> Vec<L3dLightEmitter> {
-        let mut emitters = Vec::new();
+        let Ok(luminaire) = Luminaire::from_xml(&self.inner.file.structure) else {
+            return Vec::new();
+        };
+        luminaire
+            .light_emitters()
+            .into_iter()
+            .map(|leo| L3dLightEmitter {
+                position: vec3_from(&leo.position),
+                rotation: vec3_from(&leo.rotation),
+                shape: leo.shape.to_string(),
+                name: leo.name,
+                size_x: leo.size_x,
+                size_y: leo.size_y,
+            })
+            .collect()
+    }
+
+    /// Get all joints in document order
+    pub fn get_joints(&self) -> Vec<L3dJoint> {
+        self.get_nodes()
+            .into_iter()
+            .filter_map(|node| node.joint)
+            .collect()
+    }
+
+    /// Get the geometry and joint hierarchy as a flattened tree
+    pub fn get_nodes(&self) -> Vec<L3dNode> {
+        let mut nodes = Vec::new();
         if let Ok(luminaire) = Luminaire::from_xml(&self.inner.file.structure) {
-            extract_light_emitters(&luminaire.structure.geometry, &mut emitters);
+            let part_indices: HashMap<*const Geometry, u64> = luminaire
+                .walk_geometries()
+                .iter()
+                .map(|visit| (visit.geometry as *const Geometry, visit.part_index as u64))
+                .collect();
+            add_geometry_node(
+                &luminaire,
+                &luminaire.structure.geometry,
+                None,
+                &part_indices,
+                &mut nodes,
+            );
         }
-        emitters
+        nodes
+    }
+
+    /// Recompute the world transforms of all parts for the given joint
+    /// angles in degrees, keyed by joint name
+    ///
+    /// Angles rotate about the joint's own axes and are clamped to its axis
+    /// ranges; axes without a range stay fixed. Joints that are not listed
+    /// keep the pose from structure.xml.
+    pub fn get_part_transforms(
+        &self,
+        joint_angles: HashMap<String, L3dVec3>,
+    ) -> Result<Vec<L3dPartTransform>, L3dError> {
+        let luminaire =
+            Luminaire::from_xml(&self.inner.file.structure).map_err(|_| L3dError::ParseError)?;
+        let options = BuildOptions {
+            joint_angles: joint_angles
+                .into_iter()
+                .map(|(name, v)| (name, vec3f(&v)))
+                .collect(),
+            ..Default::default()
+        };
+        Ok(luminaire
+            .resolve_parts(&options)
+            .into_iter()
+            .enumerate()
+            .map(|(part_index, part)| L3dPartTransform {
+                part_index: part_index as u64,
+                transform: dmat4_to_mat4(&part.world).to_vec(),
+                name: part.name,
+                path: part.path,
+            })
+            .collect())
     }
 
     /// Get all asset files (OBJ, textures, etc.)
@@ -190,6 +579,82 @@ impl L3dFile {
             .collect()
     }
 
+    /// Find the part hit by a ray, e.g. through a tapped pixel
+    ///
+    /// Origin and direction are in model coordinates (Z-up, meters), the
+    /// same space as the part transforms. Returns `None` if nothing is hit.
+    pub fn pick(&self, origin: L3dVec3, direction: L3dVec3) -> Option<L3dPickHit> {
+        let scene = self.pick_scene.get_or_init(|| self.inner.pick_scene());
+        let ray = Ray::new(
+            [origin.x, origin.y, origin.z],
+            [direction.x, direction.y, direction.z],
+        );
+        scene.pick(&ray).map(|hit| L3dPickHit {
+            part_index: hit.part_index as u64,
+            part_name: hit.part_name,
+            path: hit.path,
+            face_index: hit.face_index as u64,
+            position: vec3(hit.position),
+            normal: vec3(hit.normal),
+            distance: hit.distance,
+        })
+    }
+
+    /// Get GPU-ready vertex and index buffers for every part
+    ///
+    /// With `world_space` the part transforms are baked into the vertices;
+    /// otherwise draw each buffer with its `transform`.
+    pub fn get_render_buffers(&self, world_space: bool) -> Vec<L3dRenderBuffer> {
+        self.inner
+            .render_buffers(&render_options(world_space))
+            .into_iter()
+            .map(|buffer| L3dRenderBuffer {
+                vertices: buffer.vertex_bytes(),
+                indices: buffer.index_bytes(),
+                vertex_stride: (VERTEX_STRIDE * std::mem::size_of::<f32>()) as u32,
+                vertex_count: buffer.vertex_count() as u64,
+                index_count: buffer.indices.len() as u64,
+                transform: buffer.transform.to_vec(),
+                name: buffer.name,
+                path: buffer.path,
+            })
+            .collect()
+    }
+
+    /// Get the parsed geometry of every part as separate position, normal,
+    /// texture coordinate and index arrays
+    ///
+    /// With `world_space` the part transforms are baked into positions and
+    /// normals; otherwise place each mesh with its `transform`. Parts whose
+    /// geometry is missing or cannot be parsed are left out.
+    pub fn get_meshes(&self, world_space: bool) -> Vec<L3dMesh> {
+        self.inner
+            .render_buffers(&render_options(world_space))
+            .into_iter()
+            .map(|buffer| {
+                let count = buffer.vertex_count();
+                let mut positions = Vec::with_capacity(count * 3);
+                let mut normals = Vec::with_capacity(count * 3);
+                let mut uvs = Vec::with_capacity(count * 2);
+                for vertex in buffer.vertices.chunks_exact(VERTEX_STRIDE) {
+                    positions.extend_from_slice(&vertex[0..3]);
+                    normals.extend_from_slice(&vertex[3..6]);
+                    uvs.extend_from_slice(&vertex[6..8]);
+                }
+                L3dMesh {
+                    part_index: buffer.part_index as u64,
+                    name: buffer.name,
+                    path: buffer.path,
+                    positions,
+                    normals,
+                    uvs,
+                    indices: buffer.indices,
+                    transform: buffer.transform.to_vec(),
+                }
+            })
+            .collect()
+    }
+
     /// Get the number of geometry parts
     pub fn get_part_count(&self) -> u64 {
         self.inner.model.parts.len() as u64
@@ -205,157 +670,139 @@ impl L3dFile {
 // Helper functions (not exported via UniFFI)
 // ============================================================================
 
-/// Recursively extract geometry parts from the structure with accumulated transforms
-fn extract_geometry_parts(
-    geometry: &l3d_rs::Geometry,
-    parts: &mut Vec<L3dPart>,
-    defs: &[l3d_rs::GeometryFileDefinition],
-    parent_transform: &[f32; 16],
-) {
-    // Find the geometry file definition
-    let geom_id = &geometry.geometry_reference.geometry_id;
-    if let Some(def) = defs.iter().find(|d| &d.id == geom_id) {
-        let scale = l3d_rs::get_scale(&def.units);
-
-        // Build this geometry's local transform
-        let local_transform = l3d_rs::build_transform(&geometry.position, &geometry.rotation);
-
-        // Accumulate with parent transform
-        let accumulated = l3d_rs::mat4_mul(parent_transform, &local_transform);
-
-        // Apply scale
-        let scale_mat = l3d_rs::mat4_scale(scale);
-        let final_transform = l3d_rs::mat4_mul(&accumulated, &scale_mat);
-
-        // Extract world position from accumulated transform (translation is in columns 12,13,14)
-        let world_position = L3dVec3 {
-            x: accumulated[12],
-            y: accumulated[13],
-            z: accumulated[14],
-        };
-
-        parts.push(L3dPart {
-            name: geometry.part_name.clone(),
-            path: format!("{}/{}", def.id, def.filename),
-            position: world_position,
-            rotation: L3dVec3 {
-                x: geometry.rotation.x,
-                y: geometry.rotation.y,
-                z: geometry.rotation.z,
+/// Forwards core progress reports to a foreign [`L3dLoadObserver`]
+struct ObserverAdapter(Box<dyn L3dLoadObserver>);
+
+impl LoadObserver for ObserverAdapter {
+    fn on_progress(&self, progress: &LoadProgress) {
+        self.0.on_progress(L3dLoadProgress {
+            stage: match progress.stage {
+                LoadStage::ReadingArchive => L3dLoadStage::ReadingArchive,
+                LoadStage::ParsingStructure => L3dLoadStage::ParsingStructure,
+                LoadStage::BuildingModel => L3dLoadStage::BuildingModel,
+                LoadStage::Done => L3dLoadStage::Done,
             },
-            transform: final_transform.to_vec(),
+            entries_read: progress.entries_read as u64,
+            entry_count: progress.entry_count as u64,
+            bytes_read: progress.bytes_read,
+            total_bytes: progress.total_bytes,
+            fraction: progress.fraction(),
         });
+    }
 
-        // Process joints with accumulated transform (before scale)
-        if let Some(joints) = &geometry.joints {
-            for joint in &joints.joint {
-                // Build joint transform and accumulate
-                let joint_transform = l3d_rs::build_transform(&joint.position, &joint.rotation);
-                let joint_accumulated = l3d_rs::mat4_mul(&accumulated, &joint_transform);
+    fn is_cancelled(&self) -> bool {
+        self.0.is_cancelled()
+    }
+}
 
-                for child_geom in &joint.geometries.geometry {
-                    extract_geometry_parts(child_geom, parts, defs, &joint_accumulated);
-                }
-            }
-        }
-    } else {
-        // No geometry definition found, but still process joints
-        let local_transform = l3d_rs::build_transform(&geometry.position, &geometry.rotation);
-        let accumulated = l3d_rs::mat4_mul(parent_transform, &local_transform);
-
-        if let Some(joints) = &geometry.joints {
-            for joint in &joints.joint {
-                let joint_transform = l3d_rs::build_transform(&joint.position, &joint.rotation);
-                let joint_accumulated = l3d_rs::mat4_mul(&accumulated, &joint_transform);
-
-                for child_geom in &joint.geometries.geometry {
-                    extract_geometry_parts(child_geom, parts, defs, &joint_accumulated);
-                }
-            }
-        }
+/// Forwards core log messages to a foreign [`L3dLogger`]
+struct LoggerAdapter(Box<dyn L3dLogger>);
+
+impl Logger for LoggerAdapter {
+    fn log(&self, message: &str) {
+        self.0.log(message.to_string());
     }
 }
 
-/// Recursively extract light emitting objects from the structure
-fn extract_light_emitters(geometry: &l3d_rs::Geometry, emitters: &mut Vec<L3dLightEmitter>) {
-    // Extract light emitting objects from this geometry
-    if let Some(leo) = &geometry.light_emitting_objects {
-        // Access light_emitting_object field through JSON (it's private)
-        if let Ok(json) = serde_json::to_value(leo) {
-            if let Some(objects) = json.get("LightEmittingObject").and_then(|v| v.as_array()) {
-                for obj in objects {
-                    let name = obj
-                        .get("@partName")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("")
-                        .to_string();
-
-                    let position = obj
-                        .get("Position")
-                        .map(|p| L3dVec3 {
-                            x: p.get("@x").and_then(|v| v.as_f64()).unwrap_or(0.0) as f32,
-                            y: p.get("@y").and_then(|v| v.as_f64()).unwrap_or(0.0) as f32,
-                            z: p.get("@z").and_then(|v| v.as_f64()).unwrap_or(0.0) as f32,
-                        })
-                        .unwrap_or(L3dVec3 {
-                            x: 0.0,
-                            y: 0.0,
-                            z: 0.0,
-                        });
-
-                    let rotation = obj
-                        .get("Rotation")
-                        .map(|r| L3dVec3 {
-                            x: r.get("@x").and_then(|v| v.as_f64()).unwrap_or(0.0) as f32,
-                            y: r.get("@y").and_then(|v| v.as_f64()).unwrap_or(0.0) as f32,
-                            z: r.get("@z").and_then(|v| v.as_f64()).unwrap_or(0.0) as f32,
-                        })
-                        .unwrap_or(L3dVec3 {
-                            x: 0.0,
-                            y: 0.0,
-                            z: 0.0,
-                        });
-
-                    let (shape, size_x, size_y) = if let Some(rect) = obj.get("Rectangle") {
-                        (
-                            "rectangle".to_string(),
-                            rect.get("@sizeX").and_then(|v| v.as_f64()).unwrap_or(0.0),
-                            rect.get("@sizeY").and_then(|v| v.as_f64()).unwrap_or(0.0),
-                        )
-                    } else if let Some(circle) = obj.get("Circle") {
-                        (
-                            "circle".to_string(),
-                            circle
-                                .get("@diameter")
-                                .and_then(|v| v.as_f64())
-                                .unwrap_or(0.0),
-                            0.0,
-                        )
-                    } else {
-                        ("unknown".to_string(), 0.0, 0.0)
-                    };
-
-                    emitters.push(L3dLightEmitter {
-                        name,
-                        position,
-                        rotation,
-                        shape,
-                        size_x,
-                        size_y,
-                    });
-                }
-            }
-        }
+fn render_options(world_space: bool) -> RenderOptions {
+    RenderOptions {
+        space: if world_space {
+            TransformSpace::World
+        } else {
+            TransformSpace::Local
+        },
+        ..Default::default()
     }
+}
 
-    // Recursively process joints
-    if let Some(joints) = &geometry.joints {
-        for joint in &joints.joint {
-            for child_geom in &joint.geometries.geometry {
-                extract_light_emitters(child_geom, emitters);
-            }
+fn vec3([x, y, z]: [f32; 3]) -> L3dVec3 {
+    L3dVec3 { x, y, z }
+}
+
+fn vec3_from(v: &Vec3f) -> L3dVec3 {
+    vec3([v.x, v.y, v.z])
+}
+
+fn vec3f(v: &L3dVec3) -> Vec3f {
+    Vec3f {
+        x: v.x,
+        y: v.y,
+        z: v.z,
+    }
+}
+
+fn axis(axis: &Option<Axis>) -> Option<L3dAxis> {
+    axis.as_ref().map(|a| L3dAxis {
+        min: a.min,
+        max: a.max,
+        step: a.step,
+    })
+}
+
+fn joint_record(joint: &Joint) -> L3dJoint {
+    L3dJoint {
+        name: joint.part_name.clone(),
+        position: vec3_from(&joint.position),
+        rotation: vec3_from(&joint.rotation),
+        x_axis: axis(&joint.x_axis),
+        y_axis: axis(&joint.y_axis),
+        z_axis: axis(&joint.z_axis),
+        default_rotation: joint.default_rotation.as_ref().map(vec3_from),
+    }
+}
+
+/// Append a geometry and its subtree to `nodes` and return its index
+///
+/// `part_indices` maps geometries to model parts as numbered by
+/// [`Luminaire::walk_geometries`].
+fn add_geometry_node(
+    luminaire: &Luminaire,
+    geometry: &Geometry,
+    parent: Option<u32>,
+    part_indices: &HashMap<*const Geometry, u64>,
+    nodes: &mut Vec<L3dNode>,
+) -> u32 {
+    let index = nodes.len() as u32;
+    let path = luminaire
+        .geometry_definitions
+        .geometry_file_definition
+        .iter()
+        .find(|d| d.id == geometry.geometry_reference.geometry_id)
+        .map(|d| format!("{}/{}", d.id, d.filename));
+    nodes.push(L3dNode {
+        kind: L3dNodeKind::Geometry,
+        name: geometry.part_name.clone(),
+        parent,
+        children: Vec::new(),
+        position: vec3_from(&geometry.position),
+        rotation: vec3_from(&geometry.rotation),
+        path,
+        part_index: part_indices.get(&(geometry as *const Geometry)).copied(),
+        joint: None,
+    });
+
+    for joint in geometry.joints.iter().flat_map(|j| &j.joint) {
+        let joint_index = nodes.len() as u32;
+        nodes[index as usize].children.push(joint_index);
+        nodes.push(L3dNode {
+            kind: L3dNodeKind::Joint,
+            name: joint.part_name.clone(),
+            parent: Some(index),
+            children: Vec::new(),
+            position: vec3_from(&joint.position),
+            rotation: vec3_from(&joint.rotation),
+            path: None,
+            part_index: None,
+            joint: Some(joint_record(joint)),
+        });
+        for child in &joint.geometries.geometry {
+            let child_index =
+                add_geometry_node(luminaire, child, Some(joint_index), part_indices, nodes);
+            nodes[joint_index as usize].children.push(child_index);
         }
     }
+
+    index
 }
 
 /// Get the library version
@@ -363,3 +810,6 @@ fn extract_light_emitters(geometry: &l3d_rs::Geometry, emitters: &mut Vec<L3dLig
 pub fn version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,222 @@
+use super::*;
+
+/// L3D files shared with the core crate's tests
+const TEST_FILES: [&str; 2] = ["minimal.l3d", "rotated_parts.l3d"];
+
+fn load(name: &str) -> (Vec<u8>, Arc<L3dFile>) {
+    let path = format!(
+        "{}/../l3d_rs/tests/data/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    );
+    let data = std::fs::read(path).unwrap();
+    (data.clone(), L3dFile::new(data).unwrap())
+}
+
+fn xyz(v: &L3dVec3) -> [f32; 3] {
+    [v.x, v.y, v.z]
+}
+
+#[test]
+fn test_parts_match_core_model() {
+    for name in TEST_FILES {
+        let (data, file) = load(name);
+        let model = from_buffer(&data).model;
+        let parts = file.get_parts();
+        assert_eq!(parts.len() as u64, file.get_part_count());
+        assert_eq!(parts.len(), model.parts.len());
+
+        for (part, core) in parts.iter().zip(&model.parts) {
+            assert_eq!(part.name, core.name);
+            assert_eq!(part.path, core.path);
+            assert_eq!(part.transform, core.mat.to_vec());
+            assert_eq!(
+                xyz(&part.position),
+                [core.mat[12], core.mat[13], core.mat[14]]
+            );
+        }
+    }
+}
+
+#[test]
+fn test_parts_match_render_buffers_and_transforms() {
+    for name in TEST_FILES {
+        let (_, file) = load(name);
+        let parts = file.get_parts();
+        for buffer in file.get_render_buffers(false) {
+            let part = parts.iter().find(|p| p.name == buffer.name).unwrap();
+            assert_eq!(part.transform, buffer.transform);
+        }
+
+        let transforms = file.get_part_transforms(HashMap::new()).unwrap();
+        assert_eq!(transforms.len(), parts.len());
+        for (t, part) in transforms.iter().zip(&parts) {
+            assert_eq!(t.name, part.name);
+            assert_eq!(t.transform, part.transform);
+        }
+    }
+}
+
+#[test]
+fn test_part_local_transform() {
+    let (_, file) = load("rotated_parts.l3d");
+    let parts = file.get_parts();
+    let nodes = file.get_nodes();
+    for part in &parts {
+        assert_eq!(part.scale, 1.0);
+        let node = nodes
+            .iter()
+            .find(|n| n.kind == L3dNodeKind::Geometry && n.name == part.name)
+            .unwrap();
+        assert_eq!(xyz(&part.rotation), xyz(&node.rotation));
+        let local = l3d_rs::build_transform(&vec3f(&node.position), &vec3f(&node.rotation));
+        for (a, b) in part.local_transform.iter().zip(&local) {
+            assert!((a - b).abs() < 1e-6);
+        }
+    }
+
+    // Part indices of the node tree address get_parts
+    for node in nodes.iter().filter(|n| n.kind == L3dNodeKind::Geometry) {
+        let index = node.part_index.unwrap() as usize;
+        assert_eq!(parts[index].name, node.name);
+    }
+}
+
+#[test]
+fn test_header() {
+    let (_, file) = load("minimal.l3d");
+    let header = file.get_header().unwrap();
+    assert_eq!(header.created_with_application, "l3d-rs-test");
+    assert_eq!(header.creation_time_code, "2024-01-01T00:00:00Z");
+    assert_eq!(header.name, None);
+    assert_eq!(header.format_version, None);
+}
+
+#[test]
+fn test_validate() {
+    let (data, file) = load("minimal.l3d");
+    assert!(file
+        .validate()
+        .iter()
+        .all(|d| d.severity != L3dSeverity::Error));
+
+    // Point the geometry at an undefined ID and write the archive back
+    let mut l3d = from_buffer(&data);
+    l3d.file.structure = l3d
+        .file
+        .structure
+        .replace(r#"geometryId="geom_1""#, r#"geometryId="missing""#);
+    let file = L3dFile::new(l3d.file.to_bytes().unwrap()).unwrap();
+    let diagnostics = file.validate();
+    let unknown = diagnostics
+        .iter()
+        .find(|d| d.code == "unknown-geometry-id")
+        .unwrap();
+    assert_eq!(unknown.severity, L3dSeverity::Error);
+    assert_eq!(unknown.part_name.as_deref(), Some("body"));
+    assert!(!unknown.message.is_empty());
+}
+
+#[test]
+fn test_meshes() {
+    for name in TEST_FILES {
+        let (_, file) = load(name);
+        let parts = file.get_parts();
+        let meshes = file.get_meshes(false);
+        let buffers = file.get_render_buffers(false);
+        assert!(!meshes.is_empty());
+        assert_eq!(meshes.len(), buffers.len());
+
+        for (mesh, buffer) in meshes.iter().zip(&buffers) {
+            let count = mesh.positions.len() / 3;
+            assert_eq!(count as u64, buffer.vertex_count);
+            assert_eq!(mesh.normals.len(), count * 3);
+            assert_eq!(mesh.uvs.len(), count * 2);
+            assert_eq!(mesh.indices.len() as u64, buffer.index_count);
+            assert!(mesh.indices.iter().all(|&i| (i as usize) < count));
+            assert_eq!(mesh.name, parts[mesh.part_index as usize].name);
+            assert_eq!(mesh.transform, parts[mesh.part_index as usize].transform);
+        }
+
+        // World space meshes have the part transforms applied
+        for (world, local) in file.get_meshes(true).iter().zip(&meshes) {
+            let matrix: [f32; 16] = local.transform.clone().try_into().unwrap();
+            assert_eq!(world.transform, l3d_rs::MAT4_IDENTITY.to_vec());
+            assert_eq!(world.indices, local.indices);
+            for (w, l) in world.positions.chunks(3).zip(local.positions.chunks(3)) {
+                let p = l3d_rs::mat4_transform_point(&matrix, [l[0], l[1], l[2]]);
+                for k in 0..3 {
+                    assert!((w[k] - p[k]).abs() < 1e-5);
+                }
+            }
+        }
+    }
+}
+
+/// Records progress and messages, cancelling after `cancel_after` entries
+struct Recorder {
+    cancel_after: Option<u64>,
+    progress: std::sync::Mutex<Vec<L3dLoadProgress>>,
+    messages: Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+impl L3dLoadObserver for Arc<Recorder> {
+    fn on_progress(&self, progress: L3dLoadProgress) {
+        self.progress.lock().unwrap().push(progress);
+    }
+
+    fn is_cancelled(&self) -> bool {
+        let progress = self.progress.lock().unwrap();
+        let entries = progress.last().map_or(0, |p| p.entries_read);
+        self.cancel_after.is_some_and(|n| entries >= n)
+    }
+}
+
+impl L3dLogger for Arc<Recorder> {
+    fn log(&self, message: String) {
+        self.messages.lock().unwrap().push(message);
+    }
+}
+
+fn recorder(cancel_after: Option<u64>) -> Arc<Recorder> {
+    Arc::new(Recorder {
+        cancel_after,
+        progress: Default::default(),
+        messages: Default::default(),
+    })
+}
+
+#[test]
+fn test_load_with_progress() {
+    let (data, file) = load("rotated_parts.l3d");
+    let rec = recorder(None);
+    let loaded = L3dFile::load(
+        data,
+        Some(Box::new(rec.clone())),
+        Some(Box::new(rec.clone())),
+    )
+    .unwrap();
+    assert_eq!(loaded.get_part_count(), file.get_part_count());
+
+    let progress = rec.progress.lock().unwrap();
+    let last = progress.last().unwrap();
+    assert_eq!(progress[0].stage, L3dLoadStage::ReadingArchive);
+    assert_eq!(last.stage, L3dLoadStage::Done);
+    assert_eq!(last.entries_read, last.entry_count);
+    assert_eq!(last.fraction, 1.0);
+    assert_eq!(rec.messages.lock().unwrap().len(), progress.len());
+}
+
+#[test]
+fn test_load_cancel() {
+    let (data, _) = load("minimal.l3d");
+    let rec = recorder(Some(1));
+    let result = L3dFile::load(data, Some(Box::new(rec.clone())), None);
+    assert!(matches!(result, Err(L3dError::Cancelled)));
+    assert_eq!(rec.progress.lock().unwrap().last().unwrap().entries_read, 1);
+
+    assert!(matches!(
+        L3dFile::load(vec![1, 2, 3], None, None),
+        Err(L3dError::ParseError)
+    ));
+}
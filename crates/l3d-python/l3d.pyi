@@ -0,0 +1,153 @@
+"""Python bindings for l3d_rs - L3D luminaire file format parser."""
+
+from os import PathLike
+from typing import Optional, Tuple, Union
+
+import numpy as np
+import numpy.typing as npt
+
+__version__: str
+
+class L3dError(Exception):
+    """Error reading or writing an L3D file."""
+
+class Part:
+    """A geometry part with its world transform."""
+
+    @property
+    def name(self) -> str:
+        """Part name from `structure.xml`."""
+    @property
+    def path(self) -> str:
+        """Path of the geometry file in the archive."""
+    @property
+    def transform(self) -> npt.NDArray[np.float64]:
+        """4x4 world matrix in meters, indexed `[row, column]`."""
+
+class Mesh:
+    """Triangle mesh of one part or of the whole model.
+
+    Vertices are unique combinations of position, normal and texture
+    coordinate.
+    """
+
+    @property
+    def name(self) -> Optional[str]:
+        """Part name, or `None` for a merged mesh."""
+    @property
+    def path(self) -> Optional[str]:
+        """Geometry file path, or `None` for a merged mesh."""
+    @property
+    def positions(self) -> npt.NDArray[np.float32]:
+        """Vertex positions, shape `(N, 3)`."""
+    @property
+    def normals(self) -> npt.NDArray[np.float32]:
+        """Unit vertex normals, shape `(N, 3)`."""
+    @property
+    def uvs(self) -> npt.NDArray[np.float32]:
+        """Texture coordinates, shape `(N, 2)`."""
+    @property
+    def faces(self) -> npt.NDArray[np.uint32]:
+        """Counter-clockwise triangles as vertex indices, shape `(M, 3)`."""
+    @property
+    def part_indices(self) -> npt.NDArray[np.uint32]:
+        """Index into `L3dFile.parts` of every triangle, shape `(M,)`."""
+    @property
+    def transform(self) -> npt.NDArray[np.float32]:
+        """4x4 matrix placing the mesh in the model; identity in world space."""
+    def __len__(self) -> int: ...
+
+class LightEmitter:
+    """A light emitting object of a geometry."""
+
+    @property
+    def name(self) -> str: ...
+    @property
+    def geometry_part_name(self) -> str: ...
+    @property
+    def position(self) -> Tuple[float, float, float]: ...
+    @property
+    def rotation(self) -> Tuple[float, float, float]: ...
+    @property
+    def shape(self) -> str:
+        """`"rectangle"`, `"circle"` or `"unknown"`."""
+    @property
+    def size_x(self) -> float:
+        """Width of a rectangle or diameter of a circle."""
+    @property
+    def size_y(self) -> float:
+        """Height of a rectangle, zero for circles."""
+
+class Header:
+    """Metadata from the `Header` of `structure.xml`."""
+
+    @property
+    def name(self) -> Optional[str]: ...
+    @property
+    def description(self) -> Optional[str]: ...
+    @property
+    def created_with_application(self) -> str: ...
+    @property
+    def creation_time_code(self) -> str: ...
+    @property
+    def format_version(self) -> Optional[str]: ...
+
+class L3dFile:
+    """A parsed L3D file."""
+
+    def __init__(self, data: bytes) -> None:
+        """Parse an L3D archive from bytes; raises `L3dError` on failure."""
+    @staticmethod
+    def open(path: Union[str, PathLike[str]]) -> L3dFile:
+        """Load an L3D archive from a path."""
+    @property
+    def parts(self) -> list[Part]: ...
+    def meshes(self, world_space: bool = True) -> list[Mesh]:
+        """Triangle meshes of all parts with parsable geometry."""
+    def merged_mesh(self) -> Mesh:
+        """All parts combined into one world space mesh."""
+    @property
+    def light_emitters(self) -> list[LightEmitter]: ...
+    @property
+    def header(self) -> Header: ...
+    @property
+    def structure_xml(self) -> str: ...
+    @property
+    def asset_names(self) -> list[str]: ...
+    def asset(self, name: str) -> Optional[bytes]:
+        """Contents of an asset file, or `None` if there is none."""
+    def to_json(self) -> str:
+        """The luminaire structure as JSON text."""
+    def set_part_position(self, name: str, position: Tuple[float, float, float]) -> None:
+        """Move a geometry or joint relative to its parent; raises `KeyError`."""
+    def set_part_rotation(self, name: str, rotation: Tuple[float, float, float]) -> None:
+        """Set the rotation in degrees of a geometry or joint; raises `KeyError`."""
+    def set_joint_range(self, name: str, axis: str, min: float, max: float, step: float) -> None:
+        """Set the allowed angles of a joint axis (`"x"`, `"y"` or `"z"`)."""
+    def rename_part(self, old: str, new: str) -> None:
+        """Rename a geometry, joint or light emitting object."""
+    def set_asset(self, name: str, data: bytes) -> None:
+        """Replace the contents of an asset, or add a new one."""
+    def to_bytes(self) -> bytes:
+        """The archive with all changes as `.l3d` bytes."""
+    def save(self, path: Union[str, PathLike[str]]) -> None:
+        """Write the archive with all changes to a path."""
+    def __len__(self) -> int: ...
+
+def from_bytes(data: bytes) -> L3dFile:
+    """Parse an L3D file from bytes."""
+
+def from_file(path: Union[str, PathLike[str]]) -> L3dFile:
+    """Load an L3D file from a path."""
+
+def parse_l3d(data: bytes) -> str:
+    """Parse an L3D file from bytes and return JSON representation."""
+
+def get_model_parts(data: bytes) -> str:
+    """Parse an L3D file and return model parts as JSON."""
+
+def parse_structure_xml(xml: str) -> str:
+    """Parse structure.xml content directly and return JSON."""
+
+def load_l3d(path: Union[str, PathLike[str]]) -> str:
+    """Load an L3D file from path and return JSON representation."""
@@ -1,42 +1,566 @@
+//! # l3d
+//!
+//! Python bindings for l3d_rs - L3D luminaire file format parser.
+//!
+//! [`L3dFile`] wraps a parsed archive and hands out typed objects:
+//! [`Part`] with its transform as a NumPy array, [`Mesh`] with triangle
+//! geometry as NumPy arrays, [`LightEmitter`] and [`Header`]. Parts can be
+//! moved, renamed and given new joint ranges, assets replaced, and the
+//! result saved as a new archive. Errors from the core library are raised
+//! as `l3d.L3dError`, file system errors as the matching `OSError` subclass.
+//!
+//! ```python
+//! import l3d
+//!
+//! f = l3d.from_file("luminaire.l3d")
+//! for part in f.parts:
+//!     print(part.name, part.path, part.transform[:3, 3])
+//! ```
+
+use l3d_rs::{
+    Axis, BuildOptions, L3d, Luminaire, ObjMesh, RenderOptions, TransformSpace, Vec3f, VERTEX_STRIDE,
+};
+use numpy::ndarray::{Array1, Array2};
+use numpy::{IntoPyArray, PyArray1, PyArray2};
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyKeyError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+create_exception!(
+    l3d,
+    L3dError,
+    PyException,
+    "Error reading or writing an L3D file"
+);
+
+/// Convert a core library error into an `L3dError`
+fn l3d_error(e: anyhow::Error) -> PyErr {
+    L3dError::new_err(format!("{:#}", e))
+}
+
+/// A parsed L3D file
+#[pyclass(module = "l3d", name = "L3dFile")]
+pub struct L3dFile {
+    inner: L3d,
+}
+
+/// A geometry part with its world transform
+#[pyclass(module = "l3d", frozen)]
+#[derive(Clone)]
+pub struct Part {
+    /// Part name from `structure.xml`
+    #[pyo3(get)]
+    name: String,
+    /// Path of the geometry file in the archive
+    #[pyo3(get)]
+    path: String,
+    matrix: l3d_rs::DMat4,
+}
+
+/// Triangle mesh of one part or of the whole model
+///
+/// Vertices are unique combinations of position, normal and texture
+/// coordinate, so a position shared by faces with different normals
+/// appears more than once.
+#[pyclass(module = "l3d", frozen)]
+pub struct Mesh {
+    /// Part name, or `None` for a merged mesh
+    #[pyo3(get)]
+    name: Option<String>,
+    /// Geometry file path, or `None` for a merged mesh
+    #[pyo3(get)]
+    path: Option<String>,
+    vertices: Vec<f32>,
+    indices: Vec<u32>,
+    face_parts: Vec<u32>,
+    matrix: l3d_rs::Mat4,
+}
+
+/// A light emitting object of a geometry
+#[pyclass(module = "l3d", frozen, get_all)]
+#[derive(Clone)]
+pub struct LightEmitter {
+    /// Part name of the light emitting object
+    name: String,
+    /// Part name of the geometry it belongs to
+    geometry_part_name: String,
+    /// Position relative to the geometry
+    position: (f64, f64, f64),
+    /// Rotation in degrees relative to the geometry
+    rotation: (f64, f64, f64),
+    /// `"rectangle"`, `"circle"` or `"unknown"`
+    shape: &'static str,
+    /// Width of a rectangle or diameter of a circle
+    size_x: f64,
+    /// Height of a rectangle, zero for circles
+    size_y: f64,
+}
+
+/// Metadata from the `Header` of `structure.xml`
+#[pyclass(module = "l3d", frozen, get_all)]
+#[derive(Clone)]
+pub struct Header {
+    name: Option<String>,
+    description: Option<String>,
+    created_with_application: String,
+    creation_time_code: String,
+    format_version: Option<String>,
+}
+
+#[pymethods]
+impl L3dFile {
+    /// Parse an L3D archive from bytes
+    #[new]
+    fn new(data: &[u8]) -> PyResult<Self> {
+        let inner = l3d_rs::try_from_buffer(data).map_err(l3d_error)?;
+        Ok(L3dFile { inner })
+    }
+
+    /// Load an L3D archive from a path
+    #[staticmethod]
+    fn open(path: std::path::PathBuf) -> PyResult<Self> {
+        L3dFile::new(&std::fs::read(path)?)
+    }
+
+    /// Geometry parts with their world transforms
+    #[getter]
+    fn parts(&self) -> Vec<Part> {
+        self.inner
+            .model
+            .parts
+            .iter()
+            .map(|part| Part {
+                name: part.name.clone(),
+                path: part.path.clone(),
+                matrix: part.world_matrix_f64(),
+            })
+            .collect()
+    }
+
+    /// Triangle meshes of all parts with parsable geometry
+    ///
+    /// With `world_space` (the default) the part transforms are applied to
+    /// positions and normals; otherwise they are in geometry file
+    /// coordinates and `Mesh.transform` places them.
+    #[pyo3(signature = (world_space = true))]
+    fn meshes(&self, world_space: bool) -> Vec<Mesh> {
+        let options = RenderOptions {
+            space: if world_space {
+                TransformSpace::World
+            } else {
+                TransformSpace::Local
+            },
+            ..Default::default()
+        };
+        self.inner
+            .render_buffers(&options)
+            .into_iter()
+            .map(|buffer| Mesh {
+                name: Some(buffer.name),
+                path: Some(buffer.path),
+                face_parts: vec![buffer.part_index as u32; buffer.indices.len() / 3],
+                vertices: buffer.vertices,
+                indices: buffer.indices,
+                matrix: buffer.transform,
+            })
+            .collect()
+    }
+
+    /// All parts combined into one world space mesh
+    ///
+    /// `Mesh.part_indices` maps every face to its index in `parts`.
+    fn merged_mesh(&self) -> Mesh {
+        let options = RenderOptions {
+            space: TransformSpace::World,
+            ..Default::default()
+        };
+        let mut mesh = Mesh {
+            name: None,
+            path: None,
+            vertices: Vec::new(),
+            indices: Vec::new(),
+            face_parts: Vec::new(),
+            matrix: l3d_rs::MAT4_IDENTITY,
+        };
+        for buffer in self.inner.render_buffers(&options) {
+            let offset = (mesh.vertices.len() / VERTEX_STRIDE) as u32;
+            mesh.indices
+                .extend(buffer.indices.iter().map(|i| i + offset));
+            let faces = mesh.face_parts.len() + buffer.indices.len() / 3;
+            mesh.face_parts.resize(faces, buffer.part_index as u32);
+            mesh.vertices.extend(buffer.vertices);
+        }
+        mesh
+    }
+
+    /// Light emitting objects of all geometries
+    #[getter]
+    fn light_emitters(&self) -> PyResult<Vec<LightEmitter>> {
+        let luminaire = self.luminaire()?;
+        Ok(luminaire
+            .light_emitters()
+            .into_iter()
+            .map(LightEmitter::from)
+            .collect())
+    }
+
+    /// Metadata from `structure.xml`
+    #[getter]
+    fn header(&self) -> PyResult<Header> {
+        let header = self.luminaire()?.header;
+        Ok(Header {
+            name: header.name().map(str::to_string),
+            description: header.description().map(str::to_string),
+            created_with_application: header.created_with_application().to_string(),
+            creation_time_code: header.creation_time_code().to_string(),
+            format_version: header.format_version(),
+        })
+    }
+
+    /// The raw `structure.xml`
+    #[getter]
+    fn structure_xml(&self) -> String {
+        self.inner.file.structure.clone()
+    }
+
+    /// Names of all asset files in the archive
+    #[getter]
+    fn asset_names(&self) -> Vec<String> {
+        self.inner
+            .file
+            .assets
+            .iter()
+            .map(|a| a.name.clone())
+            .collect()
+    }
+
+    /// Contents of an asset file, or `None` if there is none
+    fn asset<'py>(&self, py: Python<'py>, name: &str) -> Option<Bound<'py, PyBytes>> {
+        self.inner.asset(name).map(|a| PyBytes::new(py, &a.content))
+    }
+
+    /// The luminaire structure as JSON text
+    fn to_json(&self) -> PyResult<String> {
+        self.luminaire()?.to_json().map_err(l3d_error)
+    }
+
+    /// Move a geometry or joint relative to its parent
+    fn set_part_position(&mut self, name: &str, position: (f64, f64, f64)) -> PyResult<()> {
+        self.edit(|luminaire| {
+            *part_transform(luminaire, name)?.0 = vec3(position);
+            Ok(())
+        })
+    }
+
+    /// Set the rotation in degrees of a geometry or joint
+    fn set_part_rotation(&mut self, name: &str, rotation: (f64, f64, f64)) -> PyResult<()> {
+        self.edit(|luminaire| {
+            *part_transform(luminaire, name)?.1 = vec3(rotation);
+            Ok(())
+        })
+    }
+
+    /// Set the allowed angles of a joint axis (`"x"`, `"y"` or `"z"`)
+    fn set_joint_range(
+        &mut self,
+        name: &str,
+        axis: &str,
+        min: f64,
+        max: f64,
+        step: f64,
+    ) -> PyResult<()> {
+        // Written so that NaN fails the check
+        let valid = min <= max && step >= 0.0;
+        if !valid {
+            return Err(PyValueError::new_err(format!(
+                "invalid range {}..{} with step {}",
+                min, max, step
+            )));
+        }
+        self.edit(|luminaire| {
+            let joint = luminaire
+                .joint_mut(name)
+                .ok_or_else(|| PyKeyError::new_err(name.to_string()))?;
+            let slot = match axis {
+                "x" | "X" => &mut joint.x_axis,
+                "y" | "Y" => &mut joint.y_axis,
+                "z" | "Z" => &mut joint.z_axis,
+                _ => {
+                    return Err(PyValueError::new_err(format!(
+                        "axis must be \"x\", \"y\" or \"z\", not {:?}",
+                        axis
+                    )))
+                }
+            };
+            *slot = Some(Axis { min, max, step });
+            Ok(())
+        })
+    }
+
+    /// Rename a geometry, joint or light emitting object
+    fn rename_part(&mut self, old: &str, new: &str) -> PyResult<()> {
+        self.edit(|luminaire| {
+            if !luminaire.part_names().contains(&old) {
+                return Err(PyKeyError::new_err(old.to_string()));
+            }
+            luminaire.rename_part(old, new).map_err(l3d_error)
+        })
+    }
+
+    /// Replace the contents of an asset, or add a new one
+    ///
+    /// OBJ files are checked to parse before they are stored.
+    fn set_asset(&mut self, name: &str, data: &[u8]) -> PyResult<()> {
+        if name.to_ascii_lowercase().ends_with(".obj") {
+            ObjMesh::from_bytes(data).map_err(l3d_error)?;
+        }
+        self.inner.file.set_asset(name, data.to_vec());
+        Ok(())
+    }
+
+    /// The archive with all changes as `.l3d` bytes
+    fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let bytes = self.inner.file.to_bytes().map_err(l3d_error)?;
+        Ok(PyBytes::new(py, &bytes))
+    }
+
+    /// Write the archive with all changes to a path
+    fn save(&self, path: std::path::PathBuf) -> PyResult<()> {
+        let bytes = self.inner.file.to_bytes().map_err(l3d_error)?;
+        Ok(std::fs::write(path, bytes)?)
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.model.parts.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "L3dFile(parts={}, assets={})",
+            self.inner.model.parts.len(),
+            self.inner.file.assets.len()
+        )
+    }
+}
+
+impl L3dFile {
+    fn luminaire(&self) -> PyResult<Luminaire> {
+        self.inner.luminaire().map_err(l3d_error)
+    }
+
+    /// Modify the structure and rebuild the parts
+    fn edit(&mut self, f: impl FnOnce(&mut Luminaire) -> PyResult<()>) -> PyResult<()> {
+        let mut luminaire = self.luminaire()?;
+        f(&mut luminaire)?;
+        self.inner
+            .set_luminaire(&luminaire, &BuildOptions::default())
+            .map_err(l3d_error)
+    }
+}
+
+/// Position and rotation of the geometry or joint called `name`
+fn part_transform<'a>(
+    luminaire: &'a mut Luminaire,
+    name: &str,
+) -> PyResult<(&'a mut Vec3f, &'a mut Vec3f)> {
+    if luminaire.geometry(name).is_some() {
+        let geometry = luminaire.geometry_mut(name).expect("geometry exists");
+        return Ok((&mut geometry.position, &mut geometry.rotation));
+    }
+    match luminaire.joint_mut(name) {
+        Some(joint) => Ok((&mut joint.position, &mut joint.rotation)),
+        None => Err(PyKeyError::new_err(name.to_string())),
+    }
+}
+
+fn vec3((x, y, z): (f64, f64, f64)) -> Vec3f {
+    Vec3f {
+        x: x as f32,
+        y: y as f32,
+        z: z as f32,
+    }
+}
+
+#[pymethods]
+impl Part {
+    /// 4x4 world matrix in meters as a `float64` array
+    ///
+    /// Indexed `[row, column]`, so the translation is `transform[:3, 3]`.
+    #[getter]
+    fn transform<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f64>> {
+        Array2::from_shape_fn((4, 4), |(row, col)| self.matrix[col * 4 + row]).into_pyarray(py)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Part(name={:?}, path={:?})", self.name, self.path)
+    }
+}
+
+#[pymethods]
+impl Mesh {
+    /// Vertex positions as an `(N, 3)` `float32` array
+    #[getter]
+    fn positions<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f32>> {
+        self.columns(py, 0, 3)
+    }
+
+    /// Unit vertex normals as an `(N, 3)` `float32` array
+    #[getter]
+    fn normals<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f32>> {
+        self.columns(py, 3, 3)
+    }
+
+    /// Texture coordinates as an `(N, 2)` `float32` array
+    #[getter]
+    fn uvs<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f32>> {
+        self.columns(py, 6, 2)
+    }
+
+    /// Triangles as an `(M, 3)` `uint32` array of vertex indices
+    ///
+    /// Front faces are counter-clockwise.
+    #[getter]
+    fn faces<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<u32>> {
+        Array2::from_shape_fn((self.indices.len() / 3, 3), |(i, k)| {
+            self.indices[i * 3 + k]
+        })
+        .into_pyarray(py)
+    }
+
+    /// Part index of every triangle as an `(M,)` `uint32` array
+    #[getter]
+    fn part_indices<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<u32>> {
+        Array1::from_vec(self.face_parts.clone()).into_pyarray(py)
+    }
+
+    /// 4x4 matrix placing the mesh in the model, indexed `[row, column]`
+    ///
+    /// Identity for world space meshes.
+    #[getter]
+    fn transform<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f32>> {
+        Array2::from_shape_fn((4, 4), |(row, col)| self.matrix[col * 4 + row]).into_pyarray(py)
+    }
+
+    fn __len__(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Mesh(name={:?}, vertices={}, faces={})",
+            self.name.as_deref().unwrap_or("<merged>"),
+            self.vertices.len() / VERTEX_STRIDE,
+            self.indices.len() / 3
+        )
+    }
+}
+
+impl Mesh {
+    /// `count` values per vertex starting at `offset` as an `(N, count)` array
+    fn columns<'py>(
+        &self,
+        py: Python<'py>,
+        offset: usize,
+        count: usize,
+    ) -> Bound<'py, PyArray2<f32>> {
+        Array2::from_shape_fn((self.vertices.len() / VERTEX_STRIDE, count), |(i, k)| {
+            self.vertices[i * VERTEX_STRIDE + offset + k]
+        })
+        .into_pyarray(py)
+    }
+}
+
+#[pymethods]
+impl LightEmitter {
+    fn __repr__(&self) -> String {
+        format!("LightEmitter(name={:?}, shape={:?})", self.name, self.shape)
+    }
+}
+
+#[pymethods]
+impl Header {
+    fn __repr__(&self) -> String {
+        format!(
+            "Header(name={:?}, created_with_application={:?})",
+            self.name, self.created_with_application
+        )
+    }
+}
+
+fn tuple3(v: &Vec3f) -> (f64, f64, f64) {
+    (v.x.into(), v.y.into(), v.z.into())
+}
+
+impl From<l3d_rs::LightEmitter> for LightEmitter {
+    fn from(leo: l3d_rs::LightEmitter) -> Self {
+        LightEmitter {
+            name: leo.name,
+            geometry_part_name: leo.geometry_part_name,
+            position: tuple3(&leo.position),
+            rotation: tuple3(&leo.rotation),
+            shape: leo.shape.as_str(),
+            size_x: leo.size_x,
+            size_y: leo.size_y,
+        }
+    }
+}
+
+/// Parse an L3D file from bytes
+#[pyfunction]
+fn from_bytes(data: &[u8]) -> PyResult<L3dFile> {
+    L3dFile::new(data)
+}
+
+/// Load an L3D file from a path
+#[pyfunction]
+fn from_file(path: std::path::PathBuf) -> PyResult<L3dFile> {
+    L3dFile::open(path)
+}
 
 /// Parse an L3D file from bytes and return JSON representation
 #[pyfunction]
-fn parse_l3d(data: &[u8]) -> String {
-    let l3d = l3d_rs::from_buffer(data);
-    serde_json::to_string(&l3d).unwrap_or_else(|_| "{}".to_string())
+fn parse_l3d(data: &[u8]) -> PyResult<String> {
+    let l3d = l3d_rs::try_from_buffer(data).map_err(l3d_error)?;
+    serde_json::to_string(&l3d).map_err(|e| L3dError::new_err(e.to_string()))
 }
 
 /// Parse an L3D file and return model parts as JSON
 #[pyfunction]
-fn get_model_parts(data: &[u8]) -> String {
-    let l3d = l3d_rs::from_buffer(data);
-    serde_json::to_string(&l3d.model.parts).unwrap_or_else(|_| "[]".to_string())
+fn get_model_parts(data: &[u8]) -> PyResult<String> {
+    let l3d = l3d_rs::try_from_buffer(data).map_err(l3d_error)?;
+    serde_json::to_string(&l3d.model.parts).map_err(|e| L3dError::new_err(e.to_string()))
 }
 
 /// Parse structure.xml content directly and return JSON
 #[pyfunction]
-fn parse_structure_xml(xml: &str) -> String {
-    match l3d_rs::Luminaire::from_xml(xml) {
-        Ok(luminaire) => luminaire.to_json().unwrap_or_else(|_| "{}".to_string()),
-        Err(_) => "{}".to_string(),
-    }
+fn parse_structure_xml(xml: &str) -> PyResult<String> {
+    Luminaire::from_xml(xml)
+        .and_then(|luminaire| luminaire.to_json())
+        .map_err(l3d_error)
 }
 
 /// Load an L3D file from path and return JSON representation
 #[pyfunction]
-fn load_l3d(path: &str) -> PyResult<String> {
-    let data = std::fs::read(path)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
-    Ok(parse_l3d(&data))
+fn load_l3d(path: std::path::PathBuf) -> PyResult<String> {
+    parse_l3d(&std::fs::read(path)?)
 }
 
 /// L3D Python module - parse Luminaire 3D files
 #[pymodule]
 fn l3d(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("L3dError", m.py().get_type::<L3dError>())?;
+    m.add_class::<L3dFile>()?;
+    m.add_class::<Part>()?;
+    m.add_class::<Mesh>()?;
+    m.add_class::<LightEmitter>()?;
+    m.add_class::<Header>()?;
+    m.add_function(wrap_pyfunction!(from_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(from_file, m)?)?;
     m.add_function(wrap_pyfunction!(parse_l3d, m)?)?;
     m.add_function(wrap_pyfunction!(get_model_parts, m)?)?;
     m.add_function(wrap_pyfunction!(parse_structure_xml, m)?)?;
     m.add_function(wrap_pyfunction!(load_l3d, m)?)?;
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
     Ok(())
 }
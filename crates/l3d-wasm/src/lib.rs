@@ -0,0 +1,342 @@
+//! # l3d-wasm
+//!
+//! WebAssembly bindings for l3d_rs - L3D luminaire file format parser.
+//!
+//! A slim alternative to the `l3d-egui` viewer for web frontends that
+//! render with their own engine: parse an archive from a `Uint8Array`,
+//! read the structure, part matrices, light emitting objects and assets,
+//! build GPU-ready vertex buffers, pick parts under the cursor, validate the
+//! file and export glTF. No 3D engine is compiled in.
+//!
+//! ## Usage
+//!
+//! ```js
+//! import init, { L3dFile } from "l3d-wasm";
+//!
+//! await init();
+//! const l3d = new L3dFile(new Uint8Array(await file.arrayBuffer()));
+//! for (const part of l3d.parts()) {
+//!     console.log(part.name, part.path, part.matrix); // matrix: Float32Array(16)
+//! }
+//! const glb = l3d.toGlb(); // Uint8Array
+//! ```
+
+use l3d_rs::{
+    mat4_mul, mat4_transform_direction, mat4_transform_point, try_from_buffer, validate,
+    CoordinateSystem, L3d, Luminaire, PickScene, Ray, RenderOptions, TransformSpace, VERTEX_STRIDE,
+};
+use serde::Serialize;
+use std::cell::OnceCell;
+use wasm_bindgen::prelude::*;
+
+/// A parsed L3D file
+#[wasm_bindgen]
+pub struct L3dFile {
+    inner: L3d,
+    /// Built on the first call to `pick` or `pickScreen`
+    pick_scene: OnceCell<PickScene>,
+}
+
+/// A light emitting object as returned by [`L3dFile::light_emitters`]
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LightEmitter {
+    name: String,
+    geometry_part_name: String,
+    position: [f64; 3],
+    rotation: [f64; 3],
+    shape: &'static str,
+    size_x: f64,
+    size_y: f64,
+}
+
+#[wasm_bindgen]
+impl L3dFile {
+    /// Parse an L3D archive
+    ///
+    /// Throws if the data is not a readable L3D file.
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: &[u8]) -> Result<L3dFile, JsError> {
+        let inner = try_from_buffer(data).map_err(|e| JsError::new(&format!("{:#}", e)))?;
+        Ok(L3dFile {
+            inner,
+            pick_scene: OnceCell::new(),
+        })
+    }
+
+    /// The raw `structure.xml`
+    #[wasm_bindgen(js_name = structureXml)]
+    pub fn structure_xml(&self) -> String {
+        self.inner.file.structure.clone()
+    }
+
+    /// The luminaire structure as JSON text
+    #[wasm_bindgen(js_name = structureJson)]
+    pub fn structure_json(&self) -> Result<String, JsError> {
+        self.luminaire()?
+            .to_json()
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Number of geometry parts
+    #[wasm_bindgen(js_name = partCount)]
+    pub fn part_count(&self) -> usize {
+        self.inner.model.parts.len()
+    }
+
+    /// Geometry parts as `{ name, path, matrix }` objects
+    ///
+    /// `matrix` is a column-major `Float32Array` of 16 values in meters,
+    /// Z-up as stored in L3D.
+    pub fn parts(&self) -> js_sys::Array {
+        self.inner
+            .model
+            .parts
+            .iter()
+            .map(|part| {
+                let object = js_sys::Object::new();
+                set(&object, "name", JsValue::from_str(&part.name));
+                set(&object, "path", JsValue::from_str(&part.path));
+                set(
+                    &object,
+                    "matrix",
+                    js_sys::Float32Array::from(&part.mat[..]).into(),
+                );
+                JsValue::from(object)
+            })
+            .collect()
+    }
+
+    /// GPU-ready geometry of every part
+    ///
+    /// Returns `{ partIndex, name, path, vertices, indices, transform }`
+    /// objects: `partIndex` is the index into `parts()`, `vertices` a
+    /// `Float32Array` of interleaved position, normal and texture coordinate
+    /// (8 floats per vertex), `indices` a `Uint32Array` triangle list and
+    /// `transform` the column-major model matrix (identity with
+    /// `worldSpace`). With `yUp` everything is converted to the Y-up
+    /// right-handed system of WebGL frameworks. `smoothNormals` regenerates
+    /// the normals, keeping edges sharper than this angle in degrees.
+    #[wasm_bindgen(js_name = renderBuffers)]
+    pub fn render_buffers(
+        &self,
+        world_space: bool,
+        y_up: bool,
+        smooth_normals: Option<f32>,
+    ) -> js_sys::Array {
+        let options = RenderOptions {
+            space: if world_space {
+                TransformSpace::World
+            } else {
+                TransformSpace::Local
+            },
+            smooth_normals,
+        };
+        // The conversion is the outermost transform, as in model building
+        let to_y_up = y_up.then(|| CoordinateSystem::YUpRightHanded.matrix());
+
+        self.inner
+            .render_buffers(&options)
+            .into_iter()
+            .map(|mut buffer| {
+                if let Some(to_y_up) = &to_y_up {
+                    if world_space {
+                        for vertex in buffer.vertices.chunks_exact_mut(VERTEX_STRIDE) {
+                            let p =
+                                mat4_transform_point(to_y_up, [vertex[0], vertex[1], vertex[2]]);
+                            let n = mat4_transform_direction(
+                                to_y_up,
+                                [vertex[3], vertex[4], vertex[5]],
+                            );
+                            vertex[..3].copy_from_slice(&p);
+                            vertex[3..6].copy_from_slice(&n);
+                        }
+                    } else {
+                        buffer.transform = mat4_mul(to_y_up, &buffer.transform);
+                    }
+                }
+                let object = js_sys::Object::new();
+                set(
+                    &object,
+                    "partIndex",
+                    JsValue::from(buffer.part_index as u32),
+                );
+                set(&object, "name", JsValue::from_str(&buffer.name));
+                set(&object, "path", JsValue::from_str(&buffer.path));
+                set(
+                    &object,
+                    "vertices",
+                    js_sys::Float32Array::from(buffer.vertices.as_slice()).into(),
+                );
+                set(
+                    &object,
+                    "indices",
+                    js_sys::Uint32Array::from(buffer.indices.as_slice()).into(),
+                );
+                set(
+                    &object,
+                    "transform",
+                    js_sys::Float32Array::from(&buffer.transform[..]).into(),
+                );
+                JsValue::from(object)
+            })
+            .collect()
+    }
+
+    /// Find the part hit by a ray, e.g. through the clicked pixel
+    ///
+    /// `origin` and `direction` are `Float32Array`s of 3 values in meters,
+    /// Z-up like `parts()`, or Y-up like `renderBuffers(.., true)` with
+    /// `yUp`. Returns `{ partIndex, partName, faceIndex, position, normal,
+    /// distance }` in the same system for the closest hit, or `undefined`.
+    /// `faceIndex` counts the faces of the part's OBJ file.
+    pub fn pick(
+        &self,
+        origin: &[f32],
+        direction: &[f32],
+        y_up: Option<bool>,
+    ) -> Result<Option<js_sys::Object>, JsError> {
+        let ray = Ray::new(vec3(origin, "origin")?, vec3(direction, "direction")?);
+        Ok(self.pick_ray(ray, y_up.unwrap_or(false)))
+    }
+
+    /// Find the part under a point on screen
+    ///
+    /// `inverseViewProjection` is the column-major inverse of `projection *
+    /// view` as a `Float32Array` of 16 values, and `x`, `y` the point in
+    /// normalized device coordinates (`-1..=1`, Y up). Returns the same
+    /// object as `pick()`, or `undefined`.
+    #[wasm_bindgen(js_name = pickScreen)]
+    pub fn pick_screen(
+        &self,
+        inverse_view_projection: &[f32],
+        x: f32,
+        y: f32,
+        y_up: Option<bool>,
+    ) -> Result<Option<js_sys::Object>, JsError> {
+        let matrix = inverse_view_projection
+            .try_into()
+            .map_err(|_| JsError::new("inverseViewProjection must have 16 values"))?;
+        Ok(Ray::from_screen(&matrix, [x, y])
+            .and_then(|ray| self.pick_ray(ray, y_up.unwrap_or(false))))
+    }
+
+    /// Light emitting objects of all geometries
+    ///
+    /// Each object has `name`, `geometryPartName`, `position` and
+    /// `rotation` (relative to the geometry), `shape` (`"rectangle"`,
+    /// `"circle"` or `"unknown"`), `sizeX` (width or diameter) and `sizeY`.
+    #[wasm_bindgen(js_name = lightEmitters)]
+    pub fn light_emitters(&self) -> Result<JsValue, JsError> {
+        let emitters: Vec<LightEmitter> = self
+            .luminaire()?
+            .light_emitters()
+            .into_iter()
+            .map(|leo| LightEmitter {
+                name: leo.name,
+                geometry_part_name: leo.geometry_part_name,
+                position: [leo.position.x, leo.position.y, leo.position.z].map(f64::from),
+                rotation: [leo.rotation.x, leo.rotation.y, leo.rotation.z].map(f64::from),
+                shape: leo.shape.as_str(),
+                size_x: leo.size_x,
+                size_y: leo.size_y,
+            })
+            .collect();
+        to_js(&emitters)
+    }
+
+    /// Names of all asset files in the archive
+    #[wasm_bindgen(js_name = assetNames)]
+    pub fn asset_names(&self) -> Vec<String> {
+        self.inner
+            .file
+            .assets
+            .iter()
+            .map(|a| a.name.clone())
+            .collect()
+    }
+
+    /// Contents of an asset file, or `undefined` if there is none
+    pub fn asset(&self, name: &str) -> Option<Vec<u8>> {
+        self.inner.asset(name).map(|a| a.content.clone())
+    }
+
+    /// Validation diagnostics as `{ severity, code, message, ... }` objects
+    pub fn validate(&self) -> Result<JsValue, JsError> {
+        to_js(&validate(&self.inner))
+    }
+
+    /// Export the model as binary glTF (`.glb`)
+    #[wasm_bindgen(js_name = toGlb)]
+    pub fn to_glb(&self) -> Result<Vec<u8>, JsError> {
+        self.inner
+            .to_glb()
+            .map_err(|e| JsError::new(&format!("{:#}", e)))
+    }
+}
+
+impl L3dFile {
+    fn luminaire(&self) -> Result<Luminaire, JsError> {
+        self.inner
+            .luminaire()
+            .map_err(|e| JsError::new(&format!("{:#}", e)))
+    }
+
+    fn pick_ray(&self, mut ray: Ray, y_up: bool) -> Option<js_sys::Object> {
+        // The scene is in L3D coordinates; undo the Y-up conversion of
+        // CoordinateSystem::convert_point on the way in, redo it on the way out
+        if y_up {
+            let to_l3d = |[x, y, z]: [f32; 3]| [x, -z, y];
+            ray = Ray::new(to_l3d(ray.origin), to_l3d(ray.direction));
+        }
+        let scene = self.pick_scene.get_or_init(|| self.inner.pick_scene());
+        let hit = scene.pick(&ray)?;
+        let convert = |v: [f32; 3]| {
+            if y_up {
+                CoordinateSystem::YUpRightHanded.convert_point(v)
+            } else {
+                v
+            }
+        };
+
+        let object = js_sys::Object::new();
+        set(&object, "partIndex", JsValue::from(hit.part_index as u32));
+        set(&object, "partName", JsValue::from_str(&hit.part_name));
+        set(&object, "faceIndex", JsValue::from(hit.face_index as u32));
+        set(
+            &object,
+            "position",
+            js_sys::Float32Array::from(&convert(hit.position)[..]).into(),
+        );
+        set(
+            &object,
+            "normal",
+            js_sys::Float32Array::from(&convert(hit.normal)[..]).into(),
+        );
+        set(&object, "distance", JsValue::from(hit.distance));
+        Some(object)
+    }
+}
+
+/// Version of the library
+#[wasm_bindgen]
+pub fn version() -> String {
+    env!("CARGO_PKG_VERSION").to_string()
+}
+
+/// Read a 3-component vector passed as `Float32Array`
+fn vec3(values: &[f32], name: &str) -> Result<[f32; 3], JsError> {
+    values
+        .try_into()
+        .map_err(|_| JsError::new(&format!("{name} must have 3 values")))
+}
+
+fn set(object: &js_sys::Object, key: &str, value: JsValue) {
+    js_sys::Reflect::set(object, &JsValue::from_str(key), &value).ok();
+}
+
+/// Convert a serializable value into a plain JavaScript value
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsError> {
+    let json = serde_json::to_string(value).map_err(|e| JsError::new(&e.to_string()))?;
+    js_sys::JSON::parse(&json).map_err(|_| JsError::new("invalid JSON"))
+}
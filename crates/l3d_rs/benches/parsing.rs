@@ -0,0 +1,79 @@
+//! Benchmarks for the L3D parsing hot paths
+//!
+//! Run with `cargo bench -p l3d_rs`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use l3d_rs::{from_buffer, Luminaire};
+use std::fmt::Write;
+
+/// Minimal L3D test file included in the repository
+const TEST_L3D_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/minimal.l3d");
+
+fn read_test_file() -> Vec<u8> {
+    std::fs::read(TEST_L3D_PATH).expect("Failed to read L3D file")
+}
+
+/// Build a structure.xml with a chain of `depth` nested joints
+///
+/// Written with a namespaced root, CRLF line endings and a BOM so the
+/// sanitizing path is exercised the same way as real manufacturer files.
+fn nested_structure_xml(depth: usize) -> String {
+    let mut xml = String::from("\u{FEFF}<?xml version=\"1.0\"?>\r\n");
+    xml.push_str(
+        "<Luminaire xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" \
+         xsi:noNamespaceSchemaLocation=\"https://gldf.io/xsd/l3d/0.9/l3d.xsd\">\r\n",
+    );
+    xml.push_str(
+        "<Header><CreatedWithApplication>bench</CreatedWithApplication>\
+         <CreationTimeCode>2024-01-01T00:00:00Z</CreationTimeCode></Header>\r\n",
+    );
+    xml.push_str("<GeometryDefinitions><GeometryFileDefinition id=\"geom_1\" filename=\"cube.obj\" units=\"mm\"/></GeometryDefinitions>\r\n");
+    xml.push_str("<Structure>\r\n");
+    for i in 0..depth {
+        write!(
+            xml,
+            "<Geometry partName=\"part_{i}\"><Position x=\"0\" y=\"0\" z=\"10\"/>\
+             <Rotation x=\"0\" y=\"0\" z=\"5\"/><GeometryReference geometryId=\"geom_1\"/>"
+        )
+        .unwrap();
+        if i + 1 < depth {
+            write!(
+                xml,
+                "<Joints><Joint partName=\"joint_{i}\"><Position x=\"0\" y=\"0\" z=\"1\"/>\
+                 <Rotation x=\"0\" y=\"0\" z=\"0\"/><Geometries>\r\n"
+            )
+            .unwrap();
+        }
+    }
+    for i in (0..depth).rev() {
+        xml.push_str("</Geometry>");
+        if i > 0 {
+            xml.push_str("</Geometries></Joint></Joints>\r\n");
+        }
+    }
+    xml.push_str("</Structure>\r\n</Luminaire>\r\n");
+    xml
+}
+
+fn bench_from_buffer(c: &mut Criterion) {
+    let bytes = read_test_file();
+    c.bench_function("from_buffer/minimal", |b| {
+        b.iter(|| from_buffer(black_box(&bytes)))
+    });
+}
+
+fn bench_from_xml(c: &mut Criterion) {
+    let l3d = from_buffer(&read_test_file());
+    let minimal = l3d.file.structure;
+    let nested = nested_structure_xml(32);
+
+    c.bench_function("Luminaire::from_xml/minimal", |b| {
+        b.iter(|| Luminaire::from_xml(black_box(&minimal)).unwrap())
+    });
+    c.bench_function("Luminaire::from_xml/nested_32", |b| {
+        b.iter(|| Luminaire::from_xml(black_box(&nested)).unwrap())
+    });
+}
+
+criterion_group!(benches, bench_from_buffer, bench_from_xml);
+criterion_main!(benches);
@@ -0,0 +1,268 @@
+//! # Batch Processing
+//!
+//! Validate and summarize whole directories of L3D files, e.g. a
+//! manufacturer catalogue. Each file is parsed, validated with
+//! [`validate`] and summarized in a [`FileReport`]; the
+//! collected [`BatchReport`] can be written as JSON or CSV.
+//!
+//! With the `rayon` feature enabled, files are processed in parallel.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use l3d_rs::batch::{process_directory, BatchOptions};
+//!
+//! let report = process_directory("catalogue/", &BatchOptions::default()).unwrap();
+//! println!("{} files, {} with errors", report.files.len(), report.error_count());
+//! std::fs::write("report.csv", report.to_csv()).unwrap();
+//! ```
+//!
+//! The same report is available from the command line:
+//!
+//! ```text
+//! l3d-batch catalogue/ --format csv > report.csv
+//! ```
+
+use crate::try_from_buffer;
+use crate::validation::{validate, Diagnostic, Severity};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+/// Options controlling which files [`process_directory`] picks up
+#[derive(Debug, Clone)]
+pub struct BatchOptions {
+    /// Descend into subdirectories
+    pub recursive: bool,
+    /// File extension to match, compared case-insensitively (without the dot)
+    pub extension: String,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        Self {
+            recursive: true,
+            extension: "l3d".to_string(),
+        }
+    }
+}
+
+/// Overall outcome for a single file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileStatus {
+    /// Parsed without warnings or errors
+    Ok,
+    /// Parsed, but validation reported warnings
+    Warning,
+    /// Could not be read or parsed, or validation reported errors
+    Error,
+}
+
+/// Summary of a single L3D file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileReport {
+    /// Path of the file as found while walking the directory
+    pub path: String,
+    /// Overall outcome
+    pub status: FileStatus,
+    /// Reason the file could not be read or parsed at all
+    pub error: Option<String>,
+    /// Findings from [`validate`]
+    pub diagnostics: Vec<Diagnostic>,
+    /// Number of geometry parts in the model
+    pub part_count: usize,
+    /// Number of asset files in the archive
+    pub asset_count: usize,
+    /// Size of the assembled luminaire bounding box in meters (x, y, z)
+    pub dimensions: Option<[f32; 3]>,
+}
+
+impl FileReport {
+    fn failed(path: &str, error: String) -> Self {
+        Self {
+            path: path.to_string(),
+            status: FileStatus::Error,
+            error: Some(error),
+            diagnostics: Vec::new(),
+            part_count: 0,
+            asset_count: 0,
+            dimensions: None,
+        }
+    }
+
+    fn status_str(&self) -> &'static str {
+        match self.status {
+            FileStatus::Ok => "ok",
+            FileStatus::Warning => "warning",
+            FileStatus::Error => "error",
+        }
+    }
+}
+
+/// Summary of a batch run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BatchReport {
+    /// One report per processed file, sorted by path
+    pub files: Vec<FileReport>,
+}
+
+impl BatchReport {
+    /// Number of files with status [`FileStatus::Error`]
+    pub fn error_count(&self) -> usize {
+        self.count(FileStatus::Error)
+    }
+
+    /// Number of files with status [`FileStatus::Warning`]
+    pub fn warning_count(&self) -> usize {
+        self.count(FileStatus::Warning)
+    }
+
+    fn count(&self, status: FileStatus) -> usize {
+        self.files.iter().filter(|f| f.status == status).count()
+    }
+
+    /// Serialize the report to pretty-printed JSON
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Serialize the report to CSV, one row per file
+    ///
+    /// Columns: `path, status, parts, assets, size_x, size_y, size_z,
+    /// errors, warnings, messages`. Messages of all diagnostics (and the
+    /// parse error, if any) are joined with `"; "`.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(
+            "path,status,parts,assets,size_x,size_y,size_z,errors,warnings,messages\n",
+        );
+        for file in &self.files {
+            let (x, y, z) = match file.dimensions {
+                Some([x, y, z]) => (x.to_string(), y.to_string(), z.to_string()),
+                None => Default::default(),
+            };
+            let count = |s| file.diagnostics.iter().filter(|d| d.severity == s).count();
+            let messages = file
+                .error
+                .iter()
+                .cloned()
+                .chain(file.diagnostics.iter().map(|d| d.to_string()))
+                .collect::<Vec<_>>()
+                .join("; ");
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{},{},{},{},{}",
+                csv_field(&file.path),
+                file.status_str(),
+                file.part_count,
+                file.asset_count,
+                x,
+                y,
+                z,
+                count(Severity::Error) + file.error.is_some() as usize,
+                count(Severity::Warning),
+                csv_field(&messages)
+            );
+        }
+        csv
+    }
+}
+
+/// Quote a CSV field if it contains separators, quotes or line breaks
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Summarize L3D file contents
+///
+/// `path` is only used to label the report.
+pub fn process_buffer(path: &str, bytes: &[u8]) -> FileReport {
+    let l3d = match try_from_buffer(bytes) {
+        Ok(l3d) => l3d,
+        Err(e) => return FileReport::failed(path, format!("{:#}", e)),
+    };
+
+    let diagnostics = validate(&l3d);
+    let status = match diagnostics.iter().map(|d| d.severity).max() {
+        Some(Severity::Error) => FileStatus::Error,
+        Some(Severity::Warning) => FileStatus::Warning,
+        _ => FileStatus::Ok,
+    };
+    let dimensions = l3d
+        .bounds()
+        .map(|(min, max)| [max[0] - min[0], max[1] - min[1], max[2] - min[2]]);
+
+    FileReport {
+        path: path.to_string(),
+        status,
+        error: None,
+        diagnostics,
+        part_count: l3d.model.parts.len(),
+        asset_count: l3d.file.assets.len(),
+        dimensions,
+    }
+}
+
+/// Read and summarize a single L3D file
+pub fn process_file(path: &Path) -> FileReport {
+    let label = path.display().to_string();
+    match std::fs::read(path) {
+        Ok(bytes) => process_buffer(&label, &bytes),
+        Err(e) => FileReport::failed(&label, format!("Failed to read file: {}", e)),
+    }
+}
+
+/// Summarize a list of L3D files
+///
+/// Files are processed in parallel when the `rayon` feature is enabled.
+/// The resulting report keeps the order of `paths`.
+pub fn process_files(paths: &[PathBuf]) -> BatchReport {
+    #[cfg(feature = "rayon")]
+    let files = {
+        use rayon::prelude::*;
+        paths.par_iter().map(|p| process_file(p)).collect()
+    };
+    #[cfg(not(feature = "rayon"))]
+    let files = paths.iter().map(|p| process_file(p)).collect();
+
+    BatchReport { files }
+}
+
+/// Find all L3D files in a directory and summarize them
+///
+/// Returns an error only if the directory itself cannot be read; problems
+/// with individual files are recorded in their [`FileReport`].
+pub fn process_directory(dir: impl AsRef<Path>, options: &BatchOptions) -> Result<BatchReport> {
+    let mut paths = Vec::new();
+    collect_files(dir.as_ref(), options, &mut paths)?;
+    paths.sort();
+    Ok(process_files(&paths))
+}
+
+/// Recursively collect files matching the configured extension
+fn collect_files(dir: &Path, options: &BatchOptions, paths: &mut Vec<PathBuf>) -> Result<()> {
+    let entries = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read directory {}", dir.display()))?;
+    for entry in entries {
+        let entry = entry?;
+        let path = entry.path();
+        // Symlinked directories are not followed; they may point back up
+        if entry.file_type()?.is_dir() {
+            if options.recursive {
+                collect_files(&path, options, paths)?;
+            }
+        } else if path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e.eq_ignore_ascii_case(&options.extension))
+        {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,75 @@
+//! l3d-batch - validate and summarize a directory of L3D files
+//!
+//! ```text
+//! l3d-batch <DIR> [--format json|csv] [--no-recursive]
+//! ```
+//!
+//! Prints the report to stdout and exits with status 1 if any file has errors.
+
+use l3d_rs::batch::{process_directory, BatchOptions};
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: l3d-batch <DIR> [--format json|csv] [--no-recursive]";
+
+fn main() -> ExitCode {
+    let mut dir = None;
+    let mut format = "json".to_string();
+    let mut options = BatchOptions::default();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => match args.next() {
+                Some(f) if f == "json" || f == "csv" => format = f,
+                _ => return usage_error("--format expects 'json' or 'csv'"),
+            },
+            "--no-recursive" => options.recursive = false,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            _ if dir.is_none() => dir = Some(arg),
+            _ => return usage_error(&format!("unexpected argument '{}'", arg)),
+        }
+    }
+    let Some(dir) = dir else {
+        return usage_error("missing directory");
+    };
+
+    let report = match process_directory(&dir, &options) {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("error: {:#}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if format == "csv" {
+        print!("{}", report.to_csv());
+    } else {
+        match report.to_json() {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("error: {:#}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    eprintln!(
+        "{} files: {} with errors, {} with warnings",
+        report.files.len(),
+        report.error_count(),
+        report.warning_count()
+    );
+    if report.error_count() > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn usage_error(message: &str) -> ExitCode {
+    eprintln!("error: {}\n{}", message, USAGE);
+    ExitCode::from(2)
+}
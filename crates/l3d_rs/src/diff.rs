@@ -0,0 +1,621 @@
+//! # Semantic Diff of L3D Files
+//!
+//! Compares two versions of a luminaire and reports what changed in terms of
+//! the L3D model rather than XML text: parts that were added, removed or
+//! renamed, geometries that moved or rotated, joints whose ranges changed,
+//! light emitting objects that were resized, and geometry files whose
+//! content changed.
+//!
+//! Parts are matched by name. A name used by several parts of the same kind
+//! (reported as `duplicate-part-name` by [`validate`](crate::validate)) is
+//! matched by occurrence in document order, and its second and later uses
+//! are reported as `name#2`, `name#3`, and so on.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use l3d_rs::{diff, from_buffer};
+//!
+//! let old = from_buffer(&std::fs::read("luminaire_v1.l3d").unwrap());
+//! let new = from_buffer(&std::fs::read("luminaire_v2.l3d").unwrap());
+//!
+//! let changes = diff(&old, &new).unwrap();
+//! print!("{}", changes); // human-readable, one change per line
+//! let json = changes.to_json().unwrap();
+//! ```
+
+use crate::hash::ContentHash;
+use crate::l3d::{Axis, Geometry, Joint, L3d, LightEmittingObject, Luminaire, Vec3f};
+use crate::obj::ObjMesh;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// Shape of a light emitting object
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "shape", rename_all = "snake_case")]
+pub enum LeoShape {
+    /// Rectangular emitter, sizes in meters
+    Rectangle { size_x: f64, size_y: f64 },
+    /// Circular emitter, diameter in meters
+    Circle { diameter: f64 },
+    /// Neither a rectangle nor a circle is defined
+    None,
+}
+
+impl LeoShape {
+    fn of(leo: &LightEmittingObject) -> Self {
+        match (&leo.rectangle, &leo.circle) {
+            (Some(r), _) => LeoShape::Rectangle {
+                size_x: r.size_x,
+                size_y: r.size_y,
+            },
+            (None, Some(c)) => LeoShape::Circle {
+                diameter: c.diameter,
+            },
+            (None, None) => LeoShape::None,
+        }
+    }
+}
+
+impl fmt::Display for LeoShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LeoShape::Rectangle { size_x, size_y } => write!(f, "rectangle {}x{}", size_x, size_y),
+            LeoShape::Circle { diameter } => write!(f, "circle ⌀{}", diameter),
+            LeoShape::None => f.write_str("no shape"),
+        }
+    }
+}
+
+/// Summary of a geometry file used to detect content changes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileSummary {
+    /// SHA-256 of the file content
+    pub hash: ContentHash,
+    /// Number of vertex positions, if the file is a readable OBJ
+    pub vertex_count: Option<usize>,
+}
+
+/// A single semantic change between two L3D files
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum Change {
+    /// A geometry part exists only in the new file
+    PartAdded { part_name: String },
+    /// A geometry part exists only in the old file
+    PartRemoved { part_name: String },
+    /// A geometry part with the same geometry and placement has a new name
+    PartRenamed { from: String, to: String },
+    /// A geometry part's local position changed
+    PartMoved {
+        part_name: String,
+        from: [f32; 3],
+        to: [f32; 3],
+    },
+    /// A geometry part's local rotation changed (degrees)
+    PartRotated {
+        part_name: String,
+        from: [f32; 3],
+        to: [f32; 3],
+    },
+    /// A geometry part references a different geometry definition
+    GeometryReferenceChanged {
+        part_name: String,
+        from: String,
+        to: String,
+    },
+    /// A joint exists only in the new file
+    JointAdded { part_name: String },
+    /// A joint exists only in the old file
+    JointRemoved { part_name: String },
+    /// A joint's position changed
+    JointMoved {
+        part_name: String,
+        from: [f32; 3],
+        to: [f32; 3],
+    },
+    /// A joint's rotation changed (degrees)
+    JointRotated {
+        part_name: String,
+        from: [f32; 3],
+        to: [f32; 3],
+    },
+    /// The allowed range of a joint axis changed (`axis` is `"x"`, `"y"` or `"z"`)
+    JointRangeChanged {
+        part_name: String,
+        axis: String,
+        from: Option<Axis>,
+        to: Option<Axis>,
+    },
+    /// A light emitting object exists only in the new file
+    LeoAdded { part_name: String },
+    /// A light emitting object exists only in the old file
+    LeoRemoved { part_name: String },
+    /// A light emitting object's position changed
+    LeoMoved {
+        part_name: String,
+        from: [f32; 3],
+        to: [f32; 3],
+    },
+    /// A light emitting object's rotation changed (degrees)
+    LeoRotated {
+        part_name: String,
+        from: [f32; 3],
+        to: [f32; 3],
+    },
+    /// A light emitting object's shape or size changed
+    LeoSizeChanged {
+        part_name: String,
+        from: LeoShape,
+        to: LeoShape,
+    },
+    /// An asset exists only in the new archive
+    FileAdded { path: String },
+    /// An asset exists only in the old archive
+    FileRemoved { path: String },
+    /// An asset's content changed
+    FileChanged {
+        path: String,
+        from: FileSummary,
+        to: FileSummary,
+    },
+}
+
+fn fmt_vec(v: &[f32; 3]) -> String {
+    format!("({}, {}, {})", v[0], v[1], v[2])
+}
+
+fn fmt_axis(axis: &Option<Axis>) -> String {
+    match axis {
+        Some(a) => format!("[{}, {}] step {}", a.min, a.max, a.step),
+        None => "fixed".to_string(),
+    }
+}
+
+fn fmt_summary(s: &FileSummary) -> String {
+    let hash = s.hash.to_string();
+    match s.vertex_count {
+        Some(n) => format!("{} ({} vertices)", &hash[..12], n),
+        None => hash[..12].to_string(),
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::PartAdded { part_name } => write!(f, "+ part '{}' added", part_name),
+            Change::PartRemoved { part_name } => write!(f, "- part '{}' removed", part_name),
+            Change::PartRenamed { from, to } => {
+                write!(f, "~ part '{}' renamed to '{}'", from, to)
+            }
+            Change::PartMoved {
+                part_name,
+                from,
+                to,
+            } => write!(
+                f,
+                "~ part '{}' moved from {} to {}",
+                part_name,
+                fmt_vec(from),
+                fmt_vec(to)
+            ),
+            Change::PartRotated {
+                part_name,
+                from,
+                to,
+            } => write!(
+                f,
+                "~ part '{}' rotated from {} to {}",
+                part_name,
+                fmt_vec(from),
+                fmt_vec(to)
+            ),
+            Change::GeometryReferenceChanged {
+                part_name,
+                from,
+                to,
+            } => write!(
+                f,
+                "~ part '{}' now uses geometry '{}' instead of '{}'",
+                part_name, to, from
+            ),
+            Change::JointAdded { part_name } => write!(f, "+ joint '{}' added", part_name),
+            Change::JointRemoved { part_name } => write!(f, "- joint '{}' removed", part_name),
+            Change::JointMoved {
+                part_name,
+                from,
+                to,
+            } => write!(
+                f,
+                "~ joint '{}' moved from {} to {}",
+                part_name,
+                fmt_vec(from),
+                fmt_vec(to)
+            ),
+            Change::JointRotated {
+                part_name,
+                from,
+                to,
+            } => write!(
+                f,
+                "~ joint '{}' rotated from {} to {}",
+                part_name,
+                fmt_vec(from),
+                fmt_vec(to)
+            ),
+            Change::JointRangeChanged {
+                part_name,
+                axis,
+                from,
+                to,
+            } => write!(
+                f,
+                "~ joint '{}' {} axis range changed from {} to {}",
+                part_name,
+                axis,
+                fmt_axis(from),
+                fmt_axis(to)
+            ),
+            Change::LeoAdded { part_name } => {
+                write!(f, "+ light emitting object '{}' added", part_name)
+            }
+            Change::LeoRemoved { part_name } => {
+                write!(f, "- light emitting object '{}' removed", part_name)
+            }
+            Change::LeoMoved {
+                part_name,
+                from,
+                to,
+            } => write!(
+                f,
+                "~ light emitting object '{}' moved from {} to {}",
+                part_name,
+                fmt_vec(from),
+                fmt_vec(to)
+            ),
+            Change::LeoRotated {
+                part_name,
+                from,
+                to,
+            } => write!(
+                f,
+                "~ light emitting object '{}' rotated from {} to {}",
+                part_name,
+                fmt_vec(from),
+                fmt_vec(to)
+            ),
+            Change::LeoSizeChanged {
+                part_name,
+                from,
+                to,
+            } => write!(
+                f,
+                "~ light emitting object '{}' changed from {} to {}",
+                part_name, from, to
+            ),
+            Change::FileAdded { path } => write!(f, "+ file '{}' added", path),
+            Change::FileRemoved { path } => write!(f, "- file '{}' removed", path),
+            Change::FileChanged { path, from, to } => write!(
+                f,
+                "~ file '{}' changed from {} to {}",
+                path,
+                fmt_summary(from),
+                fmt_summary(to)
+            ),
+        }
+    }
+}
+
+/// All semantic changes between two L3D files
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct L3dDiff {
+    /// Changes ordered by kind: parts, joints, light emitting objects, files
+    pub changes: Vec<Change>,
+}
+
+impl L3dDiff {
+    /// `true` if both files are semantically identical
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Serialize the changes to pretty-printed JSON
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+impl fmt::Display for L3dDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compute the semantic changes from `old` to `new`
+///
+/// Fails if the `structure.xml` of either file cannot be parsed.
+pub fn diff(old: &L3d, new: &L3d) -> Result<L3dDiff> {
+    let old_lum = old.luminaire().context("Failed to parse old structure")?;
+    let new_lum = new.luminaire().context("Failed to parse new structure")?;
+    let old_snap = Snapshot::new(old, &old_lum);
+    let new_snap = Snapshot::new(new, &new_lum);
+
+    let mut changes = Vec::new();
+    diff_geometries(&old_snap, &new_snap, &mut changes);
+    diff_joints(&old_snap, &new_snap, &mut changes);
+    diff_leos(&old_snap, &new_snap, &mut changes);
+    diff_files(old, new, &mut changes);
+    Ok(L3dDiff { changes })
+}
+
+fn vec3(v: &Vec3f) -> [f32; 3] {
+    [v.x, v.y, v.z]
+}
+
+/// Flattened view of a luminaire, keyed by unique part name
+struct Snapshot<'a> {
+    geometries: BTreeMap<String, GeometryInfo<'a>>,
+    joints: BTreeMap<String, &'a Joint>,
+    leos: BTreeMap<String, &'a LightEmittingObject>,
+}
+
+struct GeometryInfo<'a> {
+    geometry: &'a Geometry,
+    /// Hash of the referenced geometry file, falls back to the geometry id
+    content: String,
+}
+
+impl<'a> Snapshot<'a> {
+    fn new(l3d: &L3d, luminaire: &'a Luminaire) -> Self {
+        let mut snapshot = Snapshot {
+            geometries: BTreeMap::new(),
+            joints: BTreeMap::new(),
+            leos: BTreeMap::new(),
+        };
+        snapshot.collect(l3d, luminaire, &luminaire.structure.geometry);
+        snapshot
+    }
+
+    fn collect(&mut self, l3d: &L3d, luminaire: &'a Luminaire, geo: &'a Geometry) {
+        let id = &geo.geometry_reference.geometry_id;
+        let content = luminaire
+            .geometry_definitions
+            .geometry_file_definition
+            .iter()
+            .find(|d| &d.id == id)
+            .and_then(|d| l3d.asset(&format!("{}/{}", d.id, d.filename)))
+            .map(|a| a.content_hash().to_string())
+            .unwrap_or_else(|| id.clone());
+        insert_unique(
+            &mut self.geometries,
+            &geo.part_name,
+            GeometryInfo {
+                geometry: geo,
+                content,
+            },
+        );
+
+        if let Some(leos) = &geo.light_emitting_objects {
+            for leo in leos.objects() {
+                insert_unique(&mut self.leos, &leo.part_name, leo);
+            }
+        }
+        if let Some(joints) = &geo.joints {
+            for joint in &joints.joint {
+                insert_unique(&mut self.joints, &joint.part_name, joint);
+                for child in &joint.geometries.geometry {
+                    self.collect(l3d, luminaire, child);
+                }
+            }
+        }
+    }
+}
+
+/// Insert `value` under `name`, or under `name#2`, `name#3`, … if the name
+/// is taken, so parts sharing a name do not overwrite each other
+fn insert_unique<T>(map: &mut BTreeMap<String, T>, name: &str, value: T) {
+    let mut key = name.to_string();
+    let mut occurrence = 1;
+    while map.contains_key(&key) {
+        occurrence += 1;
+        key = format!("{}#{}", name, occurrence);
+    }
+    map.insert(key, value);
+}
+
+fn diff_geometries(old: &Snapshot, new: &Snapshot, changes: &mut Vec<Change>) {
+    let mut removed: Vec<&str> = Vec::new();
+    let mut added: Vec<&str> = new
+        .geometries
+        .keys()
+        .filter(|name| !old.geometries.contains_key(*name))
+        .map(String::as_str)
+        .collect();
+
+    for (name, old_info) in &old.geometries {
+        let Some(new_info) = new.geometries.get(name) else {
+            removed.push(name.as_str());
+            continue;
+        };
+        let (o, n) = (old_info.geometry, new_info.geometry);
+        if o.position != n.position {
+            changes.push(Change::PartMoved {
+                part_name: name.to_string(),
+                from: vec3(&o.position),
+                to: vec3(&n.position),
+            });
+        }
+        if o.rotation != n.rotation {
+            changes.push(Change::PartRotated {
+                part_name: name.to_string(),
+                from: vec3(&o.rotation),
+                to: vec3(&n.rotation),
+            });
+        }
+        let (old_id, new_id) = (
+            &o.geometry_reference.geometry_id,
+            &n.geometry_reference.geometry_id,
+        );
+        if old_id != new_id {
+            changes.push(Change::GeometryReferenceChanged {
+                part_name: name.to_string(),
+                from: old_id.clone(),
+                to: new_id.clone(),
+            });
+        }
+    }
+
+    // A removed and an added part with identical geometry and placement is a rename
+    removed.retain(|old_name| {
+        let o = &old.geometries[*old_name];
+        let matched = added.iter().position(|new_name| {
+            let n = &new.geometries[*new_name];
+            o.content == n.content
+                && o.geometry.position == n.geometry.position
+                && o.geometry.rotation == n.geometry.rotation
+        });
+        match matched {
+            Some(i) => {
+                changes.push(Change::PartRenamed {
+                    from: old_name.to_string(),
+                    to: added.remove(i).to_string(),
+                });
+                false
+            }
+            None => true,
+        }
+    });
+
+    changes.extend(removed.into_iter().map(|n| Change::PartRemoved {
+        part_name: n.to_string(),
+    }));
+    changes.extend(added.into_iter().map(|n| Change::PartAdded {
+        part_name: n.to_string(),
+    }));
+}
+
+fn diff_joints(old: &Snapshot, new: &Snapshot, changes: &mut Vec<Change>) {
+    for (name, o) in &old.joints {
+        let Some(n) = new.joints.get(name) else {
+            changes.push(Change::JointRemoved {
+                part_name: name.to_string(),
+            });
+            continue;
+        };
+        if o.position != n.position {
+            changes.push(Change::JointMoved {
+                part_name: name.to_string(),
+                from: vec3(&o.position),
+                to: vec3(&n.position),
+            });
+        }
+        if o.rotation != n.rotation {
+            changes.push(Change::JointRotated {
+                part_name: name.to_string(),
+                from: vec3(&o.rotation),
+                to: vec3(&n.rotation),
+            });
+        }
+        let axes = [
+            ("x", &o.x_axis, &n.x_axis),
+            ("y", &o.y_axis, &n.y_axis),
+            ("z", &o.z_axis, &n.z_axis),
+        ];
+        for (axis, from, to) in axes {
+            if from != to {
+                changes.push(Change::JointRangeChanged {
+                    part_name: name.to_string(),
+                    axis: axis.to_string(),
+                    from: from.clone(),
+                    to: to.clone(),
+                });
+            }
+        }
+    }
+    for name in new.joints.keys().filter(|n| !old.joints.contains_key(*n)) {
+        changes.push(Change::JointAdded {
+            part_name: name.to_string(),
+        });
+    }
+}
+
+fn diff_leos(old: &Snapshot, new: &Snapshot, changes: &mut Vec<Change>) {
+    for (name, o) in &old.leos {
+        let Some(n) = new.leos.get(name) else {
+            changes.push(Change::LeoRemoved {
+                part_name: name.to_string(),
+            });
+            continue;
+        };
+        if o.position != n.position {
+            changes.push(Change::LeoMoved {
+                part_name: name.to_string(),
+                from: vec3(&o.position),
+                to: vec3(&n.position),
+            });
+        }
+        if o.rotation != n.rotation {
+            changes.push(Change::LeoRotated {
+                part_name: name.to_string(),
+                from: vec3(&o.rotation),
+                to: vec3(&n.rotation),
+            });
+        }
+        let (from, to) = (LeoShape::of(o), LeoShape::of(n));
+        if from != to {
+            changes.push(Change::LeoSizeChanged {
+                part_name: name.to_string(),
+                from,
+                to,
+            });
+        }
+    }
+    for name in new.leos.keys().filter(|n| !old.leos.contains_key(*n)) {
+        changes.push(Change::LeoAdded {
+            part_name: name.to_string(),
+        });
+    }
+}
+
+fn diff_files(old: &L3d, new: &L3d, changes: &mut Vec<Change>) {
+    let names: BTreeSet<&str> = old
+        .file
+        .assets
+        .iter()
+        .chain(&new.file.assets)
+        .map(|a| a.name.as_str())
+        .collect();
+
+    for name in names {
+        match (old.asset(name), new.asset(name)) {
+            (Some(o), Some(n)) if o.content != n.content => changes.push(Change::FileChanged {
+                path: name.to_string(),
+                from: summarize(name, &o.content),
+                to: summarize(name, &n.content),
+            }),
+            (Some(_), None) => changes.push(Change::FileRemoved {
+                path: name.to_string(),
+            }),
+            (None, Some(_)) => changes.push(Change::FileAdded {
+                path: name.to_string(),
+            }),
+            _ => {}
+        }
+    }
+}
+
+fn summarize(path: &str, content: &[u8]) -> FileSummary {
+    let is_obj = path.to_ascii_lowercase().ends_with(".obj");
+    FileSummary {
+        hash: ContentHash::of(content),
+        vertex_count: is_obj
+            .then(|| ObjMesh::from_bytes(content).ok())
+            .flatten()
+            .map(|m| m.positions.len()),
+    }
+}
@@ -0,0 +1,197 @@
+//! # Editing
+//!
+//! Look up and modify geometries, joints and assets of a loaded luminaire
+//! and write the result back to an `.l3d` archive.
+//!
+//! The [`Luminaire`] structure has public fields, so positions, rotations
+//! and joint axes are changed directly on the value returned by
+//! [`Luminaire::geometry_mut`] or [`Luminaire::joint_mut`].
+//! [`L3d::set_luminaire`] then stores the edited structure and rebuilds the
+//! model; [`L3dFile::to_bytes`] writes the archive.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use l3d_rs::{from_buffer, Axis, BuildOptions};
+//!
+//! let mut l3d = from_buffer(&std::fs::read("luminaire.l3d").unwrap());
+//! let mut luminaire = l3d.luminaire().unwrap();
+//! luminaire.geometry_mut("head").unwrap().position.z = 0.25;
+//! luminaire.joint_mut("tilt").unwrap().y_axis = Some(Axis { min: -45.0, max: 45.0, step: 5.0 });
+//! luminaire.rename_part("head", "spot_head").unwrap();
+//! l3d.set_luminaire(&luminaire, &BuildOptions::default()).unwrap();
+//!
+//! std::fs::write("edited.l3d", l3d.file.to_bytes().unwrap()).unwrap();
+//! ```
+
+use crate::l3d::{BufFile, BuildOptions, Geometry, Joint, L3d, L3dFile, Luminaire};
+use anyhow::{bail, Result};
+
+impl Luminaire {
+    /// Find a geometry by its part name
+    pub fn geometry(&self, part_name: &str) -> Option<&Geometry> {
+        find_geometry(&self.structure.geometry, part_name)
+    }
+
+    /// Find a geometry by its part name for modification
+    pub fn geometry_mut(&mut self, part_name: &str) -> Option<&mut Geometry> {
+        find_geometry_mut(&mut self.structure.geometry, part_name)
+    }
+
+    /// Find a joint by its part name
+    pub fn joint(&self, part_name: &str) -> Option<&Joint> {
+        find_joint(&self.structure.geometry, part_name)
+    }
+
+    /// Find a joint by its part name for modification
+    pub fn joint_mut(&mut self, part_name: &str) -> Option<&mut Joint> {
+        find_joint_mut(&mut self.structure.geometry, part_name)
+    }
+
+    /// Part names of all geometries, joints, light emitting objects and
+    /// light emitting surfaces in document order
+    pub fn part_names(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        collect_part_names(&self.structure.geometry, &mut names);
+        names
+    }
+
+    /// Rename a geometry, joint, light emitting object or surface
+    ///
+    /// References from light emitting surfaces follow the rename. Fails if
+    /// no part is named `old` or `new` is already taken, since part names
+    /// identify parts in GLDF files.
+    pub fn rename_part(&mut self, old: &str, new: &str) -> Result<()> {
+        let names = self.part_names();
+        if !names.contains(&old) {
+            bail!("no part named {:?}", old);
+        }
+        if old == new {
+            return Ok(());
+        }
+        if names.contains(&new) {
+            bail!("part name {:?} is already used", new);
+        }
+        rename(&mut self.structure.geometry, old, new);
+        Ok(())
+    }
+}
+
+impl L3dFile {
+    /// Replace the contents of an asset, or add it if there is none
+    pub fn set_asset(&mut self, name: &str, content: Vec<u8>) {
+        let size = content.len() as u64;
+        match self.assets.iter_mut().find(|a| a.name == name) {
+            Some(asset) => {
+                asset.content = content;
+                asset.size = size;
+            }
+            None => self.assets.push(BufFile {
+                name: name.to_string(),
+                content,
+                size,
+            }),
+        }
+    }
+}
+
+impl L3d {
+    /// Store an edited luminaire as `structure.xml` and rebuild the model
+    pub fn set_luminaire(&mut self, luminaire: &Luminaire, options: &BuildOptions) -> Result<()> {
+        self.file.structure = luminaire.to_xml()?;
+        self.model = crate::build_model(luminaire, options, crate::Log::default());
+        Ok(())
+    }
+}
+
+/// Joints directly below a geometry
+fn joints(geo: &Geometry) -> impl Iterator<Item = &Joint> {
+    geo.joints.iter().flat_map(|j| &j.joint)
+}
+
+fn joints_mut(geo: &mut Geometry) -> impl Iterator<Item = &mut Joint> {
+    geo.joints.iter_mut().flat_map(|j| &mut j.joint)
+}
+
+fn find_geometry<'a>(geo: &'a Geometry, name: &str) -> Option<&'a Geometry> {
+    if geo.part_name == name {
+        return Some(geo);
+    }
+    joints(geo)
+        .flat_map(|j| &j.geometries.geometry)
+        .find_map(|child| find_geometry(child, name))
+}
+
+fn find_geometry_mut<'a>(geo: &'a mut Geometry, name: &str) -> Option<&'a mut Geometry> {
+    if geo.part_name == name {
+        return Some(geo);
+    }
+    joints_mut(geo)
+        .flat_map(|j| &mut j.geometries.geometry)
+        .find_map(|child| find_geometry_mut(child, name))
+}
+
+fn find_joint<'a>(geo: &'a Geometry, name: &str) -> Option<&'a Joint> {
+    joints(geo).find_map(|joint| {
+        if joint.part_name == name {
+            return Some(joint);
+        }
+        joint
+            .geometries
+            .geometry
+            .iter()
+            .find_map(|child| find_joint(child, name))
+    })
+}
+
+fn find_joint_mut<'a>(geo: &'a mut Geometry, name: &str) -> Option<&'a mut Joint> {
+    joints_mut(geo).find_map(|joint| {
+        if joint.part_name == name {
+            return Some(joint);
+        }
+        joint
+            .geometries
+            .geometry
+            .iter_mut()
+            .find_map(|child| find_joint_mut(child, name))
+    })
+}
+
+fn collect_part_names<'a>(geo: &'a Geometry, names: &mut Vec<&'a str>) {
+    names.push(&geo.part_name);
+    if let Some(leos) = &geo.light_emitting_objects {
+        names.extend(leos.objects().iter().map(|leo| leo.part_name()));
+    }
+    if let Some(surfaces) = &geo.light_emitting_surfaces {
+        names.extend(surfaces.part_names());
+    }
+    for joint in joints(geo) {
+        names.push(&joint.part_name);
+        for child in &joint.geometries.geometry {
+            collect_part_names(child, names);
+        }
+    }
+}
+
+fn rename(geo: &mut Geometry, old: &str, new: &str) {
+    let rename_one = |name: &mut String| {
+        if name == old {
+            *name = new.to_string();
+        }
+    };
+    rename_one(&mut geo.part_name);
+    if let Some(leos) = &mut geo.light_emitting_objects {
+        for leo in &mut leos.light_emitting_object {
+            rename_one(&mut leo.part_name);
+        }
+    }
+    if let Some(surfaces) = &mut geo.light_emitting_surfaces {
+        surfaces.rename_part(old, new);
+    }
+    for joint in joints_mut(geo) {
+        rename_one(&mut joint.part_name);
+        for child in &mut joint.geometries.geometry {
+            rename(child, old, new);
+        }
+    }
+}
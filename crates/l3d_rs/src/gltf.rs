@@ -0,0 +1,186 @@
+//! # glTF Export
+//!
+//! Write the assembled luminaire as binary glTF 2.0 (`.glb`), the format
+//! web viewers, game engines and DCC tools load directly.
+//!
+//! Every geometry file becomes one glTF mesh (shared by all parts that use
+//! it) and every part one node with its world matrix, converted to glTF's
+//! Y-up right-handed system in meters. Materials are not exported; meshes
+//! use the glTF default material.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use l3d_rs::from_buffer;
+//!
+//! let l3d = from_buffer(&std::fs::read("luminaire.l3d").unwrap());
+//! std::fs::write("luminaire.glb", l3d.to_glb().unwrap()).unwrap();
+//! ```
+
+use crate::l3d::{mat4_inverse, mat4_mul, CoordinateSystem, L3d};
+use crate::render::{RenderOptions, VERTEX_STRIDE};
+use anyhow::{anyhow, ensure, Result};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+const GLB_MAGIC: &[u8; 4] = b"glTF";
+const CHUNK_JSON: u32 = 0x4E4F_534A;
+const CHUNK_BIN: u32 = 0x004E_4942;
+
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+const FLOAT: u32 = 5126;
+const UNSIGNED_INT: u32 = 5125;
+
+impl L3d {
+    /// Export the model as a binary glTF (`.glb`) file
+    ///
+    /// Parts whose geometry is missing, cannot be parsed or has no faces
+    /// are left out. Fails if no part has usable geometry.
+    pub fn to_glb(&self) -> Result<Vec<u8>> {
+        // glTF does not allow empty accessors or buffer views
+        let buffers: Vec<_> = self
+            .render_buffers(&RenderOptions::default())
+            .into_iter()
+            .filter(|buffer| !buffer.indices.is_empty())
+            .collect();
+        ensure!(!buffers.is_empty(), "model has no exportable geometry");
+
+        // Matrix from the model's coordinate system to glTF's
+        let to_gltf = mat4_mul(
+            &CoordinateSystem::YUpRightHanded.matrix(),
+            &mat4_inverse(&self.model.coordinate_system.matrix())
+                .ok_or_else(|| anyhow!("invalid coordinate system"))?,
+        );
+
+        let mut bin = Vec::new();
+        let mut buffer_views = Vec::new();
+        let mut accessors = Vec::new();
+        let mut meshes = Vec::new();
+        let mut mesh_ids: HashMap<&str, usize> = HashMap::new();
+        let mut nodes = Vec::new();
+
+        for buffer in &buffers {
+            let mesh = match mesh_ids.get(buffer.path.as_str()) {
+                Some(&mesh) => mesh,
+                None => {
+                    let vertex_view = buffer_views.len();
+                    buffer_views.push(json!({
+                        "buffer": 0,
+                        "byteOffset": bin.len(),
+                        "byteLength": buffer.vertices.len() * 4,
+                        "byteStride": VERTEX_STRIDE * 4,
+                        "target": ARRAY_BUFFER,
+                    }));
+                    for vertex in buffer.vertices.chunks_exact(VERTEX_STRIDE) {
+                        let mut vertex = <[f32; VERTEX_STRIDE]>::try_from(vertex)?;
+                        // glTF requires unit normals
+                        if vertex[3..6].iter().all(|&c| c == 0.0) {
+                            vertex[5] = 1.0;
+                        }
+                        // glTF texture coordinates start at the top
+                        vertex[7] = 1.0 - vertex[7];
+                        bin.extend(vertex.iter().flat_map(|v| v.to_le_bytes()));
+                    }
+
+                    let index_view = buffer_views.len();
+                    buffer_views.push(json!({
+                        "buffer": 0,
+                        "byteOffset": bin.len(),
+                        "byteLength": buffer.indices.len() * 4,
+                        "target": ELEMENT_ARRAY_BUFFER,
+                    }));
+                    bin.extend(buffer.index_bytes());
+
+                    let (min, max) = position_bounds(&buffer.vertices);
+                    let count = buffer.vertex_count();
+                    let position = accessors.len();
+                    accessors.push(json!({
+                        "bufferView": vertex_view, "byteOffset": 0, "componentType": FLOAT,
+                        "count": count, "type": "VEC3", "min": min, "max": max,
+                    }));
+                    accessors.push(json!({
+                        "bufferView": vertex_view, "byteOffset": 12, "componentType": FLOAT,
+                        "count": count, "type": "VEC3",
+                    }));
+                    accessors.push(json!({
+                        "bufferView": vertex_view, "byteOffset": 24, "componentType": FLOAT,
+                        "count": count, "type": "VEC2",
+                    }));
+                    accessors.push(json!({
+                        "bufferView": index_view, "componentType": UNSIGNED_INT,
+                        "count": buffer.indices.len(), "type": "SCALAR",
+                    }));
+
+                    meshes.push(json!({
+                        "name": buffer.path,
+                        "primitives": [{
+                            "attributes": {
+                                "POSITION": position,
+                                "NORMAL": position + 1,
+                                "TEXCOORD_0": position + 2,
+                            },
+                            "indices": position + 3,
+                        }],
+                    }));
+                    mesh_ids.insert(&buffer.path, meshes.len() - 1);
+                    meshes.len() - 1
+                }
+            };
+
+            nodes.push(json!({
+                "name": buffer.name,
+                "mesh": mesh,
+                "matrix": mat4_mul(&to_gltf, &buffer.transform),
+            }));
+        }
+
+        let document = json!({
+            "asset": {
+                "version": "2.0",
+                "generator": concat!("l3d_rs ", env!("CARGO_PKG_VERSION")),
+            },
+            "scene": 0,
+            "scenes": [{ "nodes": (0..nodes.len()).collect::<Vec<_>>() }],
+            "nodes": nodes,
+            "meshes": meshes,
+            "accessors": accessors,
+            "bufferViews": buffer_views,
+            "buffers": [{ "byteLength": bin.len() }],
+        });
+        Ok(write_glb(&document, bin))
+    }
+}
+
+/// Component-wise min and max of the positions of interleaved vertices
+fn position_bounds(vertices: &[f32]) -> ([f32; 3], [f32; 3]) {
+    let mut min = [f32::MAX; 3];
+    let mut max = [f32::MIN; 3];
+    for vertex in vertices.chunks_exact(VERTEX_STRIDE) {
+        for k in 0..3 {
+            min[k] = min[k].min(vertex[k]);
+            max[k] = max[k].max(vertex[k]);
+        }
+    }
+    (min, max)
+}
+
+/// Pack the JSON document and binary buffer into a GLB container
+fn write_glb(document: &Value, mut bin: Vec<u8>) -> Vec<u8> {
+    // Chunks are 4-byte aligned: JSON with spaces, binary with zeros
+    let mut json = document.to_string().into_bytes();
+    json.resize((json.len() + 3) & !3, b' ');
+    bin.resize((bin.len() + 3) & !3, 0);
+
+    let total = 12 + 8 + json.len() + 8 + bin.len();
+    let mut glb = Vec::with_capacity(total);
+    glb.extend_from_slice(GLB_MAGIC);
+    glb.extend_from_slice(&2u32.to_le_bytes());
+    glb.extend_from_slice(&(total as u32).to_le_bytes());
+    for (kind, data) in [(CHUNK_JSON, json), (CHUNK_BIN, bin)] {
+        glb.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        glb.extend_from_slice(&kind.to_le_bytes());
+        glb.extend_from_slice(&data);
+    }
+    glb
+}
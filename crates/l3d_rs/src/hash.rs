@@ -0,0 +1,207 @@
+//! # Content Hashing and Asset Deduplication
+//!
+//! Catalogue luminaires often ship the same OBJ file in many variants. This
+//! module provides a stable SHA-256 [`ContentHash`] for every asset and for
+//! every resolved part (geometry content plus world transform), and an
+//! [`AssetIndex`] that groups identical assets within and across archives so
+//! each unique file only needs to be stored or uploaded once.
+//!
+//! Hashes depend only on the bytes (and, for parts, the matrix values), so
+//! they are stable across platforms, processes and crate versions.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use l3d_rs::{from_buffer, AssetIndex};
+//!
+//! let mut index = AssetIndex::new();
+//! for path in ["variant_a.l3d", "variant_b.l3d"] {
+//!     let l3d = from_buffer(&std::fs::read(path).unwrap());
+//!     index.add_archive(path, &l3d);
+//! }
+//!
+//! for entry in index.entries() {
+//!     // Upload `entry.hash` once, reference it from every location
+//!     println!("{} used {} times", entry.hash, entry.locations.len());
+//! }
+//! println!("{} bytes saved", index.duplicate_bytes());
+//! ```
+
+use crate::l3d::{BufFile, L3d, L3dPart};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// SHA-256 digest of some content
+///
+/// Displays and serializes as 64 lowercase hex characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ContentHash(pub [u8; 32]);
+
+impl ContentHash {
+    /// Hash arbitrary bytes
+    pub fn of(bytes: &[u8]) -> Self {
+        ContentHash(Sha256::digest(bytes).into())
+    }
+}
+
+impl fmt::Display for ContentHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for b in &self.0 {
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ContentHash {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            s.len() == 64 && s.is_ascii(),
+            "content hash must be 64 hex characters"
+        );
+        let mut bytes = [0u8; 32];
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16)?;
+        }
+        Ok(ContentHash(bytes))
+    }
+}
+
+impl Serialize for ContentHash {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ContentHash {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl BufFile {
+    /// Stable hash of the file contents (the name is not included)
+    pub fn content_hash(&self) -> ContentHash {
+        ContentHash::of(&self.content)
+    }
+}
+
+impl L3d {
+    /// Stable hash of a resolved part: its geometry content and world matrix
+    ///
+    /// Two parts hash equal when they render identically, regardless of the
+    /// archive, geometry id or file name they come from. Returns `None` if
+    /// the part's geometry file is not in the archive.
+    pub fn part_hash(&self, part: &L3dPart) -> Option<ContentHash> {
+        let geometry = self.asset(&part.path)?;
+        let mut hasher = Sha256::new();
+        hasher.update(geometry.content_hash().0);
+        for v in part.mat {
+            // Treat -0.0 and 0.0 as the same value
+            let v = if v == 0.0 { 0.0f32 } else { v };
+            hasher.update(v.to_le_bytes());
+        }
+        Some(ContentHash(hasher.finalize().into()))
+    }
+}
+
+/// Where a piece of content was found
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetLocation {
+    /// Label of the archive passed to [`AssetIndex::add_archive`]
+    pub archive: String,
+    /// Asset path within the archive
+    pub name: String,
+}
+
+/// A unique piece of content and every place it occurs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetEntry {
+    /// Hash of the content
+    pub hash: ContentHash,
+    /// Content size in bytes
+    pub size: u64,
+    /// All occurrences, in the order they were added
+    pub locations: Vec<AssetLocation>,
+}
+
+/// Index of unique assets across one or more L3D archives
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AssetIndex {
+    entries: BTreeMap<ContentHash, AssetEntry>,
+}
+
+impl AssetIndex {
+    /// Create an empty index
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add all assets of an archive
+    ///
+    /// `archive` is a label (typically the file path) used in
+    /// [`AssetLocation`]. Returns the hash of each asset, in archive order.
+    pub fn add_archive(&mut self, archive: &str, l3d: &L3d) -> Vec<ContentHash> {
+        l3d.file
+            .assets
+            .iter()
+            .map(|asset| self.add_asset(archive, asset))
+            .collect()
+    }
+
+    /// Add a single asset and return its hash
+    pub fn add_asset(&mut self, archive: &str, asset: &BufFile) -> ContentHash {
+        let hash = asset.content_hash();
+        self.entries
+            .entry(hash)
+            .or_insert_with(|| AssetEntry {
+                hash,
+                size: asset.content.len() as u64,
+                locations: Vec::new(),
+            })
+            .locations
+            .push(AssetLocation {
+                archive: archive.to_string(),
+                name: asset.name.clone(),
+            });
+        hash
+    }
+
+    /// Look up the entry for a hash
+    pub fn get(&self, hash: &ContentHash) -> Option<&AssetEntry> {
+        self.entries.get(hash)
+    }
+
+    /// All unique assets, ordered by hash
+    pub fn entries(&self) -> impl Iterator<Item = &AssetEntry> {
+        self.entries.values()
+    }
+
+    /// Unique assets that occur more than once
+    pub fn duplicates(&self) -> impl Iterator<Item = &AssetEntry> {
+        self.entries().filter(|e| e.locations.len() > 1)
+    }
+
+    /// Number of unique assets
+    pub fn unique_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Total bytes of all unique assets
+    pub fn unique_bytes(&self) -> u64 {
+        self.entries().map(|e| e.size).sum()
+    }
+
+    /// Bytes that need not be stored thanks to deduplication
+    pub fn duplicate_bytes(&self) -> u64 {
+        self.entries()
+            .map(|e| e.size * (e.locations.len() as u64 - 1))
+            .sum()
+    }
+}
@@ -0,0 +1,89 @@
+//! Coordinate system conventions
+//!
+//! L3D geometry is Z-up and right-handed. Most real-time renderers and
+//! exchange formats use a different convention, so model building can
+//! convert all transforms into the caller's system (see
+//! [`BuildOptions`](super::BuildOptions)).
+
+use super::{mat4_mul, Mat4};
+use crate::obj::ObjMesh;
+use serde::{Deserialize, Serialize};
+
+/// Axis convention of transforms and geometry
+///
+/// Conversions keep X pointing the same way and move the L3D up axis (+Z)
+/// to the target's up axis.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CoordinateSystem {
+    /// Z-up, right-handed, as stored in L3D files
+    #[default]
+    ZUpRightHanded,
+    /// Y-up, right-handed (glTF, three.js, three-d, WebGL)
+    ///
+    /// Maps `(x, y, z)` to `(x, z, -y)`.
+    YUpRightHanded,
+    /// Y-up, left-handed (Unity)
+    ///
+    /// Maps `(x, y, z)` to `(x, z, y)`.
+    YUpLeftHanded,
+}
+
+impl CoordinateSystem {
+    /// Matrix converting L3D coordinates into this system
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use l3d_rs::{mat4_transform_point, CoordinateSystem};
+    ///
+    /// let m = CoordinateSystem::YUpRightHanded.matrix();
+    /// assert_eq!(mat4_transform_point(&m, [0.0, 0.0, 1.0]), [0.0, 1.0, 0.0]);
+    /// ```
+    pub fn matrix(self) -> Mat4 {
+        match self {
+            CoordinateSystem::ZUpRightHanded => super::MAT4_IDENTITY,
+            CoordinateSystem::YUpRightHanded => [
+                1.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+            ],
+            CoordinateSystem::YUpLeftHanded => [
+                1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+            ],
+        }
+    }
+
+    /// Whether this system has the opposite handedness of L3D
+    ///
+    /// Geometry converted into such a system must have its triangle winding
+    /// reversed to keep front faces facing outward.
+    pub fn flips_winding(self) -> bool {
+        self == CoordinateSystem::YUpLeftHanded
+    }
+
+    /// Convert a point or direction from L3D coordinates
+    pub fn convert_point(self, [x, y, z]: [f32; 3]) -> [f32; 3] {
+        match self {
+            CoordinateSystem::ZUpRightHanded => [x, y, z],
+            CoordinateSystem::YUpRightHanded => [x, z, -y],
+            CoordinateSystem::YUpLeftHanded => [x, z, y],
+        }
+    }
+
+    /// Convert a world matrix computed in L3D coordinates
+    pub fn convert_matrix(self, mat: &Mat4) -> Mat4 {
+        mat4_mul(&self.matrix(), mat)
+    }
+
+    /// Convert mesh positions and normals from L3D coordinates in place
+    ///
+    /// Face winding is reversed if the handedness changes.
+    pub fn convert_mesh(self, mesh: &mut ObjMesh) {
+        for p in mesh.positions.iter_mut().chain(mesh.normals.iter_mut()) {
+            *p = self.convert_point(*p);
+        }
+        if self.flips_winding() {
+            for face in &mut mesh.faces {
+                face.vertices.reverse();
+            }
+        }
+    }
+}
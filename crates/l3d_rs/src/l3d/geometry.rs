@@ -27,8 +27,11 @@ pub struct Geometry {
     pub position: Vec3f,
     pub rotation: Vec3f,
     pub geometry_reference: GeometryReference,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub joints: Option<Joints>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub light_emitting_objects: Option<LightEmittingObjects>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub light_emitting_surfaces: Option<LightEmittingSurfaces>,
 }
 
@@ -4,10 +4,13 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Header {
+    #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     description: Option<String>,
     created_with_application: String,
     creation_time_code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     format_version: Option<FormatVersion>,
 }
 
@@ -17,6 +20,36 @@ struct FormatVersion {
     major: u8,
     #[serde(rename = "@minor")]
     minor: u8,
-    #[serde(rename = "@pre-release")]
+    #[serde(rename = "@pre-release", skip_serializing_if = "Option::is_none")]
     pre_release: Option<u8>,
 }
+
+impl Header {
+    /// Name of the luminaire, if given
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Description of the luminaire, if given
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Application that wrote the file
+    pub fn created_with_application(&self) -> &str {
+        &self.created_with_application
+    }
+
+    /// Creation time as written in the file (ISO 8601)
+    pub fn creation_time_code(&self) -> &str {
+        &self.creation_time_code
+    }
+
+    /// Format version as `major.minor` or `major.minor-pre`, if given
+    pub fn format_version(&self) -> Option<String> {
+        self.format_version.as_ref().map(|v| match v.pre_release {
+            Some(pre) => format!("{}.{}-{}", v.major, v.minor, pre),
+            None => format!("{}.{}", v.major, v.minor),
+        })
+    }
+}
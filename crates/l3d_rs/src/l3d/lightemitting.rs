@@ -1,5 +1,7 @@
 use super::structure::{Circle, Rectangle, Vec3f};
+use super::{Geometry, Luminaire};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct LightEmittingObjects {
@@ -25,8 +27,10 @@ pub struct LightEmittingObject {
     /// Rotation (x, y, z) in degrees
     pub rotation: Vec3f,
     /// Rectangle shape (if this LEO is rectangular)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rectangle: Option<Rectangle>,
     /// Circle shape (if this LEO is circular)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub circle: Option<Circle>,
 }
 
@@ -69,6 +73,31 @@ pub struct LightEmittingSurfaces {
     light_emitting_surface: Vec<LightEmittingSurface>,
 }
 
+impl LightEmittingSurfaces {
+    /// Part names of the surfaces
+    pub(crate) fn part_names(&self) -> impl Iterator<Item = &str> {
+        self.light_emitting_surface
+            .iter()
+            .map(|s| s.part_name.as_str())
+    }
+
+    /// Rename surfaces and references to light emitting objects named `old`
+    pub(crate) fn rename_part(&mut self, old: &str, new: &str) {
+        for surface in &mut self.light_emitting_surface {
+            for name in [
+                &mut surface.part_name,
+                &mut surface
+                    .light_emitting_object_reference
+                    .light_emitting_part_name,
+            ] {
+                if name == old {
+                    *name = new.to_string();
+                }
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct LightEmittingSurface {
@@ -81,7 +110,9 @@ pub struct LightEmittingSurface {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 struct FaceAssignments {
+    #[serde(skip_serializing_if = "Option::is_none")]
     face_assignment: Option<Vec<FaceAssignment>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     face_range_assignment: Option<Vec<FaceRangeAssignment>>,
 }
 
@@ -98,3 +129,96 @@ pub struct FaceRangeAssignment {
     #[serde(rename = "@faceIndexEnd")]
     face_index_end: usize,
 }
+
+/// Outline of a light emitting object
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmitterShape {
+    Rectangle,
+    Circle,
+    /// Neither a rectangle nor a circle is defined
+    Unknown,
+}
+
+impl EmitterShape {
+    /// Lowercase name: `"rectangle"`, `"circle"` or `"unknown"`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EmitterShape::Rectangle => "rectangle",
+            EmitterShape::Circle => "circle",
+            EmitterShape::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for EmitterShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A light emitting object together with the geometry it belongs to
+///
+/// Returned by [`Luminaire::light_emitters`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LightEmitter {
+    /// Part name of the light emitting object
+    pub name: String,
+    /// Part name of the geometry it belongs to
+    pub geometry_part_name: String,
+    /// Position relative to the geometry
+    pub position: Vec3f,
+    /// Rotation in degrees relative to the geometry
+    pub rotation: Vec3f,
+    pub shape: EmitterShape,
+    /// Width of a rectangle or diameter of a circle, zero if unknown
+    pub size_x: f64,
+    /// Height of a rectangle, zero otherwise
+    pub size_y: f64,
+}
+
+impl Luminaire {
+    /// Light emitting objects of all geometries in document order
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use l3d_rs::Luminaire;
+    ///
+    /// let luminaire = Luminaire::load_l3d("luminaire.l3d").unwrap();
+    /// for leo in luminaire.light_emitters() {
+    ///     println!("{} on {}: {} {}", leo.name, leo.geometry_part_name, leo.shape, leo.size_x);
+    /// }
+    /// ```
+    pub fn light_emitters(&self) -> Vec<LightEmitter> {
+        let mut emitters = Vec::new();
+        collect_emitters(&self.structure.geometry, &mut emitters);
+        emitters
+    }
+}
+
+fn collect_emitters(geometry: &Geometry, emitters: &mut Vec<LightEmitter>) {
+    if let Some(leos) = &geometry.light_emitting_objects {
+        for leo in leos.objects() {
+            let (shape, size_x, size_y) = match (leo.rectangle(), leo.circle()) {
+                (Some(rect), _) => (EmitterShape::Rectangle, rect.size_x, rect.size_y),
+                (None, Some(circle)) => (EmitterShape::Circle, circle.diameter, 0.0),
+                (None, None) => (EmitterShape::Unknown, 0.0, 0.0),
+            };
+            emitters.push(LightEmitter {
+                name: leo.part_name.clone(),
+                geometry_part_name: geometry.part_name.clone(),
+                position: leo.position.clone(),
+                rotation: leo.rotation.clone(),
+                shape,
+                size_x,
+                size_y,
+            });
+        }
+    }
+    for joint in geometry.joints.iter().flat_map(|j| &j.joint) {
+        for child in &joint.geometries.geometry {
+            collect_emitters(child, emitters);
+        }
+    }
+}
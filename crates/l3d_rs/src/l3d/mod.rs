@@ -17,26 +17,43 @@
 //! - [`L3dModel`] - Collection of geometry parts with transforms
 //! - [`L3dPart`] - Single geometry part with transformation matrix
 //! - [`Mat4`] - 4x4 transformation matrix (column-major `[f32; 16]`)
+//! - [`DMat4`] - The same in double precision (`[f64; 16]`)
+//! - [`BuildOptions`] - How model matrices are computed (e.g. [`CoordinateSystem`])
+//! - [`RotationOrder`] - How the Euler angles of `Rotation` elements combine
 //!
 //! ## Matrix Utilities
 //!
 //! Self-contained matrix functions (no external dependencies):
 //! - [`mat4_mul`] - Matrix multiplication
 //! - [`mat4_translation`], [`mat4_scale`] - Basic transforms
+//! - [`mat4_transform_point`], [`mat4_transform_direction`] - Apply a matrix
+//!   to points and directions
+//! - [`mat4_inverse`], [`mat4_transpose`], [`mat4_determinant`],
+//!   [`mat4_normal_matrix`] - Inversion and normal transforms
+//! - [`mat4_decompose`], [`mat4_compose`], [`mat4_from_quat`] - Split a
+//!   transform into translation, rotation and scale and back
 //! - [`mat4_rotate_x`], [`mat4_rotate_y`], [`mat4_rotate_z`] - Rotations
 //! - [`build_transform`] - Build transform from position and rotation
+//! - [`quat_from_axis_angle`], [`quat_to_axis_angle`], [`dmat4_from_quat`] -
+//!   Quaternion and axis-angle conversions
+//! - `dmat4_*`, [`build_transform_f64`] - Double-precision variants
 
+pub mod coordinate;
 pub mod geometry;
 pub mod header;
 pub mod lightemitting;
+pub mod rotation;
 pub mod structure;
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
+pub use coordinate::CoordinateSystem;
 pub use geometry::{Geometries, Geometry, GeometryDefinitions, GeometryFileDefinition};
 pub use header::Header;
-pub use lightemitting::{LightEmittingObject, LightEmittingObjects};
-pub use structure::{Circle, Joint, Joints, Rectangle, Structure, Vec3f};
+pub use lightemitting::{EmitterShape, LightEmitter, LightEmittingObject, LightEmittingObjects};
+pub use rotation::{dmat4_from_quat, quat_from_axis_angle, quat_to_axis_angle, RotationOrder};
+pub use structure::{Axis, Circle, Joint, Joints, Rectangle, Structure, Vec3f};
 
 /// Root element representing a complete luminaire definition
 ///
@@ -102,6 +119,31 @@ pub struct Luminaire {
 /// ```
 pub type Mat4 = [f32; 16];
 
+/// 4x4 matrix in column-major order with double precision
+///
+/// Same layout as [`Mat4`]; used for the `f64` transform pipeline.
+pub type DMat4 = [f64; 16];
+
+/// 3x3 matrix in column-major order, e.g. a normal matrix
+///
+/// Converts to `glam::Mat3::from_cols_array` and similar in the same way
+/// as [`Mat4`].
+pub type Mat3 = [f32; 9];
+
+/// A transform split into translation, rotation and scale
+///
+/// Returned by [`mat4_decompose`]; [`mat4_compose`] rebuilds the matrix
+/// as `translation * rotation * scale`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Decomposition {
+    /// Translation (x, y, z)
+    pub translation: [f32; 3],
+    /// Rotation as a unit quaternion (x, y, z, w)
+    pub rotation: [f32; 4],
+    /// Scale along the local axes; a mirrored transform has a negative x
+    pub scale: [f32; 3],
+}
+
 /// Identity matrix constant
 ///
 /// Use this as the starting point for transformation chains:
@@ -122,6 +164,7 @@ pub const MAT4_IDENTITY: Mat4 = [
 ///
 /// # Fields
 ///
+/// - `name` - Part name from `structure.xml` (the `partName` attribute)
 /// - `path` - Path to the OBJ file within the L3D archive (e.g., "geom_1/luminaire.obj")
 /// - `mat` - 4x4 transformation matrix including position, rotation, and scale
 ///
@@ -139,10 +182,81 @@ pub const MAT4_IDENTITY: Mat4 = [
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct L3dPart {
+    /// Part name from `structure.xml`
+    #[serde(default)]
+    pub name: String,
     /// Path to the geometry file (e.g., "geom_1/luminaire.obj")
     pub path: String,
     /// 4x4 transformation matrix (column-major order)
     pub mat: Mat4,
+    /// The same matrix in double precision
+    ///
+    /// Only set when the model was built with
+    /// [`BuildOptions::double_precision`]; see [`L3dPart::world_matrix_f64`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mat_f64: Option<DMat4>,
+}
+
+impl L3dPart {
+    /// World matrix in double precision
+    ///
+    /// Falls back to widening [`L3dPart::mat`] if the model was built
+    /// without [`BuildOptions::double_precision`].
+    pub fn world_matrix_f64(&self) -> DMat4 {
+        self.mat_f64.unwrap_or_else(|| self.mat.map(f64::from))
+    }
+}
+
+/// A geometry of the structure with its transforms resolved
+///
+/// Returned by [`Luminaire::resolve_parts`]; every [`L3dPart`] of a model
+/// is built from one of these. Unlike [`L3dPart`] it keeps the pieces the
+/// world matrix is made of, so editors and bindings can show local values
+/// without walking the geometry tree again.
+///
+/// All matrices are column-major and the world frame is the coordinate
+/// system of [`BuildOptions::coordinate_system`]; `world` equals
+/// `parent * local * scale`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedPart {
+    /// Part name from `structure.xml`
+    pub name: String,
+    /// ID of the referenced geometry file definition
+    pub geometry_id: String,
+    /// Path to the OBJ file, empty if the geometry ID is not defined
+    pub path: String,
+    /// Unit scale of the geometry file to meters
+    pub scale: f64,
+    /// Position relative to the parent frame
+    pub position: Vec3f,
+    /// Rotation in degrees relative to the parent frame
+    pub rotation: Vec3f,
+    /// Translation and rotation relative to the parent frame
+    pub local: DMat4,
+    /// World matrix of the parent frame, including joint transforms
+    pub parent: DMat4,
+    /// World matrix of the part including the unit scale
+    pub world: DMat4,
+}
+
+impl ResolvedPart {
+    /// Origin of the part in world space
+    pub fn world_position(&self) -> [f64; 3] {
+        [self.world[12], self.world[13], self.world[14]]
+    }
+}
+
+/// A geometry reached by [`Luminaire::walk_geometries`]
+#[derive(Debug, Clone, Copy)]
+pub struct GeometryVisit<'a> {
+    pub geometry: &'a Geometry,
+    /// Joint the geometry is attached to, `None` for the root
+    pub joint: Option<&'a Joint>,
+    /// Index of the parent geometry in the list of visits
+    pub parent: Option<usize>,
+    /// Index of the part in [`Luminaire::resolve_parts`] and
+    /// [`L3dModel::parts`]
+    pub part_index: usize,
 }
 
 /// Collection of geometry parts that make up the 3D model
@@ -166,6 +280,52 @@ pub struct L3dPart {
 pub struct L3dModel {
     /// List of geometry parts with their transformations
     pub parts: Vec<L3dPart>,
+    /// Coordinate system the part matrices are expressed in
+    #[serde(default)]
+    pub coordinate_system: CoordinateSystem,
+}
+
+/// Options for building an [`L3dModel`] from the luminaire structure
+///
+/// # Example
+///
+/// ```no_run
+/// use l3d_rs::{from_buffer_with_options, BuildOptions, CoordinateSystem};
+///
+/// let options = BuildOptions {
+///     coordinate_system: CoordinateSystem::YUpRightHanded,
+///     ..Default::default()
+/// };
+/// let l3d = from_buffer_with_options(&std::fs::read("luminaire.l3d").unwrap(), &options);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BuildOptions {
+    /// Coordinate system of the resulting part matrices
+    ///
+    /// The OBJ geometry itself stays in L3D coordinates; the part matrices
+    /// include the conversion. For left-handed systems, renderers must
+    /// reverse the triangle winding (see [`CoordinateSystem::flips_winding`]).
+    pub coordinate_system: CoordinateSystem,
+    /// How the Euler angles of `Position`/`Rotation` pairs are combined
+    ///
+    /// Defaults to [`RotationOrder::Xyz`], the order this crate has always
+    /// used. Change it for files from tools that combine the angles in a
+    /// different order.
+    pub rotation_order: RotationOrder,
+    /// Keep the `f64` world matrix of every part in [`L3dPart::mat_f64`]
+    ///
+    /// Transforms are always accumulated in double precision; this only
+    /// controls whether the result is kept for exporters that need it.
+    pub double_precision: bool,
+    /// Current angles in degrees of adjustable joints, by joint part name
+    ///
+    /// The angles rotate the joint's children about the joint's own axes,
+    /// after its `Position` and `Rotation`, and are clamped to the ranges
+    /// of [`Joint::x_axis`], [`Joint::y_axis`] and [`Joint::z_axis`]; an
+    /// axis without a range cannot rotate. Joints that are not listed keep
+    /// the pose from `structure.xml`. Use [`Joint::default_rotation`] as
+    /// the initial value in a configurator.
+    pub joint_angles: HashMap<String, Vec3f>,
 }
 
 /// A file extracted from the L3D ZIP archive
@@ -287,6 +447,337 @@ pub fn mat4_mul(a: &Mat4, b: &Mat4) -> Mat4 {
     result
 }
 
+/// Transform a point by a 4x4 matrix
+///
+/// The point is treated as `(x, y, z, 1)`, so translation is applied.
+/// The matrix is assumed to be affine (no perspective division).
+///
+/// # Example
+///
+/// ```
+/// use l3d_rs::{mat4_transform_point, mat4_translation};
+///
+/// let p = mat4_transform_point(&mat4_translation(1.0, 2.0, 3.0), [1.0, 1.0, 1.0]);
+/// assert_eq!(p, [2.0, 3.0, 4.0]);
+/// ```
+pub fn mat4_transform_point(m: &Mat4, p: [f32; 3]) -> [f32; 3] {
+    [
+        m[0] * p[0] + m[4] * p[1] + m[8] * p[2] + m[12],
+        m[1] * p[0] + m[5] * p[1] + m[9] * p[2] + m[13],
+        m[2] * p[0] + m[6] * p[1] + m[10] * p[2] + m[14],
+    ]
+}
+
+/// Transform a direction by a 4x4 matrix
+///
+/// The direction is treated as `(x, y, z, 0)`, so translation is ignored.
+/// Use [`mat4_normal_matrix`] for surface normals under non-uniform scale.
+///
+/// # Example
+///
+/// ```
+/// use l3d_rs::{mat4_transform_direction, mat4_translation};
+///
+/// let d = mat4_transform_direction(&mat4_translation(1.0, 2.0, 3.0), [0.0, 0.0, 1.0]);
+/// assert_eq!(d, [0.0, 0.0, 1.0]);
+/// ```
+pub fn mat4_transform_direction(m: &Mat4, d: [f32; 3]) -> [f32; 3] {
+    [
+        m[0] * d[0] + m[4] * d[1] + m[8] * d[2],
+        m[1] * d[0] + m[5] * d[1] + m[9] * d[2],
+        m[2] * d[0] + m[6] * d[1] + m[10] * d[2],
+    ]
+}
+
+/// Transpose a 4x4 matrix
+pub fn mat4_transpose(m: &Mat4) -> Mat4 {
+    let mut result = [0.0f32; 16];
+    for col in 0..4 {
+        for row in 0..4 {
+            result[row * 4 + col] = m[col * 4 + row];
+        }
+    }
+    result
+}
+
+/// Determinant of a 4x4 matrix
+///
+/// Negative for transforms that mirror the geometry.
+pub fn mat4_determinant(m: &Mat4) -> f32 {
+    let (_, det) = cofactors(m);
+    det as f32
+}
+
+/// Invert a 4x4 matrix
+///
+/// Returns `None` if the matrix is singular (e.g. a zero scale).
+///
+/// # Example
+///
+/// ```
+/// use l3d_rs::{mat4_inverse, mat4_transform_point, mat4_translation};
+///
+/// let inv = mat4_inverse(&mat4_translation(1.0, 2.0, 3.0)).unwrap();
+/// assert_eq!(mat4_transform_point(&inv, [1.0, 2.0, 3.0]), [0.0, 0.0, 0.0]);
+/// ```
+pub fn mat4_inverse(m: &Mat4) -> Option<Mat4> {
+    let (adjugate, det) = cofactors(m);
+    if det.abs() <= f64::EPSILON * det_bound(m) {
+        return None;
+    }
+    let mut result = [0.0f32; 16];
+    for (r, a) in result.iter_mut().zip(adjugate) {
+        *r = (a / det) as f32;
+    }
+    Some(result)
+}
+
+/// Normal matrix of a transform: the inverse transpose of its 3x3 part
+///
+/// Normals transformed with it stay perpendicular to surfaces under
+/// non-uniform scale; they need to be normalized afterwards. Returns `None`
+/// if the matrix is singular.
+///
+/// # Example
+///
+/// ```
+/// use l3d_rs::{mat4_normal_matrix, mat4_rotate_z};
+///
+/// // For a pure rotation the normal matrix is the rotation itself
+/// let n = mat4_normal_matrix(&mat4_rotate_z(90.0)).unwrap();
+/// assert!((n[1] - 1.0).abs() < 1e-6);
+/// ```
+pub fn mat4_normal_matrix(m: &Mat4) -> Option<Mat3> {
+    // The inverse transpose equals the cofactor matrix divided by the
+    // determinant
+    let a = |col: usize, row: usize| f64::from(m[col * 4 + row]);
+    let cofactor = |col: usize, row: usize| {
+        let (c0, c1) = ((col + 1) % 3, (col + 2) % 3);
+        let (r0, r1) = ((row + 1) % 3, (row + 2) % 3);
+        a(c0, r0) * a(c1, r1) - a(c1, r0) * a(c0, r1)
+    };
+    let det = a(0, 0) * cofactor(0, 0) + a(0, 1) * cofactor(0, 1) + a(0, 2) * cofactor(0, 2);
+    if det.abs() <= f64::EPSILON * linear_det_bound(m) {
+        return None;
+    }
+    let mut result = [0.0f32; 9];
+    for col in 0..3 {
+        for row in 0..3 {
+            result[col * 3 + row] = (cofactor(col, row) / det) as f32;
+        }
+    }
+    Some(result)
+}
+
+/// Split an affine transform into translation, rotation and scale
+///
+/// Assumes the matrix was built from translation, rotation and (possibly
+/// non-uniform) scale without shear, as all L3D transforms are. Returns
+/// `None` if an axis is scaled to zero.
+///
+/// # Example
+///
+/// ```
+/// use l3d_rs::{mat4_decompose, mat4_mul, mat4_scale, mat4_translation};
+///
+/// let m = mat4_mul(&mat4_translation(1.0, 2.0, 3.0), &mat4_scale(0.001));
+/// let d = mat4_decompose(&m).unwrap();
+/// assert_eq!(d.translation, [1.0, 2.0, 3.0]);
+/// assert_eq!(d.rotation, [0.0, 0.0, 0.0, 1.0]);
+/// ```
+pub fn mat4_decompose(m: &Mat4) -> Option<Decomposition> {
+    let column = |c: usize| [m[c * 4], m[c * 4 + 1], m[c * 4 + 2]];
+    let length = |v: [f32; 3]| (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    let mut scale = [length(column(0)), length(column(1)), length(column(2))];
+    if scale.iter().any(|&s| s <= f32::EPSILON) {
+        return None;
+    }
+    // A mirrored transform cannot be a rotation; move the sign into scale
+    if mat4_determinant(m) < 0.0 {
+        scale[0] = -scale[0];
+    }
+
+    let mut r = [0.0f32; 9];
+    for c in 0..3 {
+        let col = column(c);
+        for row in 0..3 {
+            r[c * 3 + row] = col[row] / scale[c];
+        }
+    }
+    Some(Decomposition {
+        translation: [m[12], m[13], m[14]],
+        rotation: mat3_to_quat(&r),
+        scale,
+    })
+}
+
+/// Build a matrix from translation, rotation and scale
+///
+/// Inverse of [`mat4_decompose`].
+pub fn mat4_compose(d: &Decomposition) -> Mat4 {
+    let [tx, ty, tz] = d.translation;
+    let [sx, sy, sz] = d.scale;
+    let scale = [
+        sx, 0.0, 0.0, 0.0, 0.0, sy, 0.0, 0.0, 0.0, 0.0, sz, 0.0, 0.0, 0.0, 0.0, 1.0,
+    ];
+    mat4_mul(
+        &mat4_mul(&mat4_translation(tx, ty, tz), &mat4_from_quat(d.rotation)),
+        &scale,
+    )
+}
+
+/// Create a rotation matrix from a unit quaternion `(x, y, z, w)`
+pub fn mat4_from_quat(q: [f32; 4]) -> Mat4 {
+    dmat4_to_mat4(&dmat4_from_quat(q.map(f64::from)))
+}
+
+/// Unit quaternion `(x, y, z, w)` of a column-major rotation matrix
+fn mat3_to_quat(r: &Mat3) -> [f32; 4] {
+    let m = |row: usize, col: usize| r[col * 3 + row];
+    let trace = m(0, 0) + m(1, 1) + m(2, 2);
+    // Use the largest diagonal term for numerical stability
+    let q = if trace > 0.0 {
+        let s = (trace + 1.0).sqrt() * 2.0;
+        [
+            (m(2, 1) - m(1, 2)) / s,
+            (m(0, 2) - m(2, 0)) / s,
+            (m(1, 0) - m(0, 1)) / s,
+            s / 4.0,
+        ]
+    } else if m(0, 0) > m(1, 1) && m(0, 0) > m(2, 2) {
+        let s = (1.0 + m(0, 0) - m(1, 1) - m(2, 2)).sqrt() * 2.0;
+        [
+            s / 4.0,
+            (m(0, 1) + m(1, 0)) / s,
+            (m(0, 2) + m(2, 0)) / s,
+            (m(2, 1) - m(1, 2)) / s,
+        ]
+    } else if m(1, 1) > m(2, 2) {
+        let s = (1.0 + m(1, 1) - m(0, 0) - m(2, 2)).sqrt() * 2.0;
+        [
+            (m(0, 1) + m(1, 0)) / s,
+            s / 4.0,
+            (m(1, 2) + m(2, 1)) / s,
+            (m(0, 2) - m(2, 0)) / s,
+        ]
+    } else {
+        let s = (1.0 + m(2, 2) - m(0, 0) - m(1, 1)).sqrt() * 2.0;
+        [
+            (m(0, 2) + m(2, 0)) / s,
+            (m(1, 2) + m(2, 1)) / s,
+            s / 4.0,
+            (m(1, 0) - m(0, 1)) / s,
+        ]
+    };
+    // Keep w non-negative so equal rotations give equal quaternions
+    if q[3] < 0.0 {
+        q.map(|c| -c)
+    } else {
+        q
+    }
+}
+
+/// Adjugate (transposed cofactor matrix) and determinant, computed in f64
+fn cofactors(m: &Mat4) -> ([f64; 16], f64) {
+    let a = m.map(f64::from);
+    let mut inv = [0.0f64; 16];
+
+    inv[0] = a[5] * a[10] * a[15] - a[5] * a[11] * a[14] - a[9] * a[6] * a[15]
+        + a[9] * a[7] * a[14]
+        + a[13] * a[6] * a[11]
+        - a[13] * a[7] * a[10];
+    inv[4] = -a[4] * a[10] * a[15] + a[4] * a[11] * a[14] + a[8] * a[6] * a[15]
+        - a[8] * a[7] * a[14]
+        - a[12] * a[6] * a[11]
+        + a[12] * a[7] * a[10];
+    inv[8] = a[4] * a[9] * a[15] - a[4] * a[11] * a[13] - a[8] * a[5] * a[15]
+        + a[8] * a[7] * a[13]
+        + a[12] * a[5] * a[11]
+        - a[12] * a[7] * a[9];
+    inv[12] = -a[4] * a[9] * a[14] + a[4] * a[10] * a[13] + a[8] * a[5] * a[14]
+        - a[8] * a[6] * a[13]
+        - a[12] * a[5] * a[10]
+        + a[12] * a[6] * a[9];
+    inv[1] = -a[1] * a[10] * a[15] + a[1] * a[11] * a[14] + a[9] * a[2] * a[15]
+        - a[9] * a[3] * a[14]
+        - a[13] * a[2] * a[11]
+        + a[13] * a[3] * a[10];
+    inv[5] = a[0] * a[10] * a[15] - a[0] * a[11] * a[14] - a[8] * a[2] * a[15]
+        + a[8] * a[3] * a[14]
+        + a[12] * a[2] * a[11]
+        - a[12] * a[3] * a[10];
+    inv[9] = -a[0] * a[9] * a[15] + a[0] * a[11] * a[13] + a[8] * a[1] * a[15]
+        - a[8] * a[3] * a[13]
+        - a[12] * a[1] * a[11]
+        + a[12] * a[3] * a[9];
+    inv[13] = a[0] * a[9] * a[14] - a[0] * a[10] * a[13] - a[8] * a[1] * a[14]
+        + a[8] * a[2] * a[13]
+        + a[12] * a[1] * a[10]
+        - a[12] * a[2] * a[9];
+    inv[2] = a[1] * a[6] * a[15] - a[1] * a[7] * a[14] - a[5] * a[2] * a[15]
+        + a[5] * a[3] * a[14]
+        + a[13] * a[2] * a[7]
+        - a[13] * a[3] * a[6];
+    inv[6] = -a[0] * a[6] * a[15] + a[0] * a[7] * a[14] + a[4] * a[2] * a[15]
+        - a[4] * a[3] * a[14]
+        - a[12] * a[2] * a[7]
+        + a[12] * a[3] * a[6];
+    inv[10] = a[0] * a[5] * a[15] - a[0] * a[7] * a[13] - a[4] * a[1] * a[15]
+        + a[4] * a[3] * a[13]
+        + a[12] * a[1] * a[7]
+        - a[12] * a[3] * a[5];
+    inv[14] = -a[0] * a[5] * a[14] + a[0] * a[6] * a[13] + a[4] * a[1] * a[14]
+        - a[4] * a[2] * a[13]
+        - a[12] * a[1] * a[6]
+        + a[12] * a[2] * a[5];
+    inv[3] = -a[1] * a[6] * a[11] + a[1] * a[7] * a[10] + a[5] * a[2] * a[11]
+        - a[5] * a[3] * a[10]
+        - a[9] * a[2] * a[7]
+        + a[9] * a[3] * a[6];
+    inv[7] = a[0] * a[6] * a[11] - a[0] * a[7] * a[10] - a[4] * a[2] * a[11]
+        + a[4] * a[3] * a[10]
+        + a[8] * a[2] * a[7]
+        - a[8] * a[3] * a[6];
+    inv[11] = -a[0] * a[5] * a[11] + a[0] * a[7] * a[9] + a[4] * a[1] * a[11]
+        - a[4] * a[3] * a[9]
+        - a[8] * a[1] * a[7]
+        + a[8] * a[3] * a[5];
+    inv[15] = a[0] * a[5] * a[10] - a[0] * a[6] * a[9] - a[4] * a[1] * a[10]
+        + a[4] * a[2] * a[9]
+        + a[8] * a[1] * a[6]
+        - a[8] * a[2] * a[5];
+
+    let det = a[0] * inv[0] + a[1] * inv[4] + a[2] * inv[8] + a[3] * inv[12];
+    (inv, det)
+}
+
+/// Norm of the first `rows` entries of a column
+fn column_norm(m: &Mat4, col: usize, rows: usize) -> f64 {
+    m[col * 4..col * 4 + rows]
+        .iter()
+        .map(|&v| f64::from(v).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Upper bound of the 3x3 determinant (Hadamard's inequality), used to
+/// scale singularity thresholds independently of the translation
+fn linear_det_bound(m: &Mat4) -> f64 {
+    (0..3).map(|col| column_norm(m, col, 3)).product()
+}
+
+/// Upper bound of the determinant, used to scale singularity thresholds
+///
+/// The translation of an affine matrix does not change its determinant,
+/// so only the 3x3 part counts.
+fn det_bound(m: &Mat4) -> f64 {
+    if m[3] == 0.0 && m[7] == 0.0 && m[11] == 0.0 {
+        linear_det_bound(m) * f64::from(m[15]).abs()
+    } else {
+        (0..4).map(|col| column_norm(m, col, 4)).product()
+    }
+}
+
 /// Create a translation matrix
 ///
 /// # Arguments
@@ -414,6 +905,13 @@ pub fn mat4_rotate_z(deg: f32) -> Mat4 {
 /// assert_eq!(get_scale("m"), 1.0);
 /// ```
 pub fn get_scale(unit: &str) -> f32 {
+    get_scale_f64(unit) as f32
+}
+
+/// Convert a unit string to a scale factor in double precision
+///
+/// See [`get_scale`].
+pub fn get_scale_f64(unit: &str) -> f64 {
     match unit {
         "mm" => 0.001,
         "in" => 2.54 / 100.0,
@@ -423,14 +921,9 @@ pub fn get_scale(unit: &str) -> f32 {
 
 /// Build a transformation matrix from position and rotation vectors
 ///
-/// Creates a combined transformation by:
-/// 1. Translating to position (x, y, z)
-/// 2. Rotating around X axis
-/// 3. Rotating around Y axis
-/// 4. Rotating around Z axis
-///
-/// This matches the L3D specification for how position and rotation
-/// are combined.
+/// Creates the combined transformation `T * Rx * Ry * Rz` of the default
+/// [`RotationOrder::Xyz`]: a vertex is rotated about Z, then Y, then X (all
+/// about the parent axes) and translated last.
 ///
 /// # Arguments
 ///
@@ -440,15 +933,98 @@ pub fn get_scale(unit: &str) -> f32 {
 /// # Example
 ///
 /// ```
-/// use l3d_rs::{build_transform, Vec3f};
+/// use l3d_rs::{build_transform, mat4_transform_point, Vec3f};
 ///
-/// // Note: In real code, Vec3f comes from parsing
-/// // This is just for illustration
+/// let pos = Vec3f { x: 0.0, y: 0.0, z: 0.0 };
+/// let rot = Vec3f { x: 90.0, y: 0.0, z: 90.0 };
+/// let p = mat4_transform_point(&build_transform(&pos, &rot), [1.0, 0.0, 0.0]);
+/// // Z turns +X into +Y, then X turns +Y into +Z
+/// assert!(p[0].abs() < 1e-6 && p[1].abs() < 1e-6 && (p[2] - 1.0).abs() < 1e-6);
 /// ```
 pub fn build_transform(pos: &Vec3f, rot: &Vec3f) -> Mat4 {
-    let m1 = mat4_translation(pos.x, pos.y, pos.z);
-    let m2 = mat4_rotate_x(rot.x);
-    let m3 = mat4_rotate_y(rot.y);
-    let m4 = mat4_rotate_z(rot.z);
-    mat4_mul(&mat4_mul(&mat4_mul(&m1, &m2), &m3), &m4)
+    dmat4_to_mat4(&build_transform_f64(pos, rot))
+}
+
+/// Build a transformation matrix from position and rotation in double
+/// precision
+///
+/// Same as [`build_transform`] without rounding to `f32`.
+pub fn build_transform_f64(pos: &Vec3f, rot: &Vec3f) -> DMat4 {
+    RotationOrder::Xyz.transform(pos, rot)
+}
+
+// ============================================================================
+// Double-Precision Matrix Operations
+// ============================================================================
+
+/// Identity matrix in double precision
+pub const DMAT4_IDENTITY: DMat4 = [
+    1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+];
+
+/// Multiply two double-precision matrices (`a * b`)
+pub fn dmat4_mul(a: &DMat4, b: &DMat4) -> DMat4 {
+    let mut result = [0.0f64; 16];
+    for col in 0..4 {
+        for row in 0..4 {
+            let mut sum = 0.0;
+            for k in 0..4 {
+                sum += a[k * 4 + row] * b[col * 4 + k];
+            }
+            result[col * 4 + row] = sum;
+        }
+    }
+    result
+}
+
+/// Transform a point by a double-precision matrix
+pub fn dmat4_transform_point(m: &DMat4, p: [f64; 3]) -> [f64; 3] {
+    [
+        m[0] * p[0] + m[4] * p[1] + m[8] * p[2] + m[12],
+        m[1] * p[0] + m[5] * p[1] + m[9] * p[2] + m[13],
+        m[2] * p[0] + m[6] * p[1] + m[10] * p[2] + m[14],
+    ]
+}
+
+/// Create a double-precision translation matrix
+pub fn dmat4_translation(x: f64, y: f64, z: f64) -> DMat4 {
+    [
+        1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, x, y, z, 1.0,
+    ]
+}
+
+/// Create a double-precision uniform scale matrix
+pub fn dmat4_scale(s: f64) -> DMat4 {
+    [
+        s, 0.0, 0.0, 0.0, 0.0, s, 0.0, 0.0, 0.0, 0.0, s, 0.0, 0.0, 0.0, 0.0, 1.0,
+    ]
+}
+
+/// Create a double-precision rotation matrix around the X axis (degrees)
+pub fn dmat4_rotate_x(deg: f64) -> DMat4 {
+    let (s, c) = deg.to_radians().sin_cos();
+    [
+        1.0, 0.0, 0.0, 0.0, 0.0, c, s, 0.0, 0.0, -s, c, 0.0, 0.0, 0.0, 0.0, 1.0,
+    ]
+}
+
+/// Create a double-precision rotation matrix around the Y axis (degrees)
+pub fn dmat4_rotate_y(deg: f64) -> DMat4 {
+    let (s, c) = deg.to_radians().sin_cos();
+    [
+        c, 0.0, -s, 0.0, 0.0, 1.0, 0.0, 0.0, s, 0.0, c, 0.0, 0.0, 0.0, 0.0, 1.0,
+    ]
+}
+
+/// Create a double-precision rotation matrix around the Z axis (degrees)
+pub fn dmat4_rotate_z(deg: f64) -> DMat4 {
+    let (s, c) = deg.to_radians().sin_cos();
+    [
+        c, s, 0.0, 0.0, -s, c, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+    ]
+}
+
+/// Round a double-precision matrix to `f32`
+pub fn dmat4_to_mat4(m: &DMat4) -> Mat4 {
+    m.map(|v| v as f32)
 }
@@ -0,0 +1,222 @@
+//! Euler rotation conventions
+//!
+//! `structure.xml` stores every rotation as three angles in degrees, but
+//! neither the file nor this crate's sources pin down the order in which
+//! they apply. Model building uses the default [`RotationOrder::Xyz`], the
+//! order this crate has always used; the other orders exist to read files
+//! or exchange angles with tools that combine them differently.
+//!
+//! # Example
+//!
+//! ```
+//! use l3d_rs::{quat_to_axis_angle, RotationOrder, Vec3f};
+//!
+//! let rot = Vec3f { x: 0.0, y: 0.0, z: 90.0 };
+//! let (axis, angle) = quat_to_axis_angle(RotationOrder::Xyz.quat(&rot));
+//! assert!((axis[2] - 1.0).abs() < 1e-12 && (angle - 90.0).abs() < 1e-12);
+//! ```
+
+use super::{dmat4_mul, dmat4_rotate_x, dmat4_rotate_y, dmat4_rotate_z, dmat4_translation};
+use super::{DMat4, Vec3f, DMAT4_IDENTITY};
+use serde::{Deserialize, Serialize};
+
+/// Order in which the three Euler angles of a rotation are applied
+///
+/// Variants name the intrinsic order: [`RotationOrder::Xyz`] rotates about
+/// X, then about the rotated Y, then about the twice-rotated Z, giving the
+/// matrix `Rx * Ry * Rz`. Read right to left, this is the extrinsic order
+/// Z, Y, X about the fixed parent axes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RotationOrder {
+    /// `Rx * Ry * Rz`, used by model building unless
+    /// [`BuildOptions::rotation_order`](super::BuildOptions::rotation_order)
+    /// says otherwise
+    ///
+    /// Position and rotation combine as `T * Rx * Ry * Rz`: a vertex is
+    /// rotated about the parent's Z axis first, then Y, then X, and
+    /// translated last. This is the crate's historical choice, not a
+    /// convention taken from the L3D specification, and it has not been
+    /// checked against files from an independent L3D implementation.
+    #[default]
+    Xyz,
+    /// `Rx * Rz * Ry`
+    Xzy,
+    /// `Ry * Rx * Rz`
+    Yxz,
+    /// `Ry * Rz * Rx`
+    Yzx,
+    /// `Rz * Rx * Ry`
+    Zxy,
+    /// `Rz * Ry * Rx` (yaw, pitch, roll)
+    Zyx,
+}
+
+impl RotationOrder {
+    /// Axis indices (0 = X, 1 = Y, 2 = Z) in multiplication order
+    pub fn axes(self) -> [usize; 3] {
+        match self {
+            RotationOrder::Xyz => [0, 1, 2],
+            RotationOrder::Xzy => [0, 2, 1],
+            RotationOrder::Yxz => [1, 0, 2],
+            RotationOrder::Yzx => [1, 2, 0],
+            RotationOrder::Zxy => [2, 0, 1],
+            RotationOrder::Zyx => [2, 1, 0],
+        }
+    }
+
+    /// Rotation matrix of Euler angles in degrees
+    pub fn matrix(self, rot: &Vec3f) -> DMat4 {
+        let angles = rot.to_f64();
+        self.axes()
+            .iter()
+            .map(|&axis| match axis {
+                0 => dmat4_rotate_x(angles[0]),
+                1 => dmat4_rotate_y(angles[1]),
+                _ => dmat4_rotate_z(angles[2]),
+            })
+            .fold(DMAT4_IDENTITY, |a, b| dmat4_mul(&a, &b))
+    }
+
+    /// Transform of a `Position` and `Rotation` pair: translation times
+    /// rotation
+    pub fn transform(self, pos: &Vec3f, rot: &Vec3f) -> DMat4 {
+        let [x, y, z] = pos.to_f64();
+        dmat4_mul(&dmat4_translation(x, y, z), &self.matrix(rot))
+    }
+
+    /// Euler angles in degrees that reproduce the rotation part of `m`
+    ///
+    /// The middle angle is kept within ±90°. At ±90° (gimbal lock) the
+    /// last angle is set to zero. Scale in `m` must be removed first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use l3d_rs::{RotationOrder, Vec3f};
+    ///
+    /// let rot = Vec3f { x: 30.0, y: -20.0, z: 75.0 };
+    /// let back = RotationOrder::Xyz.euler_from_matrix(&RotationOrder::Xyz.matrix(&rot));
+    /// assert!((back.x - 30.0).abs() < 1e-9);
+    /// assert!((back.y + 20.0).abs() < 1e-9);
+    /// assert!((back.z - 75.0).abs() < 1e-9);
+    /// ```
+    pub fn euler_from_matrix(self, m: &DMat4) -> Vec3f {
+        let [i, j, k] = self.axes();
+        let r = |row: usize, col: usize| m[col * 4 + row];
+        // Cyclic orders (XYZ, YZX, ZXY) and their reverses differ in sign
+        let s = if (j + 3 - i) % 3 == 1 { 1.0 } else { -1.0 };
+
+        let sin_b = (s * r(i, k)).clamp(-1.0, 1.0);
+        let b = sin_b.asin();
+        let (a, c) = if sin_b.abs() < 1.0 - 1e-12 {
+            ((-s * r(j, k)).atan2(r(k, k)), (-s * r(i, j)).atan2(r(i, i)))
+        } else {
+            ((s * r(k, j)).atan2(r(j, j)), 0.0)
+        };
+
+        let mut angles = [0.0; 3];
+        angles[i] = a.to_degrees();
+        angles[j] = b.to_degrees();
+        angles[k] = c.to_degrees();
+        Vec3f {
+            x: angles[0] as f32,
+            y: angles[1] as f32,
+            z: angles[2] as f32,
+        }
+    }
+
+    /// Unit quaternion `(x, y, z, w)` of Euler angles in degrees
+    ///
+    /// The sign is chosen so that `w` is non-negative.
+    pub fn quat(self, rot: &Vec3f) -> [f64; 4] {
+        let angles = rot.to_f64();
+        let q = self
+            .axes()
+            .iter()
+            .map(|&axis| {
+                let mut unit = [0.0; 3];
+                unit[axis] = 1.0;
+                quat_from_axis_angle(unit, angles[axis])
+            })
+            .fold([0.0, 0.0, 0.0, 1.0], quat_mul);
+        if q[3] < 0.0 {
+            q.map(|c| -c)
+        } else {
+            q
+        }
+    }
+
+    /// Euler angles in degrees of a unit quaternion `(x, y, z, w)`
+    pub fn euler_from_quat(self, q: [f64; 4]) -> Vec3f {
+        self.euler_from_matrix(&dmat4_from_quat(q))
+    }
+
+    /// Rotation axis and angle in degrees of Euler angles in degrees
+    ///
+    /// See [`quat_to_axis_angle`].
+    pub fn axis_angle(self, rot: &Vec3f) -> ([f64; 3], f64) {
+        quat_to_axis_angle(self.quat(rot))
+    }
+}
+
+/// Unit quaternion `(x, y, z, w)` rotating by `deg` degrees about `axis`
+///
+/// The axis does not need to be normalized. A zero axis gives the
+/// identity.
+pub fn quat_from_axis_angle(axis: [f64; 3], deg: f64) -> [f64; 4] {
+    let len = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+    if len == 0.0 {
+        return [0.0, 0.0, 0.0, 1.0];
+    }
+    let (s, c) = (deg.to_radians() / 2.0).sin_cos();
+    [axis[0] / len * s, axis[1] / len * s, axis[2] / len * s, c]
+}
+
+/// Rotation axis and angle in degrees of a unit quaternion `(x, y, z, w)`
+///
+/// The angle is within `0..=180`; the axis is flipped instead of returning
+/// larger angles. The identity returns the X axis with angle zero.
+pub fn quat_to_axis_angle(q: [f64; 4]) -> ([f64; 3], f64) {
+    let [x, y, z, w] = if q[3] < 0.0 { q.map(|c| -c) } else { q };
+    let s = (x * x + y * y + z * z).sqrt();
+    if s < 1e-12 {
+        return ([1.0, 0.0, 0.0], 0.0);
+    }
+    let angle = 2.0 * s.atan2(w);
+    ([x / s, y / s, z / s], angle.to_degrees())
+}
+
+/// Create a double-precision rotation matrix from a unit quaternion
+/// `(x, y, z, w)`
+pub fn dmat4_from_quat([x, y, z, w]: [f64; 4]) -> DMat4 {
+    [
+        1.0 - 2.0 * (y * y + z * z),
+        2.0 * (x * y + z * w),
+        2.0 * (x * z - y * w),
+        0.0,
+        2.0 * (x * y - z * w),
+        1.0 - 2.0 * (x * x + z * z),
+        2.0 * (y * z + x * w),
+        0.0,
+        2.0 * (x * z + y * w),
+        2.0 * (y * z - x * w),
+        1.0 - 2.0 * (x * x + y * y),
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        1.0,
+    ]
+}
+
+/// Hamilton product `a * b` (apply `b`, then `a`)
+fn quat_mul(a: [f64; 4], b: [f64; 4]) -> [f64; 4] {
+    let [ax, ay, az, aw] = a;
+    let [bx, by, bz, bw] = b;
+    [
+        aw * bx + ax * bw + ay * bz - az * by,
+        aw * by - ax * bz + ay * bw + az * bx,
+        aw * bz + ax * by - ay * bx + az * bw,
+        aw * bw - ax * bx - ay * by - az * bz,
+    ]
+}
@@ -6,17 +6,28 @@ pub struct Structure {
     pub geometry: Geometry,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+/// Range of an adjustable joint axis
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Axis {
+    /// Minimum joint angle in degrees
     #[serde(rename = "@min")]
-    min: f64,
+    pub min: f64,
+    /// Maximum joint angle in degrees
     #[serde(rename = "@max")]
-    max: f64,
+    pub max: f64,
+    /// Step size between allowed angles in degrees
     #[serde(rename = "@step")]
-    step: f64,
+    pub step: f64,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+impl Axis {
+    /// Limit an angle in degrees to `min..=max`
+    pub fn clamp(&self, angle: f64) -> f64 {
+        angle.max(self.min).min(self.max)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Vec3f {
     #[serde(rename = "@x")]
     pub x: f32,
@@ -26,7 +37,14 @@ pub struct Vec3f {
     pub z: f32,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+impl Vec3f {
+    /// Components widened to `f64`
+    pub(crate) fn to_f64(&self) -> [f64; 3] {
+        [self.x, self.y, self.z].map(f64::from)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Rectangle {
     /// Width of the rectangle
     #[serde(rename = "@sizeX")]
@@ -43,7 +61,7 @@ impl Rectangle {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Circle {
     /// Diameter of the circle
     #[serde(rename = "@diameter")]
@@ -70,9 +88,30 @@ pub struct Joint {
     pub part_name: String,
     pub position: Vec3f,
     pub rotation: Vec3f,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub x_axis: Option<Axis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub y_axis: Option<Axis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub z_axis: Option<Axis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub default_rotation: Option<Vec3f>,
     pub geometries: Geometries,
 }
+
+impl Joint {
+    /// Limit joint angles in degrees to the axis ranges
+    ///
+    /// Components for axes without a range become zero.
+    pub fn clamp_angles(&self, angles: &Vec3f) -> Vec3f {
+        let clamp = |axis: &Option<Axis>, angle: f32| {
+            axis.as_ref()
+                .map_or(0.0, |a| a.clamp(f64::from(angle)) as f32)
+        };
+        Vec3f {
+            x: clamp(&self.x_axis, angles.x),
+            y: clamp(&self.y_axis, angles.y),
+            z: clamp(&self.z_axis, angles.z),
+        }
+    }
+}
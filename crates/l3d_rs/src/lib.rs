@@ -76,40 +76,103 @@
 //!
 //! - [`l3d`] - Core types for L3D structure (Luminaire, Geometry, etc.)
 //! - [`from_buffer`] - Main entry point for parsing L3D files
+//! - [`load`](mod@load) - Loading with progress reports, cancellation and logging
+//! - [`obj`] - Minimal OBJ reader for the geometry files inside the archive
+//! - [`validation`] - Diagnostics for broken or suspicious files ([`validate`])
+//! - [`batch`] - Validate and summarize directories of L3D files
+//! - [`xml_compare`] - Structural comparison of XML documents
+//! - [`diff`](mod@diff) - Semantic changes between two versions of a luminaire
+//! - [`edit`] - Find, modify and rename parts and replace assets
+//! - [`hash`] - Stable content hashes and asset deduplication
+//! - [`stats`] - Per-part mesh statistics and quality checks
+//! - [`pick`] - Ray casting to find the part under the cursor
+//! - [`render`] - Interleaved vertex and index buffers for GPU upload
+//! - [`gltf`] - Binary glTF export of the assembled luminaire
+//! - [`simplify`] - Mesh decimation and level-of-detail generation
+//! - [`repair`] - Vertex welding, winding fixes and smooth normals
 //! - Matrix utilities - [`mat4_mul`], [`mat4_translation`], [`mat4_scale`], etc.
 
+pub mod batch;
+pub mod diff;
+pub mod edit;
+pub mod gltf;
+pub mod hash;
 pub mod l3d;
+pub mod load;
+pub mod obj;
+pub mod pick;
+pub mod render;
+pub mod repair;
+pub mod simplify;
+pub mod stats;
 #[cfg(test)]
 mod tests;
+pub mod validation;
+mod vecmath;
+pub mod xml_compare;
 
 use anyhow::{Context, Result};
 use quick_xml::de::from_str as from_xml_str;
 use regex::Regex;
 use serde_json::{from_str as from_json_str, to_string_pretty as to_json_str};
 use std::{
+    borrow::Cow,
     fs::File as StdFile,
-    io::Read,
+    io::{Read, Write},
     path::{Path, PathBuf},
+    sync::OnceLock,
 };
 use zip::ZipArchive;
 
 // Re-export all public types for easy access
+pub use diff::{diff, Change, L3dDiff};
+pub use hash::{AssetIndex, ContentHash};
 pub use l3d::{
     build_transform,
+    build_transform_f64,
+    // Double-precision matrix utilities
+    dmat4_from_quat,
+    dmat4_mul,
+    dmat4_rotate_x,
+    dmat4_rotate_y,
+    dmat4_rotate_z,
+    dmat4_scale,
+    dmat4_to_mat4,
+    dmat4_transform_point,
+    dmat4_translation,
     get_scale,
+    get_scale_f64,
     // Matrix utilities
+    mat4_compose,
+    mat4_decompose,
+    mat4_determinant,
+    mat4_from_quat,
+    mat4_inverse,
     mat4_mul,
+    mat4_normal_matrix,
     mat4_rotate_x,
     mat4_rotate_y,
     mat4_rotate_z,
     mat4_scale,
+    mat4_transform_direction,
+    mat4_transform_point,
     mat4_translation,
+    mat4_transpose,
+    quat_from_axis_angle,
+    quat_to_axis_angle,
+    Axis,
     BufFile,
+    BuildOptions,
     Circle,
+    CoordinateSystem,
+    DMat4,
+    Decomposition,
+    EmitterShape,
     Geometries,
     Geometry,
     GeometryDefinitions,
     GeometryFileDefinition,
+    GeometryVisit,
     Header,
     Joint,
     Joints,
@@ -119,43 +182,115 @@ pub use l3d::{
     L3dModel,
     L3dPart,
     // Light emitting objects
+    LightEmitter,
     LightEmittingObject,
     LightEmittingObjects,
     // XML structure types
     Luminaire,
+    Mat3,
     Mat4,
     Rectangle,
+    ResolvedPart,
+    RotationOrder,
     Structure,
     Vec3f,
+    DMAT4_IDENTITY,
     MAT4_IDENTITY,
 };
+pub use obj::{ObjFace, ObjMesh, ObjVertex};
+pub use pick::{PickHit, PickScene, Ray};
+pub use render::{RenderBuffer, RenderOptions, TransformSpace, VERTEX_STRIDE};
+pub use stats::{MeshStats, ModelStats, PartStats};
+pub use validation::{validate, Diagnostic, Severity};
+pub use xml_compare::{XmlCompareOptions, XmlDifference, XmlDifferenceKind};
+
+/// Compile a regex pattern once and reuse it for every subsequent call
+macro_rules! cached_regex {
+    ($pattern:expr) => {{
+        static RE: OnceLock<Regex> = OnceLock::new();
+        RE.get_or_init(|| Regex::new($pattern).unwrap())
+    }};
+}
 
 /// Normalize XML by removing or collapsing excess whitespace and trimming newlines
 pub fn normalize_whitespace(xml: &str) -> String {
     // Remove all excess spaces around self-closing tags
-    let re_self_closing = Regex::new(r"(\s+)/>").unwrap();
-    let xml = re_self_closing.replace_all(xml, "/>").to_string();
+    let xml = cached_regex!(r"(\s+)/>").replace_all(xml, "/>");
 
     // Collapse multiple spaces into a single space between tags
-    let re_collapse_spaces = Regex::new(r">\s+<").unwrap();
-    let xml = re_collapse_spaces.replace_all(&xml, "><").to_string();
+    let xml = cached_regex!(r">\s+<").replace_all(&xml, "><");
 
     // Trim leading/trailing spaces and newlines from the entire XML
-    let xml = xml.trim();
-
-    xml.to_string()
+    xml.trim().to_string()
 }
 
 /// Trait for synchronous logging (useful for debugging in native environments)
+///
+/// Receives the progress messages of [`load::load`] through
+/// [`LoadOptions::logger`](load::LoadOptions::logger), and the problems
+/// found while parsing through [`warn`](Logger::warn) and
+/// [`error`](Logger::error) when passed to [`from_buffer_with_logger`] or
+/// [`load::load`].
+///
+/// With the `log` feature, warnings and errors are also emitted through the
+/// [`log`](https://docs.rs/log) facade under the `l3d_rs` target.
 pub trait Logger {
     fn log(&self, message: &str);
+
+    /// A recoverable problem, e.g. an unknown geometry ID or unit
+    ///
+    /// Defaults to [`log`](Logger::log) with a `warning: ` prefix.
+    fn warn(&self, message: &str) {
+        self.log(&format!("warning: {}", message));
+    }
+
+    /// A failure that makes parsing return an empty result
+    ///
+    /// Defaults to [`log`](Logger::log) with an `error: ` prefix.
+    fn error(&self, message: &str) {
+        self.log(&format!("error: {}", message));
+    }
 }
 
 /// Trait for asynchronous logging (useful for WASM environments)
+///
+/// [`load::load_async`] awaits it after every loading step.
 pub trait AsyncLogger {
     fn log(&self, message: &str) -> impl std::future::Future<Output = ()> + Send;
 }
 
+/// Where parsing reports its problems: an optional [`Logger`] and, with
+/// the `log` feature, the `log` facade
+#[derive(Clone, Copy, Default)]
+pub(crate) struct Log<'a>(pub(crate) Option<&'a dyn Logger>);
+
+impl Log<'_> {
+    /// Routine information, e.g. a skipped directory entry
+    pub(crate) fn info(&self, message: &str) {
+        #[cfg(feature = "log")]
+        log::debug!(target: "l3d_rs", "{}", message);
+        if let Some(logger) = self.0 {
+            logger.log(message);
+        }
+    }
+
+    pub(crate) fn warn(&self, message: &str) {
+        #[cfg(feature = "log")]
+        log::warn!(target: "l3d_rs", "{}", message);
+        if let Some(logger) = self.0 {
+            logger.warn(message);
+        }
+    }
+
+    pub(crate) fn error(&self, message: &str) {
+        #[cfg(feature = "log")]
+        log::error!(target: "l3d_rs", "{}", message);
+        if let Some(logger) = self.0 {
+            logger.error(message);
+        }
+    }
+}
+
 /// Implementation of Luminaire parsing and serialization methods
 impl Luminaire {
     /// Detach the luminaire from any parent context (currently a no-op)
@@ -179,10 +314,7 @@ impl Luminaire {
     ///
     /// This allows parsing L3D files regardless of their XSD version.
     pub fn sanitize_xml_str(xml_str: &str) -> String {
-        let cleaned_str = Self::remove_bom(xml_str);
-        let cleaned_str = cleaned_str.replace("\r\n", "\n");
-        let re = Regex::new(r"<Luminaire .*?>").unwrap();
-        re.replace_all(&cleaned_str, "<Luminaire>").to_string()
+        sanitize_xml(xml_str).into_owned()
     }
 
     /// Deserialize a Luminaire struct from an XML string
@@ -196,7 +328,14 @@ impl Luminaire {
     /// let luminaire = Luminaire::from_xml(xml).unwrap();
     /// ```
     pub fn from_xml(xml_str: &str) -> Result<Luminaire> {
-        let my_xml_str = Self::sanitize_xml_str(xml_str);
+        Self::from_xml_logged(xml_str, Log::default())
+    }
+
+    pub(crate) fn from_xml_logged(xml_str: &str, log: Log) -> Result<Luminaire> {
+        if xml_str.starts_with('\u{FEFF}') {
+            log.warn("removed UTF-8 byte order mark from structure.xml");
+        }
+        let my_xml_str = sanitize_xml(xml_str);
         let loaded: Luminaire = from_xml_str(&my_xml_str)
             .map_err(anyhow::Error::msg)
             .context("Failed to parse XML string")?;
@@ -245,6 +384,9 @@ impl Luminaire {
     /// - Debugging (human-readable)
     /// - Storage in JSON-based databases
     ///
+    /// Optional elements that are not set are omitted rather than written
+    /// as `null`.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -299,28 +441,29 @@ impl Luminaire {
         Ok(loaded)
     }
 
-    /// Compare two XML strings for equality after normalization
+    /// Compare two XML strings structurally
     ///
-    /// Useful for testing round-trip serialization.
-    pub fn compare_xml(raw_xml: &str, generated_xml: &str) -> Result<(), String> {
-        let raw_xml_clean = remove_xml_declaration(raw_xml);
-        let generated_xml_clean = remove_xml_declaration(generated_xml);
-
-        let raw_xml_sanitized = Luminaire::sanitize_xml_str(&raw_xml_clean);
-        let generated_xml_sanitized = Luminaire::sanitize_xml_str(&generated_xml_clean);
-
-        let _raw_xml_normalized = normalize_whitespace(&raw_xml_sanitized);
-        let generated_xml_normalized = normalize_whitespace(&generated_xml_sanitized);
-        let raw_xml_normalized = remove_specific_empty_elements(&generated_xml_normalized);
-
-        if raw_xml_normalized == generated_xml_normalized {
-            Ok(())
-        } else {
-            Err(format!(
-                "The XML strings do not match!\n\nOriginal:\n{}\n\nGenerated:\n{}",
-                raw_xml_normalized, generated_xml_normalized
-            ))
-        }
+    /// Useful for testing round-trip serialization. Attribute order,
+    /// whitespace, namespace declarations and empty optional elements such
+    /// as `<Name/>` are ignored, and numbers are compared with a small
+    /// tolerance. Returns the list of differences with their element paths;
+    /// an empty list means the documents are equivalent.
+    ///
+    /// See [`xml_compare::compare_xml`] to customize these settings.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use l3d_rs::Luminaire;
+    ///
+    /// let raw = Luminaire::get_xml_str_from_l3d("luminaire.l3d".into()).unwrap();
+    /// let generated = Luminaire::from_xml(&raw).unwrap().to_xml().unwrap();
+    /// for diff in Luminaire::compare_xml(&raw, &generated).unwrap() {
+    ///     println!("{}", diff);
+    /// }
+    /// ```
+    pub fn compare_xml(raw_xml: &str, generated_xml: &str) -> Result<Vec<XmlDifference>> {
+        xml_compare::compare_xml(raw_xml, generated_xml, &XmlCompareOptions::default())
     }
 }
 
@@ -329,13 +472,68 @@ pub fn remove_xml_declaration(xml: &str) -> String {
     xml.replace(r#"<?xml version="1.0" encoding="utf-8"?>"#, "")
 }
 
-/// Remove specific empty optional elements like `<Name/>`
-fn remove_specific_empty_elements(xml: &str) -> String {
-    // Remove empty <Name/> element
-    let re_name = Regex::new(r"<Name\s*/>").unwrap(); // Matches <Name/>
-    let xml = re_name.replace_all(xml, "").to_string();
+/// Sanitize an XML string without copying it when nothing needs to change
+///
+/// Single pass over the input that strips the BOM, rewrites the `<Luminaire ...>`
+/// root tag to a bare `<Luminaire>` and normalizes CRLF line endings.
+fn sanitize_xml(xml_str: &str) -> Cow<'_, str> {
+    let xml = xml_str.strip_prefix('\u{FEFF}').unwrap_or(xml_str);
+    let root = find_root_attributes(xml);
+    let has_crlf = xml.contains("\r\n");
+
+    match root {
+        None if !has_crlf => Cow::Borrowed(xml),
+        None => Cow::Owned(xml.replace("\r\n", "\n")),
+        Some((start, end)) => {
+            let mut out = String::with_capacity(xml.len());
+            push_without_crlf(&mut out, &xml[..start], has_crlf);
+            out.push_str(if xml[..end].ends_with("/>") {
+                "<Luminaire/>"
+            } else {
+                "<Luminaire>"
+            });
+            push_without_crlf(&mut out, &xml[end..], has_crlf);
+            Cow::Owned(out)
+        }
+    }
+}
 
-    xml
+/// Locate the `<Luminaire ...>` start tag if it carries attributes
+///
+/// Returns the byte range of the whole tag, including the closing `>`.
+fn find_root_attributes(xml: &str) -> Option<(usize, usize)> {
+    const ROOT: &str = "<Luminaire";
+    let mut offset = 0;
+    while let Some(pos) = xml[offset..].find(ROOT) {
+        let start = offset + pos;
+        let after = start + ROOT.len();
+        match xml[after..].chars().next() {
+            Some(c) if c.is_ascii_whitespace() => {
+                let end = after + xml[after..].find('>')? + 1;
+                return Some((start, end));
+            }
+            Some('>') | Some('/') => return None,
+            // Some other element such as `<LuminaireFoo>`, keep searching
+            _ => offset = after,
+        }
+    }
+    None
+}
+
+/// Append `s` to `out`, converting CRLF line endings to LF when requested
+fn push_without_crlf(out: &mut String, s: &str, has_crlf: bool) {
+    if !has_crlf {
+        out.push_str(s);
+        return;
+    }
+    let mut parts = s.split("\r\n");
+    if let Some(first) = parts.next() {
+        out.push_str(first);
+    }
+    for part in parts {
+        out.push('\n');
+        out.push_str(part);
+    }
 }
 
 // ============================================================================
@@ -400,109 +598,392 @@ fn remove_specific_empty_elements(xml: &str) -> String {
 /// }
 /// ```
 pub fn from_buffer(l3d_buf: &[u8]) -> L3d {
-    match get_l3d_file(l3d_buf) {
+    from_buffer_with_options(l3d_buf, &BuildOptions::default())
+}
+
+/// Parse an L3D file from a byte buffer with custom [`BuildOptions`]
+///
+/// Same as [`from_buffer`], but e.g. produces part matrices in a Y-up
+/// coordinate system for glTF or three.js.
+pub fn from_buffer_with_options(l3d_buf: &[u8], options: &BuildOptions) -> L3d {
+    from_buffer_logged(l3d_buf, options, Log::default())
+}
+
+/// Parse an L3D file from a byte buffer, reporting problems to `logger`
+///
+/// Same as [`from_buffer_with_options`], but the reasons for an empty
+/// result go to [`Logger::error`], and recoverable problems (unknown
+/// geometry IDs and units, a byte order mark, skipped archive entries) to
+/// [`Logger::warn`].
+///
+/// # Example
+///
+/// ```no_run
+/// use l3d_rs::{from_buffer_with_logger, BuildOptions, Logger};
+///
+/// struct Stderr;
+///
+/// impl Logger for Stderr {
+///     fn log(&self, message: &str) {
+///         eprintln!("{}", message);
+///     }
+/// }
+///
+/// let bytes = std::fs::read("luminaire.l3d").unwrap();
+/// let l3d = from_buffer_with_logger(&bytes, &BuildOptions::default(), &Stderr);
+/// ```
+pub fn from_buffer_with_logger(l3d_buf: &[u8], options: &BuildOptions, logger: &dyn Logger) -> L3d {
+    from_buffer_logged(l3d_buf, options, Log(Some(logger)))
+}
+
+fn from_buffer_logged(l3d_buf: &[u8], options: &BuildOptions, log: Log) -> L3d {
+    match get_l3d_file(l3d_buf, log) {
         Ok(file) => {
-            let mut l3d = parse_structure(&file.structure);
+            let mut l3d = parse_structure(&file.structure, options, log);
             l3d.file = file;
             l3d
         }
-        Err(_e) => {
+        Err(e) => {
             // Return default on error - caller can check if model is empty
+            log.error(&format!("failed to read L3D archive: {}", e));
             L3d::default()
         }
     }
 }
 
-/// Extract L3D file contents from a ZIP buffer
+/// Parse an L3D file from a byte buffer, reporting failures
 ///
-/// Internal function that reads the ZIP archive and separates:
-/// - `structure.xml` → stored in `L3dFile.structure`
-/// - All other files → stored in `L3dFile.assets`
-fn get_l3d_file(l3d_buf: &[u8]) -> std::io::Result<L3dFile> {
-    let zip_buf = std::io::Cursor::new(l3d_buf);
-    let mut zip = zip::ZipArchive::new(zip_buf)?;
-
-    let mut l3d_file = L3dFile::default();
-    for i in 0..zip.len() {
-        let mut file = zip.by_index(i)?;
-        if file.is_file() {
-            let mut buf: Vec<u8> = Vec::new();
-            file.read_to_end(&mut buf)?;
-            if file.name() == "structure.xml" {
-                l3d_file.structure = String::from_utf8_lossy(&buf).into_owned();
+/// Same as [`from_buffer`], but instead of silently returning an empty
+/// [`L3d`] it returns an error when the archive cannot be read, has no
+/// `structure.xml`, or the structure cannot be parsed.
+///
+/// # Example
+///
+/// ```no_run
+/// use l3d_rs::try_from_buffer;
+///
+/// let bytes = std::fs::read("luminaire.l3d").unwrap();
+/// match try_from_buffer(&bytes) {
+///     Ok(l3d) => println!("Loaded {} parts", l3d.model.parts.len()),
+///     Err(e) => eprintln!("Invalid L3D file: {:#}", e),
+/// }
+/// ```
+pub fn try_from_buffer(l3d_buf: &[u8]) -> Result<L3d> {
+    try_from_buffer_with_options(l3d_buf, &BuildOptions::default())
+}
+
+/// Parse an L3D file from a byte buffer with custom [`BuildOptions`],
+/// reporting failures
+pub fn try_from_buffer_with_options(l3d_buf: &[u8], options: &BuildOptions) -> Result<L3d> {
+    let file = get_l3d_file(l3d_buf, Log::default()).context("Failed to read L3D archive")?;
+    anyhow::ensure!(
+        !file.structure.is_empty(),
+        "L3D archive contains no structure.xml"
+    );
+    let luminaire = Luminaire::from_xml(&file.structure)?;
+    Ok(L3d {
+        model: build_model(&luminaire, options, Log::default()),
+        file,
+    })
+}
+
+impl L3d {
+    /// Parse the luminaire definition from the stored `structure.xml`
+    pub fn luminaire(&self) -> Result<Luminaire> {
+        Luminaire::from_xml(&self.file.structure)
+    }
+
+    /// Find an asset by its path within the archive (e.g. `"geom_1/luminaire.obj"`)
+    pub fn asset(&self, name: &str) -> Option<&BufFile> {
+        self.file.assets.iter().find(|a| a.name == name)
+    }
+
+    /// Axis-aligned bounding box of the assembled luminaire in meters
+    ///
+    /// Every part's OBJ file is parsed and its vertices are transformed by
+    /// the part matrix, so the box is in the model's coordinate system.
+    /// Parts whose geometry is missing or cannot be parsed
+    /// are skipped. Returns `None` if no vertex could be read.
+    pub fn bounds(&self) -> Option<([f32; 3], [f32; 3])> {
+        let mut bounds: Option<([f32; 3], [f32; 3])> = None;
+        for part in &self.model.parts {
+            let Some(mesh) = self
+                .asset(&part.path)
+                .and_then(|a| ObjMesh::from_bytes(&a.content).ok())
+            else {
                 continue;
-            }
-            let buf_file = BufFile {
-                name: file.name().to_string(),
-                content: buf,
-                size: file.size(),
             };
-            l3d_file.assets.push(buf_file);
+            for p in &mesh.positions {
+                let w = mat4_transform_point(&part.mat, *p);
+                let (min, max) = bounds.get_or_insert((w, w));
+                for i in 0..3 {
+                    min[i] = min[i].min(w[i]);
+                    max[i] = max[i].max(w[i]);
+                }
+            }
+        }
+        bounds
+    }
+}
+
+impl L3dFile {
+    /// Write the contents back into an L3D (ZIP) archive
+    ///
+    /// `structure.xml` is stored first, followed by the assets in order.
+    /// All entries are deflate-compressed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use l3d_rs::from_buffer;
+    ///
+    /// let l3d = from_buffer(&std::fs::read("luminaire.l3d").unwrap());
+    /// std::fs::write("copy.l3d", l3d.file.to_bytes().unwrap()).unwrap();
+    /// ```
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+
+        zip.start_file("structure.xml", options)?;
+        zip.write_all(self.structure.as_bytes())?;
+        for asset in &self.assets {
+            zip.start_file(asset.name.as_str(), options)
+                .with_context(|| format!("Failed to add {}", asset.name))?;
+            zip.write_all(&asset.content)?;
         }
+        Ok(zip.finish()?.into_inner())
     }
 
-    Ok(l3d_file)
+    /// Copy the archive, passing every parseable OBJ asset through `f`
+    ///
+    /// `structure.xml` and all other assets are copied unchanged.
+    pub(crate) fn map_obj_assets(&self, mut f: impl FnMut(ObjMesh) -> ObjMesh) -> L3dFile {
+        let assets = self
+            .assets
+            .iter()
+            .map(|asset| {
+                if !asset.name.to_ascii_lowercase().ends_with(".obj") {
+                    return asset.clone();
+                }
+                match ObjMesh::from_bytes(&asset.content) {
+                    Ok(mesh) => {
+                        let content = f(mesh).to_obj().into_bytes();
+                        BufFile {
+                            name: asset.name.clone(),
+                            size: content.len() as u64,
+                            content,
+                        }
+                    }
+                    Err(_) => asset.clone(),
+                }
+            })
+            .collect();
+
+        L3dFile {
+            structure: self.structure.clone(),
+            assets,
+        }
+    }
+}
+
+/// Extract L3D file contents from a ZIP buffer
+///
+/// Internal function that reads the ZIP archive and separates:
+/// - `structure.xml` → stored in `L3dFile.structure`
+/// - All other files → stored in `L3dFile.assets`
+fn get_l3d_file(l3d_buf: &[u8], log: Log) -> std::io::Result<L3dFile> {
+    let mut reader = load::ArchiveReader::new(l3d_buf, log)?;
+    while reader.read_entry()?.is_some() {}
+    Ok(reader.into_file())
 }
 
 /// Parse structure.xml and build the 3D model with transformation matrices
 ///
 /// This function parses the XML and recursively processes the geometry tree,
 /// computing the final transformation matrix for each part.
-fn parse_structure(xml_data: &str) -> L3d {
-    let luminaire: Luminaire = match Luminaire::from_xml(xml_data) {
+fn parse_structure(xml_data: &str, options: &BuildOptions, log: Log) -> L3d {
+    if xml_data.is_empty() {
+        log.error("L3D archive contains no structure.xml");
+        return L3d::default();
+    }
+    let luminaire: Luminaire = match Luminaire::from_xml_logged(xml_data, log) {
         Ok(l) => l,
-        Err(_e) => {
+        Err(e) => {
+            log.error(&format!("{:#}", e));
             return L3d::default();
         }
     };
 
-    let files = &luminaire.geometry_definitions.geometry_file_definition;
-    let geo = &luminaire.structure.geometry;
-    let mut l3d_model = L3dModel { parts: Vec::new() };
-
-    parse_geometry(files, geo, MAT4_IDENTITY, &mut l3d_model);
-
     L3d {
         file: L3dFile::default(),
-        model: l3d_model,
+        model: build_model(&luminaire, options, log),
     }
 }
 
-/// Recursively parse the geometry tree and build parts with transformations
-///
-/// L3D files can have hierarchical geometry with joints (for articulated luminaires).
-/// This function walks the tree and computes the combined transformation matrix
-/// for each geometry part by multiplying parent transforms.
-fn parse_geometry(
-    files: &[GeometryFileDefinition],
-    geo: &Geometry,
-    parent_mat: Mat4,
-    model: &mut L3dModel,
-) {
-    // Find the OBJ file for this geometry and get the unit scale
-    let (path, scale) = find_obj(files, &geo.geometry_reference.geometry_id);
-
-    // Build the transformation for this geometry
-    let mat_geo = build_transform(&geo.position, &geo.rotation);
-    let mat_scale = mat4_scale(scale);
-    let mat_final = mat4_mul(&parent_mat, &mat_geo);
-
-    // Process child geometries through joints (for articulated luminaires)
-    if let Some(j) = &geo.joints {
-        for joint in &j.joint {
-            let mat_joint = build_transform(&joint.position, &joint.rotation);
-            let mat_combined = mat4_mul(&mat_final, &mat_joint);
-            for child_geo in &joint.geometries.geometry {
-                parse_geometry(files, child_geo, mat_combined, model);
+/// Build the 3D model (parts with world matrices) for a parsed luminaire
+pub(crate) fn build_model(luminaire: &Luminaire, options: &BuildOptions, log: Log) -> L3dModel {
+    let parts = luminaire
+        .resolve_parts_logged(options, log)
+        .into_iter()
+        .map(|part| L3dPart {
+            mat: dmat4_to_mat4(&part.world),
+            mat_f64: options.double_precision.then_some(part.world),
+            name: part.name,
+            path: part.path,
+        })
+        .collect();
+    L3dModel {
+        parts,
+        coordinate_system: options.coordinate_system,
+    }
+}
+
+impl Luminaire {
+    /// Resolve every geometry of the structure into a [`ResolvedPart`]
+    ///
+    /// This is the single place where transforms are accumulated, along
+    /// [`Luminaire::walk_geometries`]; [`L3d::model`] and all bindings are
+    /// built from its result. Parts are in the order of
+    /// [`L3dModel::parts`]: every geometry follows the geometries attached
+    /// to it through joints.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use l3d_rs::{BuildOptions, Luminaire};
+    ///
+    /// let luminaire = Luminaire::load_l3d("luminaire.l3d").unwrap();
+    /// for part in luminaire.resolve_parts(&BuildOptions::default()) {
+    ///     println!("{} at {:?} (scale {})", part.name, part.world_position(), part.scale);
+    /// }
+    /// ```
+    pub fn resolve_parts(&self, options: &BuildOptions) -> Vec<ResolvedPart> {
+        self.resolve_parts_logged(options, Log::default())
+    }
+
+    pub(crate) fn resolve_parts_logged(
+        &self,
+        options: &BuildOptions,
+        log: Log,
+    ) -> Vec<ResolvedPart> {
+        let files = &self.geometry_definitions.geometry_file_definition;
+        // The conversion is the outermost transform, applied after the
+        // whole L3D hierarchy
+        let root = options.coordinate_system.matrix().map(f64::from);
+        // Parents are visited before their children, so their frames are
+        // known when a child needs them
+        let mut frames: Vec<DMat4> = Vec::new();
+        let mut parts = Vec::new();
+        for visit in self.walk_geometries() {
+            let geo = visit.geometry;
+            // Find the OBJ file for this geometry and get the unit scale
+            let (path, scale) = find_obj(files, &geo.geometry_reference.geometry_id, log);
+            if path.is_empty() {
+                log.warn(&format!(
+                    "part {:?} references unknown geometry ID {:?}",
+                    geo.part_name, geo.geometry_reference.geometry_id
+                ));
             }
+
+            let parent = match (visit.parent, visit.joint) {
+                (Some(parent), Some(joint)) => {
+                    dmat4_mul(&frames[parent], &joint_matrix(joint, options))
+                }
+                _ => root,
+            };
+            // Everything is accumulated in f64 so deep joint chains do not
+            // drift
+            let local = options
+                .rotation_order
+                .transform(&geo.position, &geo.rotation);
+            let frame = dmat4_mul(&parent, &local);
+            frames.push(frame);
+            parts.push((
+                visit.part_index,
+                ResolvedPart {
+                    name: geo.part_name.clone(),
+                    geometry_id: geo.geometry_reference.geometry_id.clone(),
+                    path,
+                    scale,
+                    position: geo.position.clone(),
+                    rotation: geo.rotation.clone(),
+                    local,
+                    parent,
+                    world: dmat4_mul(&frame, &dmat4_scale(scale)),
+                },
+            ));
         }
+        parts.sort_by_key(|(index, _)| *index);
+        parts.into_iter().map(|(_, part)| part).collect()
+    }
+
+    /// Walk the geometry tree in document order
+    ///
+    /// Every geometry comes before the geometries attached to it through
+    /// its joints. This is the walk behind [`Luminaire::resolve_parts`];
+    /// bindings that show the tree use it to map geometries to parts.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use l3d_rs::Luminaire;
+    ///
+    /// let luminaire = Luminaire::load_l3d("luminaire.l3d").unwrap();
+    /// for visit in luminaire.walk_geometries() {
+    ///     let joint = visit.joint.map(|j| j.part_name.as_str());
+    ///     println!("{} (part {}, joint {:?})", visit.geometry.part_name, visit.part_index, joint);
+    /// }
+    /// ```
+    pub fn walk_geometries(&self) -> Vec<GeometryVisit<'_>> {
+        let mut visits = Vec::new();
+        walk_geometry(&self.structure.geometry, None, None, &mut 0, &mut visits);
+        visits
     }
+}
 
-    // Add this geometry part with its final transformation
-    model.parts.push(L3dPart {
-        path,
-        mat: mat4_mul(&mat_final, &mat_scale),
+/// Recursively walk the geometry tree
+///
+/// L3D files can have hierarchical geometry with joints (for articulated
+/// luminaires). Geometries are visited parents first, while part indices
+/// are assigned after the subtree, so a part follows the parts attached
+/// to it.
+fn walk_geometry<'a>(
+    geometry: &'a Geometry,
+    joint: Option<&'a Joint>,
+    parent: Option<usize>,
+    next_part: &mut usize,
+    visits: &mut Vec<GeometryVisit<'a>>,
+) {
+    let index = visits.len();
+    visits.push(GeometryVisit {
+        geometry,
+        joint,
+        parent,
+        part_index: 0,
     });
+    for joint in geometry.joints.iter().flat_map(|j| &j.joint) {
+        for child in &joint.geometries.geometry {
+            walk_geometry(child, Some(joint), Some(index), next_part, visits);
+        }
+    }
+    visits[index].part_index = *next_part;
+    *next_part += 1;
+}
+
+/// Transform of a joint relative to its geometry, including the adjusted
+/// angles from [`BuildOptions::joint_angles`]
+fn joint_matrix(joint: &Joint, options: &BuildOptions) -> DMat4 {
+    let matrix = options
+        .rotation_order
+        .transform(&joint.position, &joint.rotation);
+    match options.joint_angles.get(&joint.part_name) {
+        Some(angles) => {
+            let adjust = options.rotation_order.matrix(&joint.clamp_angles(angles));
+            dmat4_mul(&matrix, &adjust)
+        }
+        None => matrix,
+    }
 }
 
 /// Find geometry file by ID and return the path with scale factor
@@ -510,12 +991,21 @@ fn parse_geometry(
 /// Returns a tuple of:
 /// - Path in format "geometry_id/filename.obj"
 /// - Scale factor based on the unit (mm → 0.001, in → 0.0254, m → 1.0)
-fn find_obj(files: &[GeometryFileDefinition], id: &str) -> (String, f32) {
+///
+/// Unknown units fall back to meters with a warning; an unknown ID gives
+/// an empty path.
+fn find_obj(files: &[GeometryFileDefinition], id: &str, log: Log) -> (String, f64) {
     for file in files {
         if file.id == id {
+            if !validation::KNOWN_UNITS.contains(&file.units.as_str()) {
+                log.warn(&format!(
+                    "geometry {:?} has unknown units {:?}, assuming meters",
+                    file.id, file.units
+                ));
+            }
             return (
                 format!("{}/{}", &file.id, &file.filename),
-                get_scale(&file.units),
+                get_scale_f64(&file.units),
             );
         }
     }
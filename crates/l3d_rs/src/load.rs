@@ -0,0 +1,400 @@
+//! # Progress Reporting and Cancellation
+//!
+//! Load an L3D archive while reporting progress (archive entries and bytes
+//! read, current stage) to a [`LoadObserver`] that can also cancel the
+//! load, e.g. from a UI thread. Every progress report is written to the
+//! [`Logger`] of the [`LoadOptions`] as well, and so are the problems
+//! found on the way (see [`from_buffer_with_logger`](crate::from_buffer_with_logger)).
+//!
+//! [`load_async`] additionally awaits an [`AsyncLogger`] after every step,
+//! so a logger that yields to the event loop keeps a WASM frontend
+//! responsive while a large archive is read.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use l3d_rs::load::{load, Cancelled, LoadOptions, LoadProgress};
+//!
+//! let bytes = std::fs::read("luminaire.l3d").unwrap();
+//! let report = |progress: &LoadProgress| println!("{}", progress);
+//! let options = LoadOptions {
+//!     observer: Some(&report),
+//!     ..Default::default()
+//! };
+//! match load(&bytes, &options) {
+//!     Ok(l3d) => println!("{} parts", l3d.model.parts.len()),
+//!     Err(e) if e.is::<Cancelled>() => println!("cancelled"),
+//!     Err(e) => eprintln!("{:#}", e),
+//! }
+//! ```
+
+use crate::l3d::{BufFile, BuildOptions, L3d, L3dFile, L3dModel, Luminaire};
+use crate::{build_model, AsyncLogger, Log, Logger};
+use anyhow::{bail, Context, Result};
+use std::fmt;
+use std::io::{Cursor, Read};
+use zip::ZipArchive;
+
+/// Stage of loading an L3D archive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoadStage {
+    /// Decompressing the entries of the ZIP archive
+    ReadingArchive,
+    /// Parsing `structure.xml`
+    ParsingStructure,
+    /// Computing the part matrices
+    BuildingModel,
+    /// Loading is complete
+    Done,
+}
+
+impl fmt::Display for LoadStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LoadStage::ReadingArchive => "reading archive",
+            LoadStage::ParsingStructure => "parsing structure.xml",
+            LoadStage::BuildingModel => "building model",
+            LoadStage::Done => "done",
+        })
+    }
+}
+
+/// Progress of a load, passed to [`LoadObserver::on_progress`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadProgress {
+    /// Current stage
+    pub stage: LoadStage,
+    /// Archive entries read so far
+    pub entries_read: usize,
+    /// Number of entries in the archive
+    pub entry_count: usize,
+    /// Uncompressed bytes read so far
+    pub bytes_read: u64,
+    /// Uncompressed size of all entries
+    pub total_bytes: u64,
+}
+
+impl LoadProgress {
+    /// Fraction of the archive read, from 0 to 1
+    pub fn fraction(&self) -> f64 {
+        match self.stage {
+            LoadStage::ReadingArchive if self.total_bytes > 0 => {
+                self.bytes_read as f64 / self.total_bytes as f64
+            }
+            LoadStage::ReadingArchive => 0.0,
+            _ => 1.0,
+        }
+    }
+}
+
+impl fmt::Display for LoadProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}/{} entries, {}/{} bytes",
+            self.stage, self.entries_read, self.entry_count, self.bytes_read, self.total_bytes
+        )
+    }
+}
+
+/// Receives progress reports and decides whether to go on
+///
+/// Closures taking a [`LoadProgress`] implement this trait and never
+/// cancel.
+pub trait LoadObserver {
+    /// Called when a stage starts, after every archive entry and between
+    /// the chunks of a large entry
+    fn on_progress(&self, progress: &LoadProgress);
+
+    /// Checked after every progress report; return `true` to stop loading
+    fn is_cancelled(&self) -> bool {
+        false
+    }
+}
+
+impl<F: Fn(&LoadProgress)> LoadObserver for F {
+    fn on_progress(&self, progress: &LoadProgress) {
+        self(progress)
+    }
+}
+
+/// Error returned by [`load`] and [`load_async`] when the
+/// [`LoadObserver`] cancelled loading
+///
+/// Check for it with `error.is::<Cancelled>()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("loading was cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+/// Options for [`load`] and [`load_async`]
+#[derive(Default)]
+pub struct LoadOptions<'a> {
+    /// How to build the model from the structure
+    pub build: BuildOptions,
+    /// Receives progress reports and can cancel
+    pub observer: Option<&'a dyn LoadObserver>,
+    /// Receives every progress report as a message, and warnings about
+    /// recoverable problems
+    pub logger: Option<&'a dyn Logger>,
+}
+
+/// Load an L3D archive, reporting progress
+///
+/// Like [`try_from_buffer_with_options`](crate::try_from_buffer_with_options),
+/// but reports every step to `options.observer` and `options.logger` and
+/// fails with [`Cancelled`] as soon as the observer cancels.
+pub fn load(buf: &[u8], options: &LoadOptions) -> Result<L3d> {
+    let mut load = Load::new(buf, options, false)?;
+    while load.read_entry()? {}
+    let luminaire = load.parse_structure()?;
+    let model = load.build_model(&luminaire)?;
+    Ok(load.finish(model))
+}
+
+/// Load an L3D archive, reporting progress and awaiting `logger` after
+/// every step
+///
+/// Behaves like [`load`]; every message also goes to `logger`.
+pub async fn load_async(
+    buf: &[u8],
+    options: &LoadOptions<'_>,
+    logger: &impl AsyncLogger,
+) -> Result<L3d> {
+    let mut load = Load::new(buf, options, true)?;
+    flush(&mut load, logger).await;
+    while load.read_entry()? {
+        flush(&mut load, logger).await;
+    }
+    let luminaire = load.parse_structure();
+    flush(&mut load, logger).await;
+    let model = load.build_model(&luminaire?);
+    flush(&mut load, logger).await;
+    Ok(load.finish(model?))
+}
+
+/// Pass the collected messages of a load to `logger`
+async fn flush(load: &mut Load<'_, '_>, logger: &impl AsyncLogger) {
+    for message in load.reporter.messages.drain(..) {
+        logger.log(&message).await;
+    }
+}
+
+/// Largest buffer reserved up front for an archive entry
+const MAX_SIZE_HINT: u64 = 64 * 1024 * 1024;
+
+/// Number of bytes decompressed between two progress reports
+pub(crate) const CHUNK_SIZE: u64 = 1024 * 1024;
+
+/// Reads the entries of an L3D archive one at a time
+pub(crate) struct ArchiveReader<'a, 'l> {
+    zip: ZipArchive<Cursor<&'a [u8]>>,
+    log: Log<'l>,
+    next: usize,
+    bytes_read: u64,
+    total_bytes: u64,
+    file: L3dFile,
+}
+
+impl<'a, 'l> ArchiveReader<'a, 'l> {
+    pub(crate) fn new(buf: &'a [u8], log: Log<'l>) -> std::io::Result<Self> {
+        let mut zip = ZipArchive::new(Cursor::new(buf))?;
+        let mut total_bytes = 0;
+        for i in 0..zip.len() {
+            total_bytes += zip.by_index_raw(i)?.size();
+        }
+        Ok(Self {
+            zip,
+            log,
+            next: 0,
+            bytes_read: 0,
+            total_bytes,
+            file: L3dFile::default(),
+        })
+    }
+
+    /// Read the next entry, returning its name, or `None` after the last
+    pub(crate) fn read_entry(&mut self) -> std::io::Result<Option<String>> {
+        self.read_entry_with(|_, _| Ok(()))
+    }
+
+    /// Read the next entry in chunks of [`CHUNK_SIZE`]
+    ///
+    /// `on_chunk` gets the entry name and the total number of bytes read
+    /// whenever a chunk was read and more may follow; an error from it
+    /// stops reading.
+    pub(crate) fn read_entry_with<E: From<std::io::Error>>(
+        &mut self,
+        mut on_chunk: impl FnMut(&str, u64) -> Result<(), E>,
+    ) -> Result<Option<String>, E> {
+        if self.next == self.zip.len() {
+            return Ok(None);
+        }
+        let mut file = self.zip.by_index(self.next).map_err(std::io::Error::from)?;
+        self.next += 1;
+        let name = file.name().to_string();
+        if !file.is_file() {
+            self.log
+                .info(&format!("skipped archive entry {:?}: not a file", name));
+            return Ok(Some(name));
+        }
+        if name.ends_with("/structure.xml") {
+            self.log.warn(&format!(
+                "{:?} is kept as an asset, only structure.xml at the archive root is parsed",
+                name
+            ));
+        }
+
+        // The declared size is only a hint: a crafted header can claim
+        // gigabytes, and the entry must not yield more than it declares
+        let size = file.size();
+        let mut buf: Vec<u8> = Vec::with_capacity(size.min(MAX_SIZE_HINT) as usize);
+        let mut entry = (&mut file).take(size);
+        loop {
+            let read = (&mut entry).take(CHUNK_SIZE).read_to_end(&mut buf)? as u64;
+            self.bytes_read += read;
+            if read < CHUNK_SIZE {
+                break;
+            }
+            on_chunk(&name, self.bytes_read)?;
+        }
+        if name == "structure.xml" {
+            // Take ownership of valid UTF-8 instead of copying it
+            self.file.structure = String::from_utf8(buf)
+                .unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned());
+        } else {
+            self.file.assets.push(BufFile {
+                name: name.clone(),
+                size: buf.len() as u64,
+                content: buf,
+            });
+        }
+        Ok(Some(name))
+    }
+
+    pub(crate) fn into_file(self) -> L3dFile {
+        self.file
+    }
+}
+
+/// A load in progress
+struct Load<'a, 'o> {
+    reader: ArchiveReader<'a, 'o>,
+    options: &'o LoadOptions<'o>,
+    reporter: Reporter<'o>,
+}
+
+/// Passes the progress of a load to the observer and logger
+struct Reporter<'o> {
+    options: &'o LoadOptions<'o>,
+    progress: LoadProgress,
+    /// Reported messages for [`load_async`] to pass on, if collected
+    messages: Vec<String>,
+    collect_messages: bool,
+}
+
+impl<'a, 'o> Load<'a, 'o> {
+    fn new(buf: &'a [u8], options: &'o LoadOptions<'o>, collect_messages: bool) -> Result<Self> {
+        let reader =
+            ArchiveReader::new(buf, Log(options.logger)).context("Failed to read L3D archive")?;
+        let progress = LoadProgress {
+            stage: LoadStage::ReadingArchive,
+            entries_read: 0,
+            entry_count: reader.zip.len(),
+            bytes_read: 0,
+            total_bytes: reader.total_bytes,
+        };
+        let mut load = Self {
+            reader,
+            options,
+            reporter: Reporter {
+                options,
+                progress,
+                messages: Vec::new(),
+                collect_messages,
+            },
+        };
+        load.reporter.report(progress.to_string())?;
+        Ok(load)
+    }
+
+    /// Read the next archive entry, returning `false` after the last
+    fn read_entry(&mut self) -> Result<bool> {
+        let reporter = &mut self.reporter;
+        let entry = self.reader.read_entry_with(|name, bytes_read| {
+            reporter.progress.bytes_read = bytes_read;
+            reporter.report(format!("{} ({})", reporter.progress, name))
+        });
+        let entry = entry.map_err(|e| {
+            if e.is::<Cancelled>() {
+                e
+            } else {
+                e.context("Failed to read L3D archive")
+            }
+        });
+        let Some(name) = entry? else {
+            return Ok(false);
+        };
+        let reporter = &mut self.reporter;
+        reporter.progress.entries_read = self.reader.next;
+        reporter.progress.bytes_read = self.reader.bytes_read;
+        reporter.report(format!("{} ({})", reporter.progress, name))?;
+        Ok(true)
+    }
+
+    fn parse_structure(&mut self) -> Result<Luminaire> {
+        self.advance(LoadStage::ParsingStructure)?;
+        anyhow::ensure!(
+            !self.reader.file.structure.is_empty(),
+            "L3D archive contains no structure.xml"
+        );
+        Luminaire::from_xml_logged(&self.reader.file.structure, Log(self.options.logger))
+    }
+
+    fn build_model(&mut self, luminaire: &Luminaire) -> Result<L3dModel> {
+        self.advance(LoadStage::BuildingModel)?;
+        let model = build_model(luminaire, &self.options.build, Log(self.options.logger));
+        self.advance(LoadStage::Done)?;
+        Ok(model)
+    }
+
+    fn finish(self, model: L3dModel) -> L3d {
+        L3d {
+            model,
+            file: self.reader.into_file(),
+        }
+    }
+
+    fn advance(&mut self, stage: LoadStage) -> Result<()> {
+        self.reporter.progress.stage = stage;
+        self.reporter.report(self.reporter.progress.to_string())
+    }
+}
+
+impl Reporter<'_> {
+    /// Pass the progress to the observer and logger, failing if cancelled
+    fn report(&mut self, message: String) -> Result<()> {
+        if let Some(logger) = self.options.logger {
+            logger.log(&message);
+        }
+        if self.collect_messages {
+            self.messages.push(message);
+        }
+        if let Some(observer) = self.options.observer {
+            observer.on_progress(&self.progress);
+            if observer.is_cancelled() {
+                if let Some(logger) = self.options.logger {
+                    logger.log(&Cancelled.to_string());
+                }
+                bail!(Cancelled);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,281 @@
+//! # Wavefront OBJ Parsing
+//!
+//! L3D archives store the geometry of every part as an OBJ file. This module
+//! provides a small, dependency-free OBJ reader and writer so the crate can
+//! inspect and rewrite the geometry itself (bounds, statistics, render
+//! buffers, simplification) without a 3D engine.
+//!
+//! Only the subset of OBJ used by L3D files is supported: vertex positions
+//! (`v`), normals (`vn`), texture coordinates (`vt`), polygonal faces (`f`)
+//! and material references (`mtllib`, `usemtl`). Other statements are ignored.
+//!
+//! # Example
+//!
+//! ```
+//! use l3d_rs::ObjMesh;
+//!
+//! let obj = "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n";
+//! let mesh = ObjMesh::parse(obj).unwrap();
+//! assert_eq!(mesh.positions.len(), 3);
+//! assert_eq!(mesh.faces.len(), 1);
+//! ```
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+
+/// A single face corner referencing position, texture coordinate and normal
+///
+/// All indices are zero-based and already resolved (OBJ's one-based and
+/// negative relative indices are converted while parsing).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ObjVertex {
+    /// Index into [`ObjMesh::positions`]
+    pub position: usize,
+    /// Index into [`ObjMesh::texcoords`], if the face specifies one
+    pub texcoord: Option<usize>,
+    /// Index into [`ObjMesh::normals`], if the face specifies one
+    pub normal: Option<usize>,
+}
+
+/// A polygonal face as written in the OBJ file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObjFace {
+    /// Face corners in file order (at least three)
+    pub vertices: Vec<ObjVertex>,
+    /// Index into [`ObjMesh::materials`] of the active `usemtl`, if any
+    pub material: Option<usize>,
+}
+
+/// Geometry parsed from an OBJ file
+///
+/// Faces keep their original polygon layout so face indices match those used
+/// by L3D light emitting surface assignments. Use [`ObjMesh::triangles`] for a
+/// triangulated view.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ObjMesh {
+    /// Vertex positions (`v`)
+    pub positions: Vec<[f32; 3]>,
+    /// Vertex normals (`vn`)
+    pub normals: Vec<[f32; 3]>,
+    /// Texture coordinates (`vt`)
+    pub texcoords: Vec<[f32; 2]>,
+    /// Polygonal faces (`f`)
+    pub faces: Vec<ObjFace>,
+    /// Material names referenced by `usemtl`, in order of first use
+    pub materials: Vec<String>,
+    /// Material libraries referenced by `mtllib`
+    pub material_libs: Vec<String>,
+}
+
+impl ObjMesh {
+    /// Parse OBJ text into a mesh
+    ///
+    /// Returns an error with the offending line number for malformed numbers
+    /// or face indices that point outside the vertex lists.
+    pub fn parse(text: &str) -> Result<ObjMesh> {
+        let mut mesh = ObjMesh::default();
+        let mut material = None;
+
+        for (line_no, line) in text.lines().enumerate() {
+            mesh.parse_line(line, &mut material)
+                .with_context(|| format!("OBJ line {}", line_no + 1))?;
+        }
+        Ok(mesh)
+    }
+
+    /// Parse OBJ data from raw bytes, e.g. an asset of an L3D archive
+    ///
+    /// Invalid UTF-8 sequences are replaced before parsing.
+    pub fn from_bytes(bytes: &[u8]) -> Result<ObjMesh> {
+        Self::parse(&String::from_utf8_lossy(bytes))
+    }
+
+    fn parse_line(&mut self, line: &str, material: &mut Option<usize>) -> Result<()> {
+        let line = line.split('#').next().unwrap_or_default();
+        let mut tokens = line.split_whitespace();
+        let Some(keyword) = tokens.next() else {
+            return Ok(());
+        };
+
+        match keyword {
+            "v" => {
+                let [x, y, z] = parse_floats::<3>(&mut tokens)?;
+                self.positions.push([x, y, z]);
+            }
+            "vn" => {
+                let [x, y, z] = parse_floats::<3>(&mut tokens)?;
+                self.normals.push([x, y, z]);
+            }
+            "vt" => {
+                // The v coordinate is optional in OBJ and defaults to 0
+                let [u] = parse_floats::<1>(&mut tokens)?;
+                let v = match tokens.next() {
+                    Some(t) => t
+                        .parse()
+                        .with_context(|| format!("invalid number '{}'", t))?,
+                    None => 0.0,
+                };
+                self.texcoords.push([u, v]);
+            }
+            "f" => {
+                let vertices = tokens
+                    .map(|t| self.parse_face_vertex(t))
+                    .collect::<Result<Vec<_>>>()?;
+                if vertices.len() < 3 {
+                    bail!("face needs at least three vertices");
+                }
+                self.faces.push(ObjFace {
+                    vertices,
+                    material: *material,
+                });
+            }
+            "usemtl" => {
+                let name = tokens.collect::<Vec<_>>().join(" ");
+                let index = match self.materials.iter().position(|m| *m == name) {
+                    Some(index) => index,
+                    None => {
+                        self.materials.push(name);
+                        self.materials.len() - 1
+                    }
+                };
+                *material = Some(index);
+            }
+            "mtllib" => {
+                self.material_libs.extend(tokens.map(str::to_string));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn parse_face_vertex(&self, token: &str) -> Result<ObjVertex> {
+        let mut parts = token.split('/');
+        let position = resolve_index(parts.next(), self.positions.len())?
+            .ok_or_else(|| anyhow!("face vertex '{}' has no position", token))?;
+        let texcoord = resolve_index(parts.next(), self.texcoords.len())?;
+        let normal = resolve_index(parts.next(), self.normals.len())?;
+        Ok(ObjVertex {
+            position,
+            texcoord,
+            normal,
+        })
+    }
+
+    /// Number of triangles after fan triangulation of all faces
+    pub fn triangle_count(&self) -> usize {
+        self.faces.iter().map(|f| f.vertices.len() - 2).sum()
+    }
+
+    /// Iterate over all faces as triangles
+    ///
+    /// Polygons are fan-triangulated. Each item carries the index of the
+    /// original face so results can be mapped back to the OBJ file.
+    pub fn triangles(&self) -> impl Iterator<Item = (usize, [ObjVertex; 3])> + '_ {
+        self.faces
+            .iter()
+            .enumerate()
+            .flat_map(|(face_index, face)| {
+                let v = &face.vertices;
+                (1..v.len() - 1).map(move |i| (face_index, [v[0], v[i], v[i + 1]]))
+            })
+    }
+
+    /// Axis-aligned bounding box of all vertex positions as `(min, max)`
+    ///
+    /// Returns `None` for meshes without vertices.
+    pub fn bounds(&self) -> Option<([f32; 3], [f32; 3])> {
+        let first = *self.positions.first()?;
+        Some(
+            self.positions
+                .iter()
+                .fold((first, first), |(mut min, mut max), p| {
+                    for i in 0..3 {
+                        min[i] = min[i].min(p[i]);
+                        max[i] = max[i].max(p[i]);
+                    }
+                    (min, max)
+                }),
+        )
+    }
+
+    /// Write the mesh as OBJ text
+    ///
+    /// Indices are written one-based, so the output parses back into the
+    /// same mesh with [`ObjMesh::parse`]. Material libraries are referenced
+    /// by name only; their files are not written.
+    pub fn to_obj(&self) -> String {
+        let mut out = String::new();
+        for lib in &self.material_libs {
+            let _ = writeln!(out, "mtllib {}", lib);
+        }
+        for [x, y, z] in &self.positions {
+            let _ = writeln!(out, "v {} {} {}", x, y, z);
+        }
+        for [u, v] in &self.texcoords {
+            let _ = writeln!(out, "vt {} {}", u, v);
+        }
+        for [x, y, z] in &self.normals {
+            let _ = writeln!(out, "vn {} {} {}", x, y, z);
+        }
+
+        let mut material = None;
+        for face in &self.faces {
+            if face.material != material {
+                if let Some(name) = face.material.and_then(|m| self.materials.get(m)) {
+                    let _ = writeln!(out, "usemtl {}", name);
+                }
+                material = face.material;
+            }
+            out.push('f');
+            for v in &face.vertices {
+                let _ = match (v.texcoord, v.normal) {
+                    (None, None) => write!(out, " {}", v.position + 1),
+                    (Some(t), None) => write!(out, " {}/{}", v.position + 1, t + 1),
+                    (None, Some(n)) => write!(out, " {}//{}", v.position + 1, n + 1),
+                    (Some(t), Some(n)) => {
+                        write!(out, " {}/{}/{}", v.position + 1, t + 1, n + 1)
+                    }
+                };
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Parse the next `N` tokens as floats
+fn parse_floats<'a, const N: usize>(
+    tokens: &mut impl Iterator<Item = &'a str>,
+) -> Result<[f32; N]> {
+    let mut values = [0.0; N];
+    for value in values.iter_mut() {
+        let token = tokens
+            .next()
+            .ok_or_else(|| anyhow!("expected {} coordinates", N))?;
+        *value = token
+            .parse()
+            .with_context(|| format!("invalid number '{}'", token))?;
+    }
+    Ok(values)
+}
+
+/// Resolve a one-based or negative (relative) OBJ index to a zero-based index
+fn resolve_index(token: Option<&str>, len: usize) -> Result<Option<usize>> {
+    let token = match token {
+        Some(t) if !t.is_empty() => t,
+        _ => return Ok(None),
+    };
+    let index: i64 = token
+        .parse()
+        .with_context(|| format!("invalid index '{}'", token))?;
+    let resolved = match index {
+        i if i > 0 => i - 1,
+        i if i < 0 => len as i64 + i,
+        _ => bail!("index 0 is not valid in OBJ"),
+    };
+    if resolved < 0 || resolved as usize >= len {
+        bail!("index {} out of range ({} entries)", index, len);
+    }
+    Ok(Some(resolved as usize))
+}
@@ -0,0 +1,314 @@
+//! # Ray Picking
+//!
+//! CPU ray casting against the assembled luminaire, e.g. to find the part
+//! under the mouse cursor in a configurator.
+//!
+//! [`PickScene`] transforms the triangles of every part into world space
+//! with [`L3dPart::mat`](crate::L3dPart::mat) and stores them in a bounding
+//! volume hierarchy, so repeated queries only test a few triangles. Build it
+//! once after loading and reuse it for every click. Positions are in the
+//! coordinate system of the model (see
+//! [`BuildOptions`](crate::BuildOptions)), in meters.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use l3d_rs::{from_buffer, Ray};
+//!
+//! let l3d = from_buffer(&std::fs::read("luminaire.l3d").unwrap());
+//! let scene = l3d.pick_scene();
+//! let ray = Ray::new([0.0, -5.0, 0.1], [0.0, 1.0, 0.0]);
+//! if let Some(hit) = scene.pick(&ray) {
+//!     println!("{} face {} at {:?}", hit.part_name, hit.face_index, hit.position);
+//! }
+//! ```
+
+use crate::l3d::{mat4_transform_point, L3d, Mat4};
+use crate::obj::ObjMesh;
+use crate::vecmath::{cross, dot, length, sub};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Maximum number of triangles in a BVH leaf
+const LEAF_SIZE: usize = 4;
+
+/// A ray with origin and direction in model coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Ray {
+    /// Start point of the ray
+    pub origin: [f32; 3],
+    /// Direction of the ray; does not need to be normalized
+    pub direction: [f32; 3],
+}
+
+impl Ray {
+    /// Create a ray from origin and direction
+    pub fn new(origin: [f32; 3], direction: [f32; 3]) -> Ray {
+        Ray { origin, direction }
+    }
+
+    /// Ray through a point on screen
+    ///
+    /// `inverse_view_projection` is the inverse of `projection * view` and
+    /// `ndc` the point in normalized device coordinates (`-1..=1`, Y up).
+    /// The ray starts on the near plane. Returns `None` if the matrix is
+    /// not invertible.
+    pub fn from_screen(inverse_view_projection: &Mat4, ndc: [f32; 2]) -> Option<Ray> {
+        let unproject = |z: f32| {
+            let m = inverse_view_projection;
+            let p = mat4_transform_point(m, [ndc[0], ndc[1], z]);
+            let w = m[3] * ndc[0] + m[7] * ndc[1] + m[11] * z + m[15];
+            (w.abs() > f32::EPSILON).then(|| p.map(|c| c / w))
+        };
+        let near = unproject(-1.0)?;
+        let far = unproject(1.0)?;
+        Some(Ray::new(
+            near,
+            [far[0] - near[0], far[1] - near[1], far[2] - near[2]],
+        ))
+    }
+}
+
+/// Closest intersection of a ray with the model
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PickHit {
+    /// Index into [`L3dModel::parts`](crate::L3dModel::parts)
+    pub part_index: usize,
+    /// Part name from `structure.xml`
+    pub part_name: String,
+    /// Path of the part's geometry file
+    pub path: String,
+    /// Index of the hit face in the OBJ file (before triangulation)
+    pub face_index: usize,
+    /// World position of the hit
+    pub position: [f32; 3],
+    /// Unit normal of the hit triangle, facing the ray origin
+    pub normal: [f32; 3],
+    /// Distance from the ray origin to the hit
+    pub distance: f32,
+}
+
+/// World-space triangles of a model in a bounding volume hierarchy
+#[derive(Debug, Clone, Default)]
+pub struct PickScene {
+    triangles: Vec<Triangle>,
+    nodes: Vec<Node>,
+    parts: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone)]
+struct Triangle {
+    vertices: [[f64; 3]; 3],
+    part_index: usize,
+    face_index: usize,
+}
+
+/// BVH node; leaves reference `count` triangles starting at `start`,
+/// inner nodes have their left child at the next index and their right
+/// child at `start`
+#[derive(Debug, Clone)]
+struct Node {
+    min: [f64; 3],
+    max: [f64; 3],
+    start: usize,
+    count: usize,
+}
+
+impl PickScene {
+    /// Build the scene from the parts of a loaded L3D
+    ///
+    /// Parts whose geometry is missing or cannot be parsed are skipped;
+    /// use [`L3d::stats`] to find them.
+    pub fn new(l3d: &L3d) -> PickScene {
+        let mut meshes: HashMap<&str, Option<ObjMesh>> = HashMap::new();
+        let mut triangles = Vec::new();
+        let mut parts = Vec::with_capacity(l3d.model.parts.len());
+
+        for (part_index, part) in l3d.model.parts.iter().enumerate() {
+            parts.push((part.name.clone(), part.path.clone()));
+            let mesh = meshes.entry(&part.path).or_insert_with(|| {
+                let asset = l3d.asset(&part.path)?;
+                ObjMesh::from_bytes(&asset.content).ok()
+            });
+            let Some(mesh) = mesh else {
+                continue;
+            };
+
+            let world: Vec<[f64; 3]> = mesh
+                .positions
+                .iter()
+                .map(|p| mat4_transform_point(&part.mat, *p).map(f64::from))
+                .collect();
+            triangles.extend(mesh.triangles().map(|(face_index, tri)| Triangle {
+                vertices: tri.map(|v| world[v.position]),
+                part_index,
+                face_index,
+            }));
+        }
+
+        let mut scene = PickScene {
+            triangles,
+            nodes: Vec::new(),
+            parts,
+        };
+        if !scene.triangles.is_empty() {
+            scene.build_node(0, scene.triangles.len());
+        }
+        scene
+    }
+
+    /// Number of triangles in the scene
+    pub fn triangle_count(&self) -> usize {
+        self.triangles.len()
+    }
+
+    /// Axis-aligned bounds of all triangles as `(min, max)`
+    pub fn bounds(&self) -> Option<([f32; 3], [f32; 3])> {
+        let root = self.nodes.first()?;
+        Some((root.min.map(|v| v as f32), root.max.map(|v| v as f32)))
+    }
+
+    /// Closest hit of the ray, if any
+    ///
+    /// Triangles are hit from both sides so parts with inconsistent
+    /// winding can still be picked.
+    pub fn pick(&self, ray: &Ray) -> Option<PickHit> {
+        let origin = ray.origin.map(f64::from);
+        let direction = ray.direction.map(f64::from);
+        let len = length(direction);
+        if self.nodes.is_empty() || len == 0.0 {
+            return None;
+        }
+        let direction = direction.map(|c| c / len);
+        let inverse = direction.map(|c| 1.0 / c);
+
+        let mut best: Option<(f64, usize)> = None;
+        let mut stack = vec![0];
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            let limit = best.map_or(f64::INFINITY, |(t, _)| t);
+            if !hits_box(node, origin, inverse, limit) {
+                continue;
+            }
+            if node.count > 0 {
+                for i in node.start..node.start + node.count {
+                    if let Some(t) = intersect(&self.triangles[i], origin, direction) {
+                        if t < best.map_or(f64::INFINITY, |(b, _)| b) {
+                            best = Some((t, i));
+                        }
+                    }
+                }
+            } else {
+                stack.push(node.start);
+                stack.push(index + 1);
+            }
+        }
+
+        let (t, i) = best?;
+        let triangle = &self.triangles[i];
+        let [a, b, c] = triangle.vertices;
+        let mut normal = cross(sub(b, a), sub(c, a));
+        let n = length(normal);
+        if dot(normal, direction) > 0.0 {
+            normal = normal.map(|v| -v);
+        }
+        let (part_name, path) = &self.parts[triangle.part_index];
+        Some(PickHit {
+            part_index: triangle.part_index,
+            part_name: part_name.clone(),
+            path: path.clone(),
+            face_index: triangle.face_index,
+            position: [0, 1, 2].map(|k| (origin[k] + direction[k] * t) as f32),
+            normal: normal.map(|v| (v / n) as f32),
+            distance: t as f32,
+        })
+    }
+
+    /// Build the subtree for `triangles[start..end]` and return its index
+    fn build_node(&mut self, start: usize, end: usize) -> usize {
+        let index = self.nodes.len();
+        let (min, max) = triangle_bounds(&self.triangles[start..end]);
+        self.nodes.push(Node {
+            min,
+            max,
+            start,
+            count: end - start,
+        });
+        if end - start <= LEAF_SIZE {
+            return index;
+        }
+
+        // Split at the median centroid along the longest axis
+        let extent = sub(max, min);
+        let axis = (0..3)
+            .max_by(|&a, &b| extent[a].total_cmp(&extent[b]))
+            .unwrap_or(0);
+        let centroid = |t: &Triangle| t.vertices.iter().map(|v| v[axis]).sum::<f64>();
+        let mid = (start + end) / 2;
+        self.triangles[start..end]
+            .select_nth_unstable_by(mid - start, |a, b| centroid(a).total_cmp(&centroid(b)));
+
+        self.build_node(start, mid);
+        let right = self.build_node(mid, end);
+        self.nodes[index].start = right;
+        self.nodes[index].count = 0;
+        index
+    }
+}
+
+impl L3d {
+    /// Build a [`PickScene`] for ray casting against the model
+    pub fn pick_scene(&self) -> PickScene {
+        PickScene::new(self)
+    }
+}
+
+fn triangle_bounds(triangles: &[Triangle]) -> ([f64; 3], [f64; 3]) {
+    let mut min = [f64::INFINITY; 3];
+    let mut max = [f64::NEG_INFINITY; 3];
+    for v in triangles.iter().flat_map(|t| t.vertices.iter()) {
+        for k in 0..3 {
+            min[k] = min[k].min(v[k]);
+            max[k] = max[k].max(v[k]);
+        }
+    }
+    (min, max)
+}
+
+/// Slab test of the ray against a node's box up to distance `limit`
+fn hits_box(node: &Node, origin: [f64; 3], inverse: [f64; 3], limit: f64) -> bool {
+    let mut near = 0.0f64;
+    let mut far = limit;
+    for k in 0..3 {
+        let t0 = (node.min[k] - origin[k]) * inverse[k];
+        let t1 = (node.max[k] - origin[k]) * inverse[k];
+        // NaN (origin on a slab with zero direction) keeps the bounds as-is
+        near = near.max(t0.min(t1));
+        far = far.min(t0.max(t1));
+    }
+    near <= far
+}
+
+/// Möller–Trumbore intersection; distance along the ray if it hits
+fn intersect(triangle: &Triangle, origin: [f64; 3], direction: [f64; 3]) -> Option<f64> {
+    let [a, b, c] = triangle.vertices;
+    let e1 = sub(b, a);
+    let e2 = sub(c, a);
+    let p = cross(direction, e2);
+    let det = dot(e1, p);
+    if det.abs() <= 1e-12 * length(e1) * length(e2) {
+        return None;
+    }
+    let s = sub(origin, a);
+    let u = dot(s, p) / det;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let q = cross(s, e1);
+    let v = dot(direction, q) / det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let t = dot(e2, q) / det;
+    (t >= 0.0).then_some(t)
+}
@@ -0,0 +1,229 @@
+//! # Render Buffers
+//!
+//! Interleaved, indexed vertex buffers per part, ready to upload to a GPU
+//! without any further processing in the frontend.
+//!
+//! Every vertex is [`VERTEX_STRIDE`] `f32` values: position (3), normal (3)
+//! and texture coordinate (2). OBJ corners that share position, normal and
+//! texture coordinate become one vertex; faces are triangulated into `u32`
+//! triangle indices with counter-clockwise front faces. Corners without a
+//! normal get the normal of their face, so faces without `vn` statements
+//! render flat unless [`RenderOptions::smooth_normals`] regenerates them.
+//! Missing texture coordinates are `(0, 0)`.
+//!
+//! Buffers are either in the part's local space, to be drawn with
+//! [`RenderBuffer::transform`], or already in world space with an identity
+//! transform (see [`TransformSpace`]).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use l3d_rs::{from_buffer, RenderOptions, VERTEX_STRIDE};
+//!
+//! let l3d = from_buffer(&std::fs::read("luminaire.l3d").unwrap());
+//! for buffer in l3d.render_buffers(&RenderOptions::default()) {
+//!     let vertex_count = buffer.vertices.len() / VERTEX_STRIDE;
+//!     println!("{}: {} vertices, {} indices", buffer.name, vertex_count, buffer.indices.len());
+//! }
+//! ```
+
+use crate::l3d::{
+    mat4_determinant, mat4_normal_matrix, mat4_transform_point, L3d, Mat3, Mat4, MAT4_IDENTITY,
+};
+use crate::obj::{ObjMesh, ObjVertex};
+use crate::repair::{repair, RepairOptions};
+use crate::vecmath::{cross, length, sub};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Number of `f32` values per vertex: position, normal, texture coordinate
+pub const VERTEX_STRIDE: usize = 8;
+
+/// Space the vertex positions and normals of a [`RenderBuffer`] are in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransformSpace {
+    /// Geometry file coordinates; draw with [`RenderBuffer::transform`]
+    ///
+    /// Parts sharing an OBJ file have identical buffers, so renderers can
+    /// instance them.
+    #[default]
+    Local,
+    /// Model coordinates with the part matrix applied
+    World,
+}
+
+/// Options for [`L3d::render_buffers`]
+#[derive(Debug, Clone, Default)]
+pub struct RenderOptions {
+    /// Whether to bake the part matrices into the vertices
+    pub space: TransformSpace,
+    /// Replace the normals of every mesh with smooth normals, keeping edges
+    /// sharper than this angle in degrees
+    ///
+    /// Duplicate positions are welded and face winding is made consistent
+    /// first, so split faces are smoothed too and normals point outward
+    /// (see [`repair`](crate::repair)). `None` uses the normals of the OBJ
+    /// file.
+    pub smooth_normals: Option<f32>,
+}
+
+/// GPU-ready geometry of one part
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RenderBuffer {
+    /// Index into [`L3dModel::parts`](crate::L3dModel::parts)
+    pub part_index: usize,
+    /// Part name from `structure.xml`
+    pub name: String,
+    /// Path of the part's geometry file
+    pub path: String,
+    /// Interleaved vertices, [`VERTEX_STRIDE`] values each
+    pub vertices: Vec<f32>,
+    /// Triangle list indices into the vertices
+    pub indices: Vec<u32>,
+    /// Model matrix to draw the buffer with; identity for
+    /// [`TransformSpace::World`]
+    pub transform: Mat4,
+}
+
+impl RenderBuffer {
+    /// Number of vertices
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len() / VERTEX_STRIDE
+    }
+
+    /// Vertex data as little-endian bytes, e.g. for a `GPUBuffer` or
+    /// `MTLBuffer`
+    pub fn vertex_bytes(&self) -> Vec<u8> {
+        self.vertices.iter().flat_map(|v| v.to_le_bytes()).collect()
+    }
+
+    /// Index data as little-endian bytes
+    pub fn index_bytes(&self) -> Vec<u8> {
+        self.indices.iter().flat_map(|i| i.to_le_bytes()).collect()
+    }
+}
+
+/// Build interleaved vertices and triangle indices of a mesh
+///
+/// Returns `(vertices, indices)` in the mesh's own coordinates.
+pub fn mesh_buffers(mesh: &ObjMesh) -> (Vec<f32>, Vec<u32>) {
+    // Corners without a normal are keyed by their face so each face gets
+    // its own vertices with the face normal
+    let mut lookup: HashMap<(ObjVertex, Option<usize>), u32> = HashMap::new();
+    let mut vertices = Vec::new();
+    let mut indices = Vec::with_capacity(mesh.triangle_count() * 3);
+
+    for (face_index, face) in mesh.faces.iter().enumerate() {
+        let face_normal = face_normal(mesh, &face.vertices);
+        let mut corners = Vec::with_capacity(face.vertices.len());
+        for v in &face.vertices {
+            let key = (*v, v.normal.is_none().then_some(face_index));
+            let index = *lookup.entry(key).or_insert_with(|| {
+                let normal = v.normal.map_or(face_normal, |n| mesh.normals[n]);
+                let uv = v.texcoord.map_or([0.0, 0.0], |t| mesh.texcoords[t]);
+                vertices.extend_from_slice(&mesh.positions[v.position]);
+                vertices.extend_from_slice(&normal);
+                vertices.extend_from_slice(&uv);
+                (vertices.len() / VERTEX_STRIDE - 1) as u32
+            });
+            corners.push(index);
+        }
+        for i in 1..corners.len() - 1 {
+            indices.extend_from_slice(&[corners[0], corners[i], corners[i + 1]]);
+        }
+    }
+    (vertices, indices)
+}
+
+/// Unit normal of a polygon (Newell's method), zero if degenerate
+fn face_normal(mesh: &ObjMesh, corners: &[ObjVertex]) -> [f32; 3] {
+    let p = |i: usize| mesh.positions[corners[i].position].map(f64::from);
+    let mut normal = [0.0f64; 3];
+    for i in 1..corners.len() - 1 {
+        let n = cross(sub(p(i), p(0)), sub(p(i + 1), p(0)));
+        normal = [normal[0] + n[0], normal[1] + n[1], normal[2] + n[2]];
+    }
+    let len = length(normal);
+    if len == 0.0 {
+        return [0.0; 3];
+    }
+    normal.map(|c| (c / len) as f32)
+}
+
+/// Transform positions and normals of interleaved vertices in place
+fn transform_vertices(vertices: &mut [f32], mat: &Mat4, normal_matrix: Option<&Mat3>) {
+    for vertex in vertices.chunks_exact_mut(VERTEX_STRIDE) {
+        let p = mat4_transform_point(mat, [vertex[0], vertex[1], vertex[2]]);
+        vertex[..3].copy_from_slice(&p);
+        if let Some(n) = normal_matrix {
+            let [x, y, z] = [vertex[3], vertex[4], vertex[5]];
+            let t = [
+                n[0] * x + n[3] * y + n[6] * z,
+                n[1] * x + n[4] * y + n[7] * z,
+                n[2] * x + n[5] * y + n[8] * z,
+            ];
+            let len = (t[0] * t[0] + t[1] * t[1] + t[2] * t[2]).sqrt();
+            if len > 0.0 {
+                vertex[3..6].copy_from_slice(&t.map(|c| c / len));
+            }
+        }
+    }
+}
+
+impl L3d {
+    /// Build GPU-ready vertex and index buffers for every part
+    ///
+    /// Each OBJ file is parsed once. Parts whose geometry is missing or
+    /// cannot be parsed are skipped; use [`L3d::stats`] to find them.
+    pub fn render_buffers(&self, options: &RenderOptions) -> Vec<RenderBuffer> {
+        let mut meshes: HashMap<&str, Option<RenderBuffer>> = HashMap::new();
+        let mut buffers = Vec::with_capacity(self.model.parts.len());
+
+        for (part_index, part) in self.model.parts.iter().enumerate() {
+            let mesh = meshes.entry(&part.path).or_insert_with(|| {
+                let asset = self.asset(&part.path)?;
+                let mut mesh = ObjMesh::from_bytes(&asset.content).ok()?;
+                if let Some(angle) = options.smooth_normals {
+                    let repair_options = RepairOptions {
+                        crease_angle: Some(angle),
+                        ..Default::default()
+                    };
+                    repair(&mut mesh, &repair_options);
+                }
+                let (vertices, indices) = mesh_buffers(&mesh);
+                Some(RenderBuffer {
+                    vertices,
+                    indices,
+                    ..Default::default()
+                })
+            });
+            let Some(mesh) = mesh else {
+                continue;
+            };
+
+            let mut buffer = RenderBuffer {
+                part_index,
+                name: part.name.clone(),
+                path: part.path.clone(),
+                transform: part.mat,
+                ..mesh.clone()
+            };
+            if options.space == TransformSpace::World {
+                transform_vertices(
+                    &mut buffer.vertices,
+                    &part.mat,
+                    mat4_normal_matrix(&part.mat).as_ref(),
+                );
+                // Mirroring turns front faces into back faces
+                if mat4_determinant(&part.mat) < 0.0 {
+                    for triangle in buffer.indices.chunks_exact_mut(3) {
+                        triangle.swap(1, 2);
+                    }
+                }
+                buffer.transform = MAT4_IDENTITY;
+            }
+            buffers.push(buffer);
+        }
+        buffers
+    }
+}
@@ -0,0 +1,354 @@
+//! # Mesh Repair
+//!
+//! Fixes common problems of manufacturer OBJ files that make luminaires
+//! render faceted, dark or inside out:
+//!
+//! - [`weld_vertices`] merges duplicate vertex positions within a tolerance
+//! - [`fix_winding`] makes face orientation consistent and outward facing
+//! - [`compute_normals`] generates smooth normals that keep sharp edges
+//!   above a crease angle
+//!
+//! All functions work in place on an [`ObjMesh`] and never add, remove or
+//! reorder faces, so face indices used by light emitting surfaces stay
+//! valid. [`repair`] runs all steps; [`repair_l3d`] applies it to every OBJ
+//! file of an archive.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use l3d_rs::from_buffer;
+//! use l3d_rs::repair::{repair_l3d, RepairOptions};
+//!
+//! let l3d = from_buffer(&std::fs::read("luminaire.l3d").unwrap());
+//! let repaired = repair_l3d(&l3d, &RepairOptions::default());
+//! std::fs::write("luminaire_fixed.l3d", repaired.to_bytes().unwrap()).unwrap();
+//! ```
+
+use crate::l3d::{L3d, L3dFile};
+use crate::obj::ObjMesh;
+use crate::vecmath::{cross, dot, length, sub};
+use std::collections::{HashMap, VecDeque};
+
+/// Settings for [`repair`]
+#[derive(Debug, Clone)]
+pub struct RepairOptions {
+    /// Merge positions closer than this distance (in OBJ file units)
+    ///
+    /// `None` skips welding.
+    pub weld_tolerance: Option<f32>,
+    /// Make face winding consistent and outward facing
+    pub fix_winding: bool,
+    /// Replace all normals with smooth normals, keeping edges sharper than
+    /// this angle in degrees
+    ///
+    /// `None` keeps the existing normals.
+    pub crease_angle: Option<f32>,
+}
+
+impl Default for RepairOptions {
+    fn default() -> Self {
+        Self {
+            weld_tolerance: Some(1e-5),
+            fix_winding: true,
+            crease_angle: Some(30.0),
+        }
+    }
+}
+
+/// What [`repair`] changed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RepairReport {
+    /// Number of positions merged into another one
+    pub welded_vertices: usize,
+    /// Number of faces whose winding was reversed
+    pub flipped_faces: usize,
+    /// Number of normals after regeneration, if normals were regenerated
+    pub normal_count: Option<usize>,
+}
+
+/// Run all repair steps enabled in `options`
+///
+/// Steps run in the order weld, winding, normals, so regenerated normals
+/// follow the corrected winding.
+pub fn repair(mesh: &mut ObjMesh, options: &RepairOptions) -> RepairReport {
+    let mut report = RepairReport::default();
+    if let Some(tolerance) = options.weld_tolerance {
+        report.welded_vertices = weld_vertices(mesh, tolerance);
+    }
+    if options.fix_winding {
+        report.flipped_faces = fix_winding(mesh);
+    }
+    if let Some(angle) = options.crease_angle {
+        compute_normals(mesh, angle);
+        report.normal_count = Some(mesh.normals.len());
+    }
+    report
+}
+
+/// Repair every OBJ file of an archive
+///
+/// `structure.xml` and all other assets are copied unchanged. Use
+/// [`L3dFile::to_bytes`] to write the result.
+pub fn repair_l3d(l3d: &L3d, options: &RepairOptions) -> L3dFile {
+    l3d.file.map_obj_assets(|mut mesh| {
+        repair(&mut mesh, options);
+        mesh
+    })
+}
+
+/// Merge vertex positions that are closer than `tolerance`
+///
+/// Faces are re-pointed to the first of each group of close positions and
+/// unused positions are removed. Returns the number of merged positions.
+pub fn weld_vertices(mesh: &mut ObjMesh, tolerance: f32) -> usize {
+    let tolerance = f64::from(tolerance.max(0.0));
+    let cell_size = if tolerance > 0.0 { tolerance } else { 1.0 };
+    let cell = |p: [f64; 3]| p.map(|c| (c / cell_size).floor() as i64);
+
+    // Representatives by grid cell; close positions are at most one cell apart
+    let mut grid: HashMap<[i64; 3], Vec<usize>> = HashMap::new();
+    let mut remap = Vec::with_capacity(mesh.positions.len());
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    for p in &mesh.positions {
+        let pd = p.map(f64::from);
+        let [cx, cy, cz] = cell(pd);
+        let mut found = None;
+        // Cells of huge coordinates saturate at the ends of the i64 range
+        'search: for x in cx.saturating_sub(1)..=cx.saturating_add(1) {
+            for y in cy.saturating_sub(1)..=cy.saturating_add(1) {
+                for z in cz.saturating_sub(1)..=cz.saturating_add(1) {
+                    for &rep in grid.get(&[x, y, z]).into_iter().flatten() {
+                        let d = sub(positions[rep].map(f64::from), pd);
+                        if dot(d, d) <= tolerance * tolerance {
+                            found = Some(rep);
+                            break 'search;
+                        }
+                    }
+                }
+            }
+        }
+        let index = found.unwrap_or_else(|| {
+            positions.push(*p);
+            grid.entry([cx, cy, cz])
+                .or_default()
+                .push(positions.len() - 1);
+            positions.len() - 1
+        });
+        remap.push(index);
+    }
+
+    let welded = mesh.positions.len() - positions.len();
+    for corner in mesh.faces.iter_mut().flat_map(|f| f.vertices.iter_mut()) {
+        corner.position = remap[corner.position];
+    }
+    mesh.positions = positions;
+    welded
+}
+
+/// Make the winding of connected faces consistent and outward facing
+///
+/// Faces sharing an edge are oriented so they traverse it in opposite
+/// directions. Each connected patch is then turned so its faces agree with
+/// the majority of existing normals or, without normals, so a closed patch
+/// encloses a positive volume. Returns the number of flipped faces.
+pub fn fix_winding(mesh: &mut ObjMesh) -> usize {
+    let face_count = mesh.faces.len();
+
+    // Directed edges of every face, to find neighbors across shared edges
+    let mut edge_faces: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for f in 0..face_count {
+        for (a, b) in oriented_edges(mesh, f, false) {
+            edge_faces.entry((a.min(b), a.max(b))).or_default().push(f);
+        }
+    }
+
+    let mut flip = vec![false; face_count];
+    let mut visited = vec![false; face_count];
+    let mut flipped = 0;
+    for seed in 0..face_count {
+        if visited[seed] {
+            continue;
+        }
+
+        // Propagate the seed's orientation over manifold edges
+        let mut patch = vec![seed];
+        let mut queue = VecDeque::from([seed]);
+        visited[seed] = true;
+        while let Some(f) = queue.pop_front() {
+            for (a, b) in oriented_edges(mesh, f, flip[f]) {
+                let Some(neighbors) = edge_faces.get(&(a.min(b), a.max(b))) else {
+                    continue;
+                };
+                let g = match neighbors[..] {
+                    [x, g] | [g, x] if x == f => g,
+                    _ => continue,
+                };
+                if visited[g] {
+                    continue;
+                }
+                // A consistent neighbor runs the shared edge as b -> a
+                flip[g] = oriented_edges(mesh, g, false).any(|e| e == (a, b));
+                visited[g] = true;
+                patch.push(g);
+                queue.push_back(g);
+            }
+        }
+
+        if patch_faces_inward(mesh, &patch, &flip) {
+            for &f in &patch {
+                flip[f] = !flip[f];
+            }
+        }
+        for &f in &patch {
+            if flip[f] {
+                mesh.faces[f].vertices.reverse();
+                flipped += 1;
+            }
+        }
+    }
+    flipped
+}
+
+/// Whether a consistently oriented patch points the wrong way
+fn patch_faces_inward(mesh: &ObjMesh, patch: &[usize], flip: &[bool]) -> bool {
+    let mut normal_votes = 0i64;
+    let mut signed_volume = 0.0;
+    for &f in patch {
+        let face_normal = face_normal(mesh, f, flip[f]);
+        let vertex_normal = mesh.faces[f]
+            .vertices
+            .iter()
+            .filter_map(|v| v.normal.and_then(|n| mesh.normals.get(n)))
+            .fold([0.0; 3], |acc, n| {
+                [
+                    acc[0] + f64::from(n[0]),
+                    acc[1] + f64::from(n[1]),
+                    acc[2] + f64::from(n[2]),
+                ]
+            });
+        let agreement = dot(face_normal, vertex_normal);
+        if agreement > 0.0 {
+            normal_votes += 1;
+        } else if agreement < 0.0 {
+            normal_votes -= 1;
+        }
+        // Volume of the fan triangles against the origin
+        let p = ordered_positions(mesh, f, flip[f]);
+        for i in 1..p.len().saturating_sub(1) {
+            signed_volume += dot(p[0], cross(p[i], p[i + 1]));
+        }
+    }
+    if normal_votes != 0 {
+        normal_votes < 0
+    } else {
+        signed_volume < 0.0 && patch_is_closed(mesh, patch)
+    }
+}
+
+fn patch_is_closed(mesh: &ObjMesh, patch: &[usize]) -> bool {
+    let mut counts: HashMap<(usize, usize), usize> = HashMap::new();
+    for &f in patch {
+        for (a, b) in oriented_edges(mesh, f, false) {
+            *counts.entry((a.min(b), a.max(b))).or_default() += 1;
+        }
+    }
+    counts.values().all(|&n| n == 2)
+}
+
+/// Generate smooth normals, keeping edges sharper than `crease_angle`
+///
+/// Each face corner gets the area-weighted average of the normals of the
+/// faces around its position whose angle to the corner's own face is at
+/// most `crease_angle` degrees. Existing normals are replaced; equal
+/// normals are shared between corners.
+pub fn compute_normals(mesh: &mut ObjMesh, crease_angle: f32) {
+    let cos_crease = f64::from(crease_angle).to_radians().cos();
+    let face_normals: Vec<[f64; 3]> = (0..mesh.faces.len())
+        .map(|f| face_normal(mesh, f, false))
+        .collect();
+    let unit: Vec<[f64; 3]> = face_normals
+        .iter()
+        .map(|&n| normalize(n).unwrap_or_default())
+        .collect();
+
+    let mut faces_at: Vec<Vec<usize>> = vec![Vec::new(); mesh.positions.len()];
+    for (f, face) in mesh.faces.iter().enumerate() {
+        for v in &face.vertices {
+            if faces_at[v.position].last() != Some(&f) {
+                faces_at[v.position].push(f);
+            }
+        }
+    }
+
+    let mut normals: Vec<[f32; 3]> = Vec::new();
+    let mut lookup: HashMap<[u32; 3], usize> = HashMap::new();
+    for f in 0..mesh.faces.len() {
+        for c in 0..mesh.faces[f].vertices.len() {
+            let position = mesh.faces[f].vertices[c].position;
+            let sum = faces_at[position]
+                .iter()
+                .filter(|&&g| g == f || dot(unit[f], unit[g]) >= cos_crease)
+                .fold([0.0; 3], |acc, &g| {
+                    let n = face_normals[g];
+                    [acc[0] + n[0], acc[1] + n[1], acc[2] + n[2]]
+                });
+            // Corners of degenerate faces get an arbitrary but valid normal
+            let n = normalize(sum).unwrap_or([0.0, 0.0, 1.0]).map(|c| c as f32);
+            let index = *lookup.entry(n.map(f32::to_bits)).or_insert_with(|| {
+                normals.push(n);
+                normals.len() - 1
+            });
+            mesh.faces[f].vertices[c].normal = Some(index);
+        }
+    }
+    mesh.normals = normals;
+}
+
+/// Directed edges of a face, reversed if `flip` is set
+fn oriented_edges(
+    mesh: &ObjMesh,
+    f: usize,
+    flip: bool,
+) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let v = &mesh.faces[f].vertices;
+    (0..v.len())
+        .map(move |i| {
+            let (a, b) = (v[i].position, v[(i + 1) % v.len()].position);
+            if flip {
+                (b, a)
+            } else {
+                (a, b)
+            }
+        })
+        .filter(|(a, b)| a != b)
+}
+
+fn ordered_positions(mesh: &ObjMesh, f: usize, flip: bool) -> Vec<[f64; 3]> {
+    let mut p: Vec<[f64; 3]> = mesh.faces[f]
+        .vertices
+        .iter()
+        .map(|v| mesh.positions[v.position].map(f64::from))
+        .collect();
+    if flip {
+        p.reverse();
+    }
+    p
+}
+
+/// Area-weighted face normal (Newell's method), valid for any polygon
+fn face_normal(mesh: &ObjMesh, f: usize, flip: bool) -> [f64; 3] {
+    let p = ordered_positions(mesh, f, flip);
+    let mut n = [0.0; 3];
+    for i in 0..p.len() {
+        let (a, b) = (p[i], p[(i + 1) % p.len()]);
+        n[0] += (a[1] - b[1]) * (a[2] + b[2]);
+        n[1] += (a[2] - b[2]) * (a[0] + b[0]);
+        n[2] += (a[0] - b[0]) * (a[1] + b[1]);
+    }
+    n.map(|c| c / 2.0)
+}
+
+fn normalize(v: [f64; 3]) -> Option<[f64; 3]> {
+    let len = length(v);
+    (len > 0.0).then(|| v.map(|c| c / len))
+}
@@ -0,0 +1,438 @@
+//! # Mesh Simplification
+//!
+//! Reduces the triangle count of dense OBJ geometry for web delivery, using
+//! quadric error metrics (Garland & Heckbert). Edges are collapsed onto one
+//! of their end points in order of the geometric error they introduce, so
+//! the remaining vertices, normals and texture coordinates are taken from the
+//! original mesh.
+//!
+//! Collapses that would flip a triangle or make the surface non-manifold are
+//! skipped, and open borders are kept in place by default. Vertices that are
+//! duplicated in the OBJ file (e.g. along UV seams) are treated as separate
+//! vertices, so such seams behave like borders.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use l3d_rs::from_buffer;
+//! use l3d_rs::simplify::{simplify_l3d, SimplifyOptions};
+//!
+//! let l3d = from_buffer(&std::fs::read("luminaire.l3d").unwrap());
+//! let options = SimplifyOptions {
+//!     ratio: 0.25,
+//!     ..Default::default()
+//! };
+//! let reduced = simplify_l3d(&l3d, &options);
+//! std::fs::write("luminaire_web.l3d", reduced.to_bytes().unwrap()).unwrap();
+//! ```
+
+use crate::l3d::{L3d, L3dFile};
+use crate::obj::{ObjFace, ObjMesh, ObjVertex};
+use crate::vecmath::{cross, dot, sub};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashSet};
+
+/// Settings for [`simplify`]
+#[derive(Debug, Clone)]
+pub struct SimplifyOptions {
+    /// Fraction of triangles to keep, between 0 and 1
+    pub ratio: f32,
+    /// Stop before a collapse whose quadric error exceeds this value
+    ///
+    /// The error is a squared distance in the units of the OBJ file.
+    pub max_error: Option<f64>,
+    /// Never move vertices on open borders
+    pub preserve_borders: bool,
+}
+
+impl Default for SimplifyOptions {
+    fn default() -> Self {
+        Self {
+            ratio: 0.5,
+            max_error: None,
+            preserve_borders: true,
+        }
+    }
+}
+
+/// Simplify a mesh to roughly `options.ratio` of its triangles
+///
+/// The result contains only triangles; polygons are fan-triangulated first.
+/// Normals, texture coordinates and materials are kept per face corner.
+///
+/// # Example
+///
+/// ```
+/// use l3d_rs::simplify::{simplify, SimplifyOptions};
+/// use l3d_rs::ObjMesh;
+///
+/// // A flat 3x3 grid of quads
+/// let mut obj = String::new();
+/// for y in 0..4 {
+///     for x in 0..4 {
+///         obj += &format!("v {} {} 0\n", x, y);
+///     }
+/// }
+/// for y in 0..3 {
+///     for x in 0..3 {
+///         let i = y * 4 + x + 1;
+///         obj += &format!("f {} {} {} {}\n", i, i + 1, i + 5, i + 4);
+///     }
+/// }
+/// let mesh = ObjMesh::parse(&obj).unwrap();
+/// let simple = simplify(&mesh, &SimplifyOptions::default());
+/// assert!(simple.triangle_count() < mesh.triangle_count());
+/// ```
+pub fn simplify(mesh: &ObjMesh, options: &SimplifyOptions) -> ObjMesh {
+    let target = (mesh.triangle_count() as f64 * options.ratio.clamp(0.0, 1.0) as f64).ceil();
+    Simplifier::new(mesh, options).run(target as usize, options.max_error)
+}
+
+/// Generate levels of detail for a mesh
+///
+/// Returns one mesh per entry of `ratios`, each relative to the triangle
+/// count of the original. Each level is simplified from the previous one, so
+/// ratios should be given in decreasing order.
+pub fn generate_lods(mesh: &ObjMesh, ratios: &[f32], options: &SimplifyOptions) -> Vec<ObjMesh> {
+    let original = mesh.triangle_count() as f64;
+    let mut lods: Vec<ObjMesh> = Vec::with_capacity(ratios.len());
+    for &ratio in ratios {
+        let source = lods.last().unwrap_or(mesh);
+        let target = (original * ratio.clamp(0.0, 1.0) as f64).ceil() as usize;
+        lods.push(Simplifier::new(source, options).run(target, options.max_error));
+    }
+    lods
+}
+
+/// Simplify every OBJ file of an archive
+///
+/// `structure.xml` and all other assets are copied unchanged, so the
+/// hierarchy, transforms and part names stay the same. OBJ files that cannot
+/// be parsed are copied as they are. Use [`L3dFile::to_bytes`] to write the
+/// result.
+pub fn simplify_l3d(l3d: &L3d, options: &SimplifyOptions) -> L3dFile {
+    l3d.file.map_obj_assets(|mesh| simplify(&mesh, options))
+}
+
+// ============================================================================
+// Quadric error metric
+// ============================================================================
+
+/// Symmetric 4x4 error quadric, stored as its upper triangle
+#[derive(Debug, Clone, Copy, Default)]
+struct Quadric([f64; 10]);
+
+impl Quadric {
+    /// Quadric of the plane `a*x + b*y + c*z + d = 0`, scaled by `weight`
+    fn plane(a: f64, b: f64, c: f64, d: f64, weight: f64) -> Self {
+        Quadric(
+            [
+                a * a,
+                a * b,
+                a * c,
+                a * d,
+                b * b,
+                b * c,
+                b * d,
+                c * c,
+                c * d,
+                d * d,
+            ]
+            .map(|q| q * weight),
+        )
+    }
+
+    fn add(&mut self, other: &Quadric) {
+        for (q, o) in self.0.iter_mut().zip(other.0) {
+            *q += o;
+        }
+    }
+
+    /// Squared distance error of placing the vertex at `p`
+    fn error(&self, [x, y, z]: [f64; 3]) -> f64 {
+        let q = &self.0;
+        q[0] * x * x
+            + 2.0 * q[1] * x * y
+            + 2.0 * q[2] * x * z
+            + 2.0 * q[3] * x
+            + q[4] * y * y
+            + 2.0 * q[5] * y * z
+            + 2.0 * q[6] * y
+            + q[7] * z * z
+            + 2.0 * q[8] * z
+            + q[9]
+    }
+}
+
+/// Candidate collapse of vertex `from` onto vertex `to`
+#[derive(Debug, PartialEq)]
+struct Collapse {
+    cost: f64,
+    from: usize,
+    to: usize,
+    /// Versions of both vertices when the candidate was computed
+    stamp: (u32, u32),
+}
+
+impl Eq for Collapse {}
+
+impl Ord for Collapse {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Break ties by vertex so the result does not depend on hash order
+        self.cost
+            .total_cmp(&other.cost)
+            .then(self.from.cmp(&other.from))
+            .then(self.to.cmp(&other.to))
+    }
+}
+
+impl PartialOrd for Collapse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+struct Simplifier<'a> {
+    mesh: &'a ObjMesh,
+    positions: Vec<[f64; 3]>,
+    triangles: Vec<([ObjVertex; 3], Option<usize>)>,
+    alive: Vec<bool>,
+    /// Triangles around each vertex (may contain dead triangles)
+    adjacent: Vec<Vec<usize>>,
+    quadrics: Vec<Quadric>,
+    locked: Vec<bool>,
+    version: Vec<u32>,
+    heap: BinaryHeap<Reverse<Collapse>>,
+}
+
+impl<'a> Simplifier<'a> {
+    fn new(mesh: &'a ObjMesh, options: &SimplifyOptions) -> Self {
+        let positions: Vec<[f64; 3]> = mesh.positions.iter().map(|p| p.map(f64::from)).collect();
+        let triangles: Vec<_> = mesh
+            .triangles()
+            .map(|(face, corners)| (corners, mesh.faces[face].material))
+            .collect();
+
+        let n = positions.len();
+        let mut adjacent = vec![Vec::new(); n];
+        let mut quadrics = vec![Quadric::default(); n];
+        for (t, (corners, _)) in triangles.iter().enumerate() {
+            let [a, b, c] = corners.map(|v| v.position);
+            for v in [a, b, c] {
+                adjacent[v].push(t);
+            }
+            let normal = cross(
+                sub(positions[b], positions[a]),
+                sub(positions[c], positions[a]),
+            );
+            let len = dot(normal, normal).sqrt();
+            if len > 0.0 {
+                let [x, y, z] = normal.map(|c| c / len);
+                let d = -dot([x, y, z], positions[a]);
+                // Weight by area so small slivers do not dominate
+                let q = Quadric::plane(x, y, z, d, len / 2.0);
+                for v in [a, b, c] {
+                    quadrics[v].add(&q);
+                }
+            }
+        }
+
+        let mut locked = vec![false; n];
+        if options.preserve_borders {
+            for (a, b) in edges(&triangles)
+                .filter(|&(_, _, count)| count != 2)
+                .map(|(a, b, _)| (a, b))
+            {
+                locked[a] = true;
+                locked[b] = true;
+            }
+        }
+
+        let mut simplifier = Simplifier {
+            mesh,
+            positions,
+            alive: vec![true; triangles.len()],
+            triangles,
+            adjacent,
+            quadrics,
+            locked,
+            version: vec![0; n],
+            heap: BinaryHeap::new(),
+        };
+        for (a, b, _) in edges(&simplifier.triangles) {
+            simplifier.push_edge(a, b);
+        }
+        simplifier
+    }
+
+    /// Queue the cheaper direction of collapsing the edge `a`-`b`
+    fn push_edge(&mut self, a: usize, b: usize) {
+        let mut q = self.quadrics[a];
+        q.add(&self.quadrics[b]);
+        let candidates = [(a, b), (b, a)]
+            .into_iter()
+            .filter(|&(from, _)| !self.locked[from])
+            .map(|(from, to)| (q.error(self.positions[to]), from, to));
+        if let Some((cost, from, to)) = candidates.min_by(|x, y| x.0.total_cmp(&y.0)) {
+            self.heap.push(Reverse(Collapse {
+                cost,
+                from,
+                to,
+                stamp: (self.version[from], self.version[to]),
+            }));
+        }
+    }
+
+    fn run(mut self, target: usize, max_error: Option<f64>) -> ObjMesh {
+        let mut live = self.triangles.len();
+        while live > target {
+            let Some(Reverse(c)) = self.heap.pop() else {
+                break;
+            };
+            if c.stamp != (self.version[c.from], self.version[c.to]) {
+                continue;
+            }
+            if max_error.is_some_and(|max| c.cost > max) {
+                break;
+            }
+            if self.can_collapse(c.from, c.to) {
+                live -= self.collapse(c.from, c.to);
+            }
+        }
+        self.into_mesh()
+    }
+
+    fn live_triangles(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        self.adjacent[v].iter().copied().filter(|&t| self.alive[t])
+    }
+
+    fn neighbors(&self, v: usize) -> HashSet<usize> {
+        self.live_triangles(v)
+            .flat_map(|t| self.triangles[t].0.map(|c| c.position))
+            .filter(|&n| n != v)
+            .collect()
+    }
+
+    /// Check that collapsing `from` onto `to` keeps the mesh manifold and
+    /// does not flip any triangle
+    fn can_collapse(&self, from: usize, to: usize) -> bool {
+        // Link condition: the only shared neighbors are the opposite corners
+        // of the triangles on the collapsed edge
+        let shared_triangles = self
+            .live_triangles(from)
+            .filter(|&t| self.triangles[t].0.iter().any(|c| c.position == to))
+            .count();
+        if shared_triangles == 0 {
+            return false;
+        }
+        let from_neighbors = self.neighbors(from);
+        let shared_neighbors = self.neighbors(to).intersection(&from_neighbors).count();
+        if shared_neighbors > shared_triangles {
+            return false;
+        }
+
+        for t in self.live_triangles(from) {
+            let corners = self.triangles[t].0.map(|c| c.position);
+            if corners.contains(&to) {
+                continue;
+            }
+            let p = corners.map(|v| self.positions[v]);
+            let moved = corners.map(|v| self.positions[if v == from { to } else { v }]);
+            let before = cross(sub(p[1], p[0]), sub(p[2], p[0]));
+            let after = cross(sub(moved[1], moved[0]), sub(moved[2], moved[0]));
+            if dot(before, after) <= 0.0 {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Collapse `from` onto `to` and return the number of removed triangles
+    fn collapse(&mut self, from: usize, to: usize) -> usize {
+        let mut removed = 0;
+        let moved: Vec<usize> = self.live_triangles(from).collect();
+        for t in moved {
+            let corners = &mut self.triangles[t].0;
+            if corners.iter().any(|c| c.position == to) {
+                self.alive[t] = false;
+                removed += 1;
+            } else {
+                for c in corners.iter_mut().filter(|c| c.position == from) {
+                    c.position = to;
+                }
+                self.adjacent[to].push(t);
+            }
+        }
+        self.adjacent[from].clear();
+        self.adjacent[to].retain(|&t| self.alive[t]);
+
+        let q = self.quadrics[from];
+        self.quadrics[to].add(&q);
+        self.version[from] += 1;
+        self.version[to] += 1;
+        for n in self.neighbors(to) {
+            self.push_edge(to, n);
+        }
+        removed
+    }
+
+    /// Build the output mesh, dropping unused positions
+    fn into_mesh(self) -> ObjMesh {
+        let mut remap = vec![usize::MAX; self.positions.len()];
+        let mut positions = Vec::new();
+        let mut faces = Vec::new();
+        for ((corners, material), _) in self
+            .triangles
+            .into_iter()
+            .zip(self.alive)
+            .filter(|(_, alive)| *alive)
+        {
+            let vertices = corners
+                .map(|mut c| {
+                    if remap[c.position] == usize::MAX {
+                        remap[c.position] = positions.len();
+                        positions.push(self.mesh.positions[c.position]);
+                    }
+                    c.position = remap[c.position];
+                    c
+                })
+                .to_vec();
+            faces.push(ObjFace { vertices, material });
+        }
+
+        ObjMesh {
+            positions,
+            normals: self.mesh.normals.clone(),
+            texcoords: self.mesh.texcoords.clone(),
+            faces,
+            materials: self.mesh.materials.clone(),
+            material_libs: self.mesh.material_libs.clone(),
+        }
+    }
+}
+
+/// Undirected edges of a triangle list with the number of triangles using
+/// each, sorted by vertex indices
+fn edges(
+    triangles: &[([ObjVertex; 3], Option<usize>)],
+) -> impl Iterator<Item = (usize, usize, usize)> {
+    let mut all: Vec<(usize, usize)> = triangles
+        .iter()
+        .flat_map(|(corners, _)| {
+            let [a, b, c] = corners.map(|v| v.position);
+            [(a, b), (b, c), (c, a)]
+        })
+        .filter(|(a, b)| a != b)
+        .map(|(a, b)| (a.min(b), a.max(b)))
+        .collect();
+    all.sort_unstable();
+
+    let mut counted: Vec<(usize, usize, usize)> = Vec::new();
+    for (a, b) in all {
+        match counted.last_mut() {
+            Some(last) if (last.0, last.1) == (a, b) => last.2 += 1,
+            _ => counted.push((a, b, 1)),
+        }
+    }
+    counted.into_iter()
+}
@@ -0,0 +1,207 @@
+//! # Mesh Statistics
+//!
+//! Per-part and total geometry statistics for catalogue quality checks:
+//! vertex and triangle counts, degenerate triangles, open or non-manifold
+//! edges, missing normals, unreferenced vertices, surface area and, for
+//! closed meshes, enclosed volume.
+//!
+//! Counts describe the OBJ file as stored. Surface area and volume are
+//! measured after applying the part's world matrix, so they are in square
+//! and cubic meters regardless of the geometry file's units.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use l3d_rs::from_buffer;
+//!
+//! let l3d = from_buffer(&std::fs::read("luminaire.l3d").unwrap());
+//! let stats = l3d.stats();
+//! for part in &stats.parts {
+//!     if let Some(mesh) = &part.mesh {
+//!         println!("{}: {} triangles", part.name, mesh.triangle_count);
+//!     }
+//! }
+//! println!("total: {} triangles", stats.total.triangle_count);
+//! ```
+
+use crate::l3d::{mat4_transform_point, L3d, Mat4};
+use crate::obj::ObjMesh;
+use crate::vecmath::{cross, dot, length, sub};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Relative tolerance below which a triangle is considered to have no area
+const DEGENERATE_TOLERANCE: f64 = 1e-9;
+
+/// Statistics of a single mesh
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MeshStats {
+    /// Number of vertex positions
+    pub vertex_count: usize,
+    /// Number of polygonal faces as written in the OBJ file
+    pub face_count: usize,
+    /// Number of triangles after fan triangulation
+    pub triangle_count: usize,
+    /// Triangles with repeated vertices or (almost) zero area
+    pub degenerate_triangles: usize,
+    /// Edges used by only one face (holes or open borders)
+    pub boundary_edges: usize,
+    /// Edges shared by more than two faces
+    pub non_manifold_edges: usize,
+    /// Faces with at least one corner without a normal
+    pub faces_without_normals: usize,
+    /// Vertex positions not used by any face
+    pub unreferenced_vertices: usize,
+    /// Surface area in square meters
+    pub surface_area: f64,
+    /// Enclosed volume in cubic meters, if the mesh is closed and manifold
+    pub volume: Option<f64>,
+}
+
+impl MeshStats {
+    /// Compute statistics for a mesh placed with the given world matrix
+    pub fn compute(mesh: &ObjMesh, mat: &Mat4) -> Self {
+        let world: Vec<[f64; 3]> = mesh
+            .positions
+            .iter()
+            .map(|p| mat4_transform_point(mat, *p).map(f64::from))
+            .collect();
+
+        let mut stats = MeshStats {
+            vertex_count: mesh.positions.len(),
+            face_count: mesh.faces.len(),
+            triangle_count: mesh.triangle_count(),
+            ..Default::default()
+        };
+
+        // Undirected edges of the original polygons; fan diagonals are
+        // interior and would not tell anything about the surface
+        let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut referenced = vec![false; mesh.positions.len()];
+        for face in &mesh.faces {
+            let v = &face.vertices;
+            if v.iter().any(|c| c.normal.is_none()) {
+                stats.faces_without_normals += 1;
+            }
+            for (i, corner) in v.iter().enumerate() {
+                referenced[corner.position] = true;
+                let (a, b) = (corner.position, v[(i + 1) % v.len()].position);
+                if a != b {
+                    *edges.entry((a.min(b), a.max(b))).or_default() += 1;
+                }
+            }
+        }
+        stats.boundary_edges = edges.values().filter(|&&n| n == 1).count();
+        stats.non_manifold_edges = edges.values().filter(|&&n| n > 2).count();
+        stats.unreferenced_vertices = referenced.iter().filter(|&&r| !r).count();
+
+        let mut signed_volume = 0.0;
+        for (_, [a, b, c]) in mesh.triangles() {
+            let (p0, p1, p2) = (world[a.position], world[b.position], world[c.position]);
+            let normal = cross(sub(p1, p0), sub(p2, p0));
+            let double_area = length(normal);
+            let longest = [sub(p1, p0), sub(p2, p1), sub(p0, p2)]
+                .iter()
+                .map(|e| dot(*e, *e))
+                .fold(0.0, f64::max);
+            if a.position == b.position
+                || b.position == c.position
+                || c.position == a.position
+                || double_area <= DEGENERATE_TOLERANCE * longest
+            {
+                stats.degenerate_triangles += 1;
+            }
+            stats.surface_area += double_area / 2.0;
+            signed_volume += dot(p0, cross(p1, p2)) / 6.0;
+        }
+
+        if stats.face_count > 0 && stats.boundary_edges == 0 && stats.non_manifold_edges == 0 {
+            stats.volume = Some(signed_volume.abs());
+        }
+        stats
+    }
+
+    /// Add the counts, area and volume of another mesh
+    ///
+    /// The volume stays known only if both meshes are closed.
+    fn accumulate(&mut self, other: &MeshStats) {
+        self.vertex_count += other.vertex_count;
+        self.face_count += other.face_count;
+        self.triangle_count += other.triangle_count;
+        self.degenerate_triangles += other.degenerate_triangles;
+        self.boundary_edges += other.boundary_edges;
+        self.non_manifold_edges += other.non_manifold_edges;
+        self.faces_without_normals += other.faces_without_normals;
+        self.unreferenced_vertices += other.unreferenced_vertices;
+        self.surface_area += other.surface_area;
+        self.volume = self.volume.zip(other.volume).map(|(a, b)| a + b);
+    }
+}
+
+/// Statistics of one part of the model
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartStats {
+    /// Part name from `structure.xml`
+    pub name: String,
+    /// Path of the geometry file within the archive
+    pub path: String,
+    /// Mesh statistics, if the geometry could be read
+    pub mesh: Option<MeshStats>,
+    /// Why the geometry could not be read
+    pub error: Option<String>,
+}
+
+/// Statistics of a whole model
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModelStats {
+    /// One entry per part, in model order
+    pub parts: Vec<PartStats>,
+    /// Sum over all readable parts
+    ///
+    /// `volume` is only set if every readable part is closed.
+    pub total: MeshStats,
+}
+
+impl L3d {
+    /// Compute mesh statistics for every part and for the whole model
+    ///
+    /// Parts referencing the same OBJ file parse it only once. Parts whose
+    /// geometry is missing or invalid are listed with an error and left out
+    /// of the total.
+    pub fn stats(&self) -> ModelStats {
+        let mut meshes: HashMap<&str, Result<ObjMesh, String>> = HashMap::new();
+        let mut total = MeshStats {
+            volume: Some(0.0),
+            ..Default::default()
+        };
+        let mut parts = Vec::with_capacity(self.model.parts.len());
+
+        for part in &self.model.parts {
+            let mesh = meshes.entry(&part.path).or_insert_with(|| {
+                let asset = self
+                    .asset(&part.path)
+                    .ok_or_else(|| format!("geometry file {} not found", part.path))?;
+                ObjMesh::from_bytes(&asset.content).map_err(|e| format!("{:#}", e))
+            });
+            let (mesh, error) = match mesh {
+                Ok(mesh) => {
+                    let stats = MeshStats::compute(mesh, &part.mat);
+                    total.accumulate(&stats);
+                    (Some(stats), None)
+                }
+                Err(e) => (None, Some(e.clone())),
+            };
+            parts.push(PartStats {
+                name: part.name.clone(),
+                path: part.path.clone(),
+                mesh,
+                error,
+            });
+        }
+
+        if parts.iter().all(|p| p.mesh.is_none()) {
+            total.volume = None;
+        }
+        ModelStats { parts, total }
+    }
+}
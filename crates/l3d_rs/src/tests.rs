@@ -1,5 +1,14 @@
-use crate::from_buffer;
 use crate::l3d::Luminaire;
+use crate::{
+    batch, build_transform, diff, diff::LeoShape, dmat4_mul, dmat4_scale, dmat4_translation,
+    from_buffer, from_buffer_with_options, mat4_compose, mat4_decompose, mat4_determinant,
+    mat4_from_quat, mat4_inverse, mat4_mul, mat4_normal_matrix, mat4_rotate_x, mat4_rotate_z,
+    mat4_scale, mat4_transform_direction, mat4_transform_point, mat4_translation, mat4_transpose,
+    quat_from_axis_angle, quat_to_axis_angle, repair, simplify, validate, xml_compare, AssetIndex,
+    Axis, BuildOptions, Change, ContentHash, CoordinateSystem, MeshStats, ObjFace, ObjMesh,
+    ObjVertex, Ray, RenderOptions, RotationOrder, TransformSpace, Vec3f, XmlCompareOptions,
+    XmlDifferenceKind, MAT4_IDENTITY, VERTEX_STRIDE,
+};
 
 /// Minimal L3D test file included in the repository
 const TEST_L3D_PATH: &str = "tests/data/minimal.l3d";
@@ -24,6 +33,21 @@ fn test_l3d_load_and_json_roundtrip() {
     assert_eq!(json_data, json_data_roundtrip, "JSON roundtrip failed");
 }
 
+#[test]
+fn test_json_omits_missing_optionals_and_accepts_nulls() {
+    let loaded = Luminaire::load_l3d(TEST_L3D_PATH).unwrap();
+    let json = loaded.to_json().unwrap();
+    assert!(!json.contains("null"), "missing optionals are omitted");
+
+    // 0.2 wrote missing optionals as explicit nulls
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let header = value["Header"].as_object_mut().unwrap();
+    header.insert("Name".to_string(), serde_json::Value::Null);
+    header.insert("Description".to_string(), serde_json::Value::Null);
+    let old = Luminaire::from_json(&value.to_string()).unwrap();
+    assert_eq!(old.to_json().unwrap(), json);
+}
+
 #[test]
 fn test_from_buffer_parses_model() {
     // Load the L3D file as bytes
@@ -55,3 +79,1557 @@ fn test_from_buffer_parses_model() {
     // Verify assets were loaded
     assert!(!l3d.file.assets.is_empty(), "Should have asset files");
 }
+
+/// Overwrite the declared uncompressed size of `entry` in both its local
+/// header and the central directory
+fn patch_zip_entry_size(zip: &mut [u8], entry: &str, size: u32) {
+    let u32_at = |zip: &[u8], i: usize| u32::from_le_bytes(zip[i..i + 4].try_into().unwrap());
+    let u16_at = |zip: &[u8], i: usize| u16::from_le_bytes([zip[i], zip[i + 1]]) as usize;
+    let mut patched = 0;
+    for i in 0..zip.len() - 46 {
+        // Offsets of the size, name length and name fields
+        let (size_at, name_len_at, name_at) = match u32_at(zip, i) {
+            0x0403_4b50 => (i + 22, i + 26, i + 30),
+            0x0201_4b50 => (i + 24, i + 28, i + 46),
+            _ => continue,
+        };
+        let name_len = u16_at(zip, name_len_at);
+        if zip.get(name_at..name_at + name_len) == Some(entry.as_bytes()) {
+            zip[size_at..size_at + 4].copy_from_slice(&size.to_le_bytes());
+            patched += 1;
+        }
+    }
+    assert_eq!(patched, 2, "{} not found in archive", entry);
+}
+
+#[test]
+fn test_declared_entry_size_is_not_trusted() {
+    let l3d = from_buffer(&std::fs::read(TEST_L3D_PATH).unwrap());
+    let obj = &l3d.file.assets[0];
+    let mut bytes = l3d.file.to_bytes().unwrap();
+    patch_zip_entry_size(&mut bytes, &obj.name, 0xFFFF_FFF0);
+
+    // Reserving the declared 4 GiB up front aborts where memory is not
+    // overcommitted, e.g. on wasm32
+    let parsed = crate::try_from_buffer(&bytes).unwrap();
+    let read = parsed.asset(&obj.name).unwrap();
+    assert_eq!(read.content, obj.content);
+    assert_eq!(read.size, obj.content.len() as u64);
+    assert!(read.content.capacity() <= 64 << 20);
+}
+
+#[test]
+fn test_from_xml_handles_bom_crlf_and_namespaced_root() {
+    let xml = "\u{FEFF}<?xml version=\"1.0\"?>\r\n\
+        <Luminaire xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\"\r\n  \
+        xsi:noNamespaceSchemaLocation=\"https://gldf.io/xsd/l3d/0.9/l3d.xsd\">\r\n\
+        <Header><CreatedWithApplication>test</CreatedWithApplication>\
+        <CreationTimeCode>2024-01-01T00:00:00Z</CreationTimeCode></Header>\r\n\
+        <GeometryDefinitions><GeometryFileDefinition id=\"g\" filename=\"a.obj\" units=\"m\"/></GeometryDefinitions>\r\n\
+        <Structure><Geometry partName=\"body\"><Position x=\"0\" y=\"0\" z=\"0\"/>\
+        <Rotation x=\"0\" y=\"0\" z=\"0\"/><GeometryReference geometryId=\"g\"/></Geometry></Structure>\r\n\
+        </Luminaire>\r\n";
+
+    let sanitized = Luminaire::sanitize_xml_str(xml);
+    assert!(!sanitized.starts_with('\u{FEFF}'), "BOM should be removed");
+    assert!(!sanitized.contains('\r'), "CRLF should be normalized");
+    assert!(
+        sanitized.contains("<Luminaire>"),
+        "root attributes should be stripped"
+    );
+
+    let luminaire = Luminaire::from_xml(xml).expect("Failed to parse sanitized XML");
+    assert_eq!(luminaire.structure.geometry.part_name, "body");
+}
+
+#[test]
+fn test_obj_parse_polygons_and_relative_indices() {
+    let obj = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nvn 0 0 1\nf 1//1 2//1 3//1 4//1\nf -4 -3 -2\n";
+    let mesh = ObjMesh::parse(obj).expect("Failed to parse OBJ");
+
+    assert_eq!(mesh.faces.len(), 2);
+    assert_eq!(mesh.triangle_count(), 3);
+    assert_eq!(mesh.faces[1].vertices[0].position, 0);
+    assert_eq!(mesh.faces[0].vertices[0].normal, Some(0));
+    assert_eq!(mesh.bounds(), Some(([0.0, 0.0, 0.0], [1.0, 1.0, 0.0])));
+
+    assert!(ObjMesh::parse("v 0 0 0\nf 1 2 3\n").is_err());
+}
+
+#[test]
+fn test_validate_reports_broken_references() {
+    let l3d_bytes = std::fs::read(TEST_L3D_PATH).expect("Failed to read L3D file");
+    let l3d = from_buffer(&l3d_bytes);
+    assert!(validate(&l3d).is_empty(), "minimal.l3d should be valid");
+
+    let mut broken = l3d;
+    broken.file.structure = broken
+        .file
+        .structure
+        .replace("geometryId=\"geom_1\"", "geometryId=\"nope\"");
+    broken.file.assets.clear();
+
+    let codes: Vec<_> = validate(&broken).into_iter().map(|d| d.code).collect();
+    assert!(codes.contains(&"unknown-geometry-id".to_string()));
+    assert!(codes.contains(&"missing-geometry-file".to_string()));
+    assert!(codes.contains(&"unused-geometry-definition".to_string()));
+}
+
+#[test]
+fn test_batch_process_directory() {
+    let report = batch::process_directory("tests/data", &batch::BatchOptions::default())
+        .expect("Failed to process directory");
+
+    let file = report
+        .files
+        .iter()
+        .find(|f| f.path.ends_with("minimal.l3d"))
+        .expect("minimal.l3d should be part of the report");
+    assert_eq!(file.status, batch::FileStatus::Ok);
+    assert_eq!(file.part_count, 1);
+
+    // 20mm cube
+    let [x, y, z] = file.dimensions.expect("dimensions should be computed");
+    assert!((x - 0.02).abs() < 1e-6 && (y - 0.02).abs() < 1e-6 && (z - 0.02).abs() < 1e-6);
+
+    let csv = report.to_csv();
+    assert!(csv.starts_with("path,status,parts"));
+    assert!(csv.contains(",ok,1,1,"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_batch_does_not_follow_directory_symlinks() {
+    let dir = std::env::temp_dir().join(format!("l3d_rs-batch-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("sub")).unwrap();
+    std::fs::copy(TEST_L3D_PATH, dir.join("sub/minimal.l3d")).unwrap();
+    std::os::unix::fs::symlink("..", dir.join("sub/parent")).unwrap();
+
+    let report = batch::process_directory(&dir, &batch::BatchOptions::default());
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(report.unwrap().files.len(), 1);
+}
+
+#[test]
+fn test_compare_xml_roundtrip_is_equal() {
+    let raw = Luminaire::get_xml_str_from_l3d(TEST_L3D_PATH.into()).expect("Failed to read XML");
+    let generated = Luminaire::from_xml(&raw)
+        .and_then(|l| l.to_xml())
+        .expect("Failed to round-trip XML");
+
+    let diffs = Luminaire::compare_xml(&raw, &generated).expect("Failed to compare XML");
+    assert!(diffs.is_empty(), "unexpected differences: {:?}", diffs);
+}
+
+#[test]
+fn test_compare_xml_detects_changed_position() {
+    let raw = Luminaire::get_xml_str_from_l3d(TEST_L3D_PATH.into()).expect("Failed to read XML");
+    let mut luminaire = Luminaire::from_xml(&raw).expect("Failed to parse XML");
+    luminaire.structure.geometry.position.z = 0.5;
+    let generated = luminaire.to_xml().expect("Failed to serialize XML");
+
+    let diffs = Luminaire::compare_xml(&raw, &generated).expect("Failed to compare XML");
+    assert_eq!(diffs.len(), 1, "{:?}", diffs);
+    assert_eq!(
+        diffs[0].path,
+        "/Luminaire/Structure/Geometry[@partName='body']/Position"
+    );
+    assert_eq!(
+        diffs[0].kind,
+        XmlDifferenceKind::AttributeValue {
+            name: "z".to_string(),
+            expected: "0".to_string(),
+            actual: "0.5".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_compare_xml_detects_missing_joint() {
+    let joint = r#"<Joints><Joint partName="arm"><Position x="0" y="0" z="1"/><Rotation x="0" y="0" z="0"/><Geometries><Geometry partName="head"/></Geometries></Joint></Joints>"#;
+    let with_joint = format!(
+        r#"<Luminaire><Structure><Geometry partName="body">{joint}</Geometry></Structure></Luminaire>"#
+    );
+    let without_joint =
+        r#"<Luminaire><Structure><Geometry partName="body"></Geometry></Structure></Luminaire>"#;
+
+    let diffs = xml_compare::compare_xml(&with_joint, without_joint, &XmlCompareOptions::default())
+        .expect("Failed to compare XML");
+    assert_eq!(diffs.len(), 1, "{:?}", diffs);
+    assert_eq!(
+        diffs[0].path,
+        "/Luminaire/Structure/Geometry[@partName='body']/Joints"
+    );
+    assert!(matches!(
+        diffs[0].kind,
+        XmlDifferenceKind::MissingElement { .. }
+    ));
+}
+
+#[test]
+fn test_compare_xml_ignores_attribute_order_and_number_format() {
+    let a = r#"<Position x="1" y="0.1" z="-2"/>"#;
+    let b = r#"
+        <Position z="-2.0" x="1.0000000001" y="0.1" />
+    "#;
+    let diffs = xml_compare::compare_xml(a, b, &XmlCompareOptions::default())
+        .expect("Failed to compare XML");
+    assert!(diffs.is_empty(), "unexpected differences: {:?}", diffs);
+}
+
+#[test]
+fn test_diff_reports_semantic_changes() {
+    let l3d_bytes = std::fs::read(TEST_L3D_PATH).expect("Failed to read L3D file");
+    let old = from_buffer(&l3d_bytes);
+    assert!(diff(&old, &from_buffer(&l3d_bytes)).unwrap().is_empty());
+
+    let mut new = from_buffer(&l3d_bytes);
+    new.file.structure = new
+        .file
+        .structure
+        .replacen(
+            r#"<Position x="0" y="0" z="0" />"#,
+            r#"<Position x="0" y="0" z="5" />"#,
+            1,
+        )
+        .replace(r#"<Circle diameter="10" />"#, r#"<Circle diameter="12" />"#);
+    new.file.assets[0].content.extend_from_slice(b"v 0 0 0\n");
+
+    let changes = diff(&old, &new).expect("Failed to diff").changes;
+    assert!(changes.contains(&Change::PartMoved {
+        part_name: "body".to_string(),
+        from: [0.0, 0.0, 0.0],
+        to: [0.0, 0.0, 5.0],
+    }));
+    assert!(changes.iter().any(|c| matches!(
+        c,
+        Change::LeoSizeChanged { to: LeoShape::Circle { diameter }, .. } if *diameter == 12.0
+    )));
+    assert!(changes.iter().any(|c| matches!(
+        c,
+        Change::FileChanged { from, to, .. }
+            if from.vertex_count == Some(8) && to.vertex_count == Some(9)
+    )));
+}
+
+#[test]
+fn test_diff_detects_renamed_part() {
+    let l3d_bytes = std::fs::read(TEST_L3D_PATH).expect("Failed to read L3D file");
+    let old = from_buffer(&l3d_bytes);
+    let mut new = from_buffer(&l3d_bytes);
+    new.file.structure = new
+        .file
+        .structure
+        .replace(r#"partName="body""#, r#"partName="housing""#);
+
+    let result = diff(&old, &new).expect("Failed to diff");
+    assert_eq!(
+        result.changes,
+        vec![Change::PartRenamed {
+            from: "body".to_string(),
+            to: "housing".to_string(),
+        }]
+    );
+    assert_eq!(result.to_string(), "~ part 'body' renamed to 'housing'\n");
+}
+
+#[test]
+fn test_diff_keeps_parts_with_duplicate_names() {
+    let bytes = std::fs::read(ROTATED_PARTS_PATH).unwrap();
+    let (mut old, mut new) = (from_buffer(&bytes), from_buffer(&bytes));
+    old.file.structure = old
+        .file
+        .structure
+        .replace(r#"partName="y90""#, r#"partName="x90""#);
+    new.file.structure = old
+        .file
+        .structure
+        .replacen(
+            r#"<Rotation x="90" y="0" z="0" />"#,
+            r#"<Rotation x="45" y="0" z="0" />"#,
+            1,
+        )
+        .replace(
+            r#"<Rotation x="0" y="90" z="0" />"#,
+            r#"<Rotation x="0" y="80" z="0" />"#,
+        );
+
+    // Both parts named "x90" are compared, by order of appearance
+    let changes = diff(&old, &new).unwrap().changes;
+    assert_eq!(
+        changes,
+        vec![
+            Change::PartRotated {
+                part_name: "x90".to_string(),
+                from: [90.0, 0.0, 0.0],
+                to: [45.0, 0.0, 0.0],
+            },
+            Change::PartRotated {
+                part_name: "x90#2".to_string(),
+                from: [0.0, 90.0, 0.0],
+                to: [0.0, 80.0, 0.0],
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_content_hash_is_stable() {
+    let hash = ContentHash::of(b"abc");
+    assert_eq!(
+        hash.to_string(),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert_eq!(hash.to_string().parse::<ContentHash>().unwrap(), hash);
+    assert_eq!(
+        serde_json::to_string(&hash).unwrap(),
+        format!("\"{}\"", hash)
+    );
+}
+
+#[test]
+fn test_asset_index_deduplicates_across_archives() {
+    let l3d_bytes = std::fs::read(TEST_L3D_PATH).expect("Failed to read L3D file");
+    let a = from_buffer(&l3d_bytes);
+    let mut b = from_buffer(&l3d_bytes);
+    b.file.assets[0].name = "geom_2/cube.obj".to_string();
+
+    let mut index = AssetIndex::new();
+    let hashes_a = index.add_archive("a.l3d", &a);
+    let hashes_b = index.add_archive("b.l3d", &b);
+
+    assert_eq!(hashes_a, hashes_b);
+    assert_eq!(index.unique_count(), 1);
+    let entry = index
+        .duplicates()
+        .next()
+        .expect("cube should be a duplicate");
+    assert_eq!(entry.locations.len(), 2);
+    assert_eq!(entry.locations[1].name, "geom_2/cube.obj");
+    assert_eq!(index.duplicate_bytes(), entry.size);
+
+    // Same geometry and transform hash equal, a moved part does not
+    let part = &a.model.parts[0];
+    assert_eq!(a.part_hash(part), from_buffer(&l3d_bytes).part_hash(part));
+    let mut moved = part.clone();
+    moved.mat[12] += 1.0;
+    assert_ne!(a.part_hash(part), a.part_hash(&moved));
+}
+
+#[test]
+fn test_stats_for_closed_cube_part() {
+    let l3d_bytes = std::fs::read(TEST_L3D_PATH).expect("Failed to read L3D file");
+    let stats = from_buffer(&l3d_bytes).stats();
+
+    assert_eq!(stats.parts.len(), 1);
+    assert_eq!(stats.parts[0].name, "body");
+    let cube = stats.parts[0]
+        .mesh
+        .as_ref()
+        .expect("cube should be readable");
+    assert_eq!(cube.vertex_count, 8);
+    assert_eq!(cube.triangle_count, 12);
+    assert_eq!(cube.boundary_edges, 0);
+    assert_eq!(cube.non_manifold_edges, 0);
+    assert_eq!(cube.faces_without_normals, 0);
+    // 20 mm cube, measured in meters
+    assert!((cube.surface_area - 0.0024).abs() < 1e-9);
+    assert!((cube.volume.unwrap() - 8e-6).abs() < 1e-11);
+    assert_eq!(stats.total, *cube);
+}
+
+#[test]
+fn test_stats_report_open_and_degenerate_geometry() {
+    let obj = "v 0 0 0\nv 1 0 0\nv 0 1 0\nv 2 0 0\nv 5 5 5\n\
+               f 1 2 3\nf 1 2 4\n";
+    let mesh = ObjMesh::parse(obj).unwrap();
+    let stats = MeshStats::compute(&mesh, &MAT4_IDENTITY);
+
+    assert_eq!(stats.degenerate_triangles, 1);
+    assert_eq!(stats.unreferenced_vertices, 1);
+    assert_eq!(stats.faces_without_normals, 2);
+    assert_eq!(stats.boundary_edges, 4);
+    assert_eq!(stats.volume, None);
+    assert!((stats.surface_area - 0.5).abs() < 1e-12);
+}
+
+/// Flat `n` x `n` grid of unit quads in the XY plane
+fn grid_obj(n: usize) -> String {
+    let mut obj = String::new();
+    for y in 0..=n {
+        for x in 0..=n {
+            obj += &format!("v {} {} 0\n", x, y);
+        }
+    }
+    obj += "vn 0 0 1\n";
+    for y in 0..n {
+        for x in 0..n {
+            let i = y * (n + 1) + x + 1;
+            obj += &format!(
+                "f {}//1 {}//1 {}//1 {}//1\n",
+                i,
+                i + 1,
+                i + n + 2,
+                i + n + 1
+            );
+        }
+    }
+    obj
+}
+
+#[test]
+fn test_simplify_flat_grid_keeps_border_and_area() {
+    let mesh = ObjMesh::parse(&grid_obj(10)).unwrap();
+    let options = simplify::SimplifyOptions {
+        ratio: 0.2,
+        ..Default::default()
+    };
+    let simple = simplify::simplify(&mesh, &options);
+
+    assert!(simple.triangle_count() <= 40);
+    assert_eq!(simple.bounds(), mesh.bounds());
+    let stats = MeshStats::compute(&simple, &MAT4_IDENTITY);
+    assert!((stats.surface_area - 100.0).abs() < 1e-6);
+    assert_eq!(stats.degenerate_triangles, 0);
+    assert_eq!(stats.non_manifold_edges, 0);
+    assert_eq!(stats.faces_without_normals, 0);
+
+    // Written OBJ parses back into the same mesh
+    assert_eq!(ObjMesh::parse(&simple.to_obj()).unwrap(), simple);
+
+    let lods = simplify::generate_lods(&mesh, &[0.5, 0.1], &options);
+    assert_eq!(lods.len(), 2);
+    assert!(lods[0].triangle_count() <= 100);
+    assert!(lods[1].triangle_count() < lods[0].triangle_count());
+}
+
+#[test]
+fn test_simplify_l3d_writes_archive_with_same_structure() {
+    let l3d_bytes = std::fs::read(TEST_L3D_PATH).expect("Failed to read L3D file");
+    let mut l3d = from_buffer(&l3d_bytes);
+    l3d.file.assets[0].content = grid_obj(8).into_bytes();
+
+    let reduced = simplify::simplify_l3d(&l3d, &simplify::SimplifyOptions::default());
+    let bytes = reduced.to_bytes().unwrap();
+    let reloaded = from_buffer(&bytes);
+
+    assert_eq!(reloaded.file.structure, l3d.file.structure);
+    assert_eq!(reloaded.model.parts.len(), l3d.model.parts.len());
+    assert_eq!(reloaded.model.parts[0].mat, l3d.model.parts[0].mat);
+    let mesh = ObjMesh::from_bytes(&reloaded.file.assets[0].content).unwrap();
+    assert!(mesh.triangle_count() <= 64);
+}
+
+#[test]
+fn test_repair_cube_with_split_faces() {
+    // Cube whose faces each have their own vertices and no normals, with
+    // one face wound the wrong way
+    let l3d_bytes = std::fs::read(TEST_L3D_PATH).expect("Failed to read L3D file");
+    let l3d = from_buffer(&l3d_bytes);
+    let cube = ObjMesh::from_bytes(&l3d.file.assets[0].content).unwrap();
+    let mut split = ObjMesh::default();
+    for (i, face) in cube.faces.iter().enumerate() {
+        let mut corners: Vec<_> = face
+            .vertices
+            .iter()
+            .map(|v| cube.positions[v.position])
+            .collect();
+        if i == 0 {
+            corners.reverse();
+        }
+        let base = split.positions.len();
+        split.positions.extend(corners);
+        split.faces.push(ObjFace {
+            vertices: (0..3)
+                .map(|k| ObjVertex {
+                    position: base + k,
+                    texcoord: None,
+                    normal: None,
+                })
+                .collect(),
+            material: None,
+        });
+    }
+
+    let report = repair::repair(&mut split, &repair::RepairOptions::default());
+
+    assert_eq!(report.welded_vertices, 36 - 8);
+    assert_eq!(split.positions.len(), 8);
+    assert_eq!(split.faces.len(), 12);
+    // The cube is wound inward; without normals the volume decides
+    assert_eq!(report.flipped_faces, 11);
+    // Sharp 90 degree edges keep one normal per side
+    assert_eq!(report.normal_count, Some(6));
+    let stats = MeshStats::compute(&split, &MAT4_IDENTITY);
+    assert_eq!(stats.boundary_edges, 0);
+    assert_eq!(stats.faces_without_normals, 0);
+
+    // All corners of the bottom face point down
+    let bottom = split.faces[1].vertices[0].normal.unwrap();
+    assert_eq!(split.normals[bottom], [0.0, 0.0, -1.0]);
+}
+
+#[test]
+fn test_weld_vertices_with_huge_coordinates() {
+    // Cells of these positions lie at the ends of the i64 range
+    let obj = b"v 1e30 0 0\nv 1e30 0 0\nv -1e30 1 0\nv -1e30 1 0\nv 0 0 1\nf 1 3 5\nf 2 4 5\n";
+    let mut mesh = ObjMesh::from_bytes(obj).unwrap();
+    assert_eq!(repair::weld_vertices(&mut mesh, 1e-5), 2);
+    assert_eq!(mesh.positions.len(), 3);
+    assert_eq!(mesh.faces[0].vertices, mesh.faces[1].vertices);
+}
+
+#[test]
+fn test_repair_follows_existing_normals_and_smooths_curves() {
+    let l3d_bytes = std::fs::read(TEST_L3D_PATH).expect("Failed to read L3D file");
+    let l3d = from_buffer(&l3d_bytes);
+
+    // The test cube is wound against its normals
+    let repaired = repair::repair_l3d(
+        &l3d,
+        &repair::RepairOptions {
+            crease_angle: None,
+            ..Default::default()
+        },
+    );
+    let mut mesh = ObjMesh::from_bytes(&repaired.assets[0].content).unwrap();
+    assert_eq!(mesh.faces[0].vertices[0].position, 2);
+    assert_eq!(repaired.structure, l3d.file.structure);
+
+    // A crease angle above 90 degrees smooths every cube corner
+    repair::compute_normals(&mut mesh, 100.0);
+    assert_eq!(mesh.normals.len(), 8);
+}
+
+#[test]
+fn test_build_options_coordinate_system() {
+    let l3d_bytes = std::fs::read(TEST_L3D_PATH).expect("Failed to read L3D file");
+    let z_up = from_buffer(&l3d_bytes);
+    let options = BuildOptions {
+        coordinate_system: CoordinateSystem::YUpRightHanded,
+        ..Default::default()
+    };
+    let y_up = from_buffer_with_options(&l3d_bytes, &options);
+
+    assert_eq!(
+        y_up.model.coordinate_system,
+        CoordinateSystem::YUpRightHanded
+    );
+    // Top of the 20 mm cube: 10 mm up in both conventions
+    let top = [0.0, 0.0, 10.0];
+    let close = |a: [f32; 3], b: [f32; 3]| (0..3).all(|i| (a[i] - b[i]).abs() < 1e-6);
+    assert!(close(
+        mat4_transform_point(&z_up.model.parts[0].mat, top),
+        [0.0, 0.0, 0.01]
+    ));
+    assert!(close(
+        mat4_transform_point(&y_up.model.parts[0].mat, top),
+        [0.0, 0.01, 0.0]
+    ));
+    assert_eq!(
+        y_up.model.parts[0].mat,
+        CoordinateSystem::YUpRightHanded.convert_matrix(&z_up.model.parts[0].mat)
+    );
+
+    // Left-handed conversion mirrors the geometry and reverses winding
+    let mut mesh =
+        ObjMesh::parse("v 1 2 3\nv 0 0 0\nv 0 1 0\nvn 0 0 1\nf 1//1 2//1 3//1\n").unwrap();
+    CoordinateSystem::YUpLeftHanded.convert_mesh(&mut mesh);
+    assert_eq!(mesh.positions[0], [1.0, 3.0, 2.0]);
+    assert_eq!(mesh.normals[0], [0.0, 1.0, 0.0]);
+    assert_eq!(mesh.faces[0].vertices[0].position, 2);
+}
+
+fn assert_mat_close(a: &[f32], b: &[f32]) {
+    assert_eq!(a.len(), b.len());
+    for (x, y) in a.iter().zip(b) {
+        assert!((x - y).abs() < 1e-5, "{:?} != {:?}", a, b);
+    }
+}
+
+#[test]
+fn test_mat4_inverse_and_normal_matrix() {
+    let pos = Vec3f {
+        x: 1.0,
+        y: -2.0,
+        z: 0.5,
+    };
+    let rot = Vec3f {
+        x: 30.0,
+        y: 45.0,
+        z: -60.0,
+    };
+    let m = mat4_mul(&build_transform(&pos, &rot), &mat4_scale(0.5));
+    let inv = mat4_inverse(&m).unwrap();
+    assert_mat_close(&mat4_mul(&m, &inv), &MAT4_IDENTITY);
+    assert_mat_close(&mat4_mul(&inv, &m), &MAT4_IDENTITY);
+    assert!(mat4_inverse(&mat4_scale(0.0)).is_none());
+
+    // Known inverse of a translated, scaled matrix
+    let ts = mat4_mul(&mat4_translation(2.0, 4.0, 6.0), &mat4_scale(2.0));
+    let expected = mat4_mul(&mat4_scale(0.5), &mat4_translation(-2.0, -4.0, -6.0));
+    assert_mat_close(&mat4_inverse(&ts).unwrap(), &expected);
+    assert!((mat4_determinant(&ts) - 8.0).abs() < 1e-6);
+
+    // Directions ignore translation
+    assert_mat_close(
+        &mat4_transform_direction(&ts, [1.0, 0.0, 0.0]),
+        &[2.0, 0.0, 0.0],
+    );
+
+    // Stretching along x shrinks the x component of normals
+    let mut squash = MAT4_IDENTITY;
+    squash[0] = 2.0;
+    let n = mat4_normal_matrix(&squash).unwrap();
+    assert_mat_close(&n, &[0.5, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+    assert_mat_close(&mat4_transpose(&mat4_transpose(&m)), &m);
+}
+
+#[test]
+fn test_mat4_inverse_of_far_mm_part() {
+    let assert_rel_close = |a: &[f32], b: &[f32]| {
+        for (x, y) in a.iter().zip(b) {
+            assert!(
+                (x - y).abs() <= 1e-6 * y.abs().max(1.0),
+                "{:?} != {:?}",
+                a,
+                b
+            );
+        }
+    };
+
+    // A part in millimeters far from the origin is not singular
+    for t in [1.0, 50.0, 200.0, 1.0e4, 1.0e5] {
+        let m = mat4_mul(&mat4_translation(t, -t, 0.5 * t), &mat4_scale(0.001));
+        let inv = mat4_inverse(&m).unwrap_or_else(|| panic!("no inverse at {} m", t));
+        let expected = mat4_mul(&mat4_scale(1000.0), &mat4_translation(-t, t, -0.5 * t));
+        assert_rel_close(&inv, &expected);
+        let n = mat4_normal_matrix(&m).unwrap_or_else(|| panic!("no normal matrix at {} m", t));
+        assert_rel_close(&n, &[1000.0, 0.0, 0.0, 0.0, 1000.0, 0.0, 0.0, 0.0, 1000.0]);
+    }
+
+    // Flattened parts stay singular however far away they are
+    let mut flat = mat4_translation(1.0e5, 0.0, 0.0);
+    flat[10] = 0.0;
+    assert!(mat4_inverse(&flat).is_none());
+    assert!(mat4_normal_matrix(&flat).is_none());
+}
+
+#[test]
+fn test_mat4_decompose_roundtrip() {
+    let rotation = mat4_mul(&mat4_rotate_z(90.0), &mat4_rotate_x(30.0));
+    let mut scale = mat4_scale(1.0);
+    scale[0] = 2.0;
+    scale[5] = 3.0;
+    scale[10] = 4.0;
+    let m = mat4_mul(
+        &mat4_mul(&mat4_translation(1.0, 2.0, 3.0), &rotation),
+        &scale,
+    );
+
+    let d = mat4_decompose(&m).unwrap();
+    assert_mat_close(&d.translation, &[1.0, 2.0, 3.0]);
+    assert_mat_close(&d.scale, &[2.0, 3.0, 4.0]);
+    assert_mat_close(&mat4_from_quat(d.rotation), &rotation);
+    assert_mat_close(&mat4_compose(&d), &m);
+
+    // Rotation of 90 degrees around Z only
+    let z = mat4_decompose(&mat4_rotate_z(90.0)).unwrap();
+    let half = std::f32::consts::FRAC_1_SQRT_2;
+    assert_mat_close(&z.rotation, &[0.0, 0.0, half, half]);
+
+    // Mirrored transforms keep the sign in the scale
+    let mut mirror = MAT4_IDENTITY;
+    mirror[0] = -1.0;
+    let d = mat4_decompose(&mirror).unwrap();
+    assert_eq!(d.scale, [-1.0, 1.0, 1.0]);
+    assert_mat_close(&mat4_compose(&d), &mirror);
+}
+
+/// structure.xml with a chain of `depth` geometries, each moved by `step`
+/// meters along its local x axis and turned by +90 / -90 degrees in turn
+fn nested_structure_xml(depth: usize, step: &str) -> String {
+    let mut xml = String::from(
+        "<Luminaire><Header><CreatedWithApplication>test</CreatedWithApplication>\
+         <CreationTimeCode>2024-01-01T00:00:00Z</CreationTimeCode></Header>\
+         <GeometryDefinitions><GeometryFileDefinition id=\"g\" filename=\"a.obj\" units=\"m\"/>\
+         </GeometryDefinitions><Structure>",
+    );
+    for i in 0..depth {
+        let angle = if i % 2 == 0 { 90 } else { -90 };
+        xml += &format!(
+            "<Geometry partName=\"part_{i}\"><Position x=\"{step}\" y=\"0\" z=\"0\"/>\
+             <Rotation x=\"0\" y=\"0\" z=\"{angle}\"/><GeometryReference geometryId=\"g\"/>"
+        );
+        if i + 1 < depth {
+            xml += &format!(
+                "<Joints><Joint partName=\"joint_{i}\"><Position x=\"0\" y=\"0\" z=\"0\"/>\
+                 <Rotation x=\"0\" y=\"0\" z=\"0\"/><Geometries>"
+            );
+        }
+    }
+    for i in (0..depth).rev() {
+        xml += "</Geometry>";
+        if i > 0 {
+            xml += "</Geometries></Joint></Joints>";
+        }
+    }
+    xml + "</Structure></Luminaire>"
+}
+
+#[test]
+fn test_double_precision_deep_joint_chain() {
+    let depth = 64;
+    // Positions are parsed as f32; everything after that is f64
+    let step = f64::from("10.123456789".parse::<f32>().unwrap());
+    let luminaire = Luminaire::from_xml(&nested_structure_xml(depth, "10.123456789")).unwrap();
+    let options = BuildOptions {
+        double_precision: true,
+        ..Default::default()
+    };
+    let model = crate::build_model(&luminaire, &options, crate::Log::default());
+
+    // Parts are pushed children first, so the deepest part comes first
+    let deepest = &model.parts[0];
+    assert_eq!(deepest.name, format!("part_{}", depth - 1));
+    let expected = step * (depth / 2) as f64;
+    let world = deepest.world_matrix_f64();
+    assert!((world[12] - expected).abs() < 1e-9, "{}", world[12]);
+    assert!((world[13] - expected).abs() < 1e-9, "{}", world[13]);
+    assert_eq!(world[14], 0.0);
+
+    // The f32 matrix is the rounded f64 result, not an accumulated error
+    assert_eq!(deepest.mat[12], world[12] as f32);
+    assert_eq!(deepest.mat[13], world[13] as f32);
+
+    // Without the option the f64 matrix is not kept but still available
+    let model = crate::build_model(&luminaire, &BuildOptions::default(), crate::Log::default());
+    assert!(model.parts[0].mat_f64.is_none());
+    assert_eq!(
+        model.parts[0].world_matrix_f64()[12],
+        deepest.mat[12] as f64
+    );
+    let json = serde_json::to_string(&model.parts[0]).unwrap();
+    assert!(!json.contains("mat_f64"));
+}
+
+/// Regression fixture with an axis marker (vertices at 1 m on X, 2 m on Y
+/// and 3 m on Z) placed under several rotations
+///
+/// Written for this crate, with the expected positions worked out by hand
+/// from `T * Rx * Ry * Rz`. It pins the rotation order against accidental
+/// changes but does not show conformance with other L3D readers.
+const ROTATED_PARTS_PATH: &str = "tests/data/rotated_parts.l3d";
+
+#[test]
+fn test_rotation_order_regression_file() {
+    let l3d = from_buffer(&std::fs::read(ROTATED_PARTS_PATH).unwrap());
+    let marker = ObjMesh::from_bytes(&l3d.file.assets[0].content).unwrap();
+
+    // Expected world positions of the three marker vertices per part
+    let expected: [(&str, [[f32; 3]; 3]); 8] = [
+        ("base", [[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]]),
+        ("x90", [[1.0, 0.0, 0.0], [0.0, 0.0, 2.0], [0.0, -3.0, 0.0]]),
+        ("y90", [[0.0, 0.0, -1.0], [0.0, 2.0, 0.0], [3.0, 0.0, 0.0]]),
+        ("z90", [[0.0, 1.0, 0.0], [-2.0, 0.0, 0.0], [0.0, 0.0, 3.0]]),
+        // Z is applied before X: +X turns into +Y, then into +Z
+        (
+            "x90_z90",
+            [[10.0, 0.0, 1.0], [8.0, 0.0, 0.0], [10.0, -3.0, 0.0]],
+        ),
+        (
+            "xyz",
+            [
+                [0.353_553_4, 5.926_777, 0.126_826_5],
+                [-1.224_745, 5.253_653, 1.560_66],
+                [2.121_32, 3.939_34, 1.837_117],
+            ],
+        ),
+        ("arm", [[0.0, 1.0, 1.0], [-2.0, 0.0, 1.0], [0.0, 0.0, 4.0]]),
+        // The head is rotated in the arm's frame, offset by the hinge
+        ("head", [[0.0, 2.0, 1.0], [0.0, 1.0, 3.0], [3.0, 1.0, 1.0]]),
+    ];
+    assert_eq!(l3d.model.parts.len(), expected.len());
+    for (name, points) in expected {
+        let part = l3d.model.parts.iter().find(|p| p.name == name).unwrap();
+        for (vertex, point) in marker.positions.iter().zip(points) {
+            let world = mat4_transform_point(&part.mat, *vertex);
+            for i in 0..3 {
+                assert!(
+                    (world[i] - point[i]).abs() < 1e-5,
+                    "{name}: {world:?} != {point:?}"
+                );
+            }
+        }
+    }
+
+    // Reading the file with another convention moves the combined part
+    let options = BuildOptions {
+        rotation_order: RotationOrder::Zyx,
+        ..Default::default()
+    };
+    let zyx = from_buffer_with_options(&std::fs::read(ROTATED_PARTS_PATH).unwrap(), &options);
+    let part = zyx
+        .model
+        .parts
+        .iter()
+        .find(|p| p.name == "x90_z90")
+        .unwrap();
+    let world = mat4_transform_point(&part.mat, [1.0, 0.0, 0.0]);
+    assert!((world[1] - 1.0).abs() < 1e-5, "{world:?}");
+}
+
+#[test]
+fn test_rotation_order_conversions() {
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+    let rot = Vec3f {
+        x: 30.0,
+        y: 45.0,
+        z: 60.0,
+    };
+    let orders = [
+        RotationOrder::Xyz,
+        RotationOrder::Xzy,
+        RotationOrder::Yxz,
+        RotationOrder::Yzx,
+        RotationOrder::Zxy,
+        RotationOrder::Zyx,
+    ];
+    for order in orders {
+        // Matrices, quaternions and Euler angles agree in every order
+        let m = order.matrix(&rot);
+        let from_quat = crate::dmat4_from_quat(order.quat(&rot));
+        assert!(
+            m.iter().zip(from_quat).all(|(a, b)| close(*a, b)),
+            "{order:?}"
+        );
+        // Euler angles are stored as f32
+        let same = |v: Vec3f| {
+            v.to_f64()
+                .iter()
+                .zip([30.0, 45.0, 60.0])
+                .all(|(a, b)| (a - b).abs() < 1e-4)
+        };
+        assert!(same(order.euler_from_matrix(&m)));
+        assert!(same(order.euler_from_quat(order.quat(&rot))));
+
+        // Gimbal lock still reproduces the same rotation
+        let mut locked = rot.clone();
+        match order.axes()[1] {
+            0 => locked.x = 90.0,
+            1 => locked.y = -90.0,
+            _ => locked.z = 90.0,
+        }
+        let m = order.matrix(&locked);
+        let again = order.matrix(&order.euler_from_matrix(&m));
+        assert!(m.iter().zip(again).all(|(a, b)| close(*a, b)), "{order:?}");
+    }
+
+    // The default order matches build_transform
+    let pos = Vec3f {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    let expected = build_transform(&pos, &rot);
+    let m = RotationOrder::Xyz.transform(&pos, &rot);
+    assert!(m
+        .iter()
+        .zip(expected)
+        .all(|(a, b)| (*a as f32 - b).abs() < 1e-6));
+
+    // Axis-angle roundtrip, with angles above 180 degrees flipping the axis
+    let (axis, angle) = quat_to_axis_angle(quat_from_axis_angle([0.0, 0.0, 2.0], 270.0));
+    assert!(close(axis[2], -1.0) && close(angle, 90.0));
+    let (axis, angle) = RotationOrder::Xyz.axis_angle(&Vec3f {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    });
+    assert_eq!((axis, angle), ([1.0, 0.0, 0.0], 0.0));
+}
+
+#[test]
+fn test_pick_cube_faces() {
+    let l3d = from_buffer(&std::fs::read(TEST_L3D_PATH).unwrap());
+    let scene = l3d.pick_scene();
+    assert_eq!(scene.triangle_count(), 12);
+
+    // The 20mm cube is centered on the origin; hit its -X side
+    let hit = scene
+        .pick(&Ray::new([-1.0, 0.001, 0.002], [2.0, 0.0, 0.0]))
+        .expect("ray should hit the cube");
+    assert_eq!(hit.part_name, "body");
+    assert_eq!(hit.part_index, 0);
+    assert!(hit.face_index == 8 || hit.face_index == 9);
+    assert!((hit.position[0] + 0.01).abs() < 1e-6);
+    assert!((hit.distance - 0.99).abs() < 1e-6);
+    assert_eq!(hit.normal, [-1.0, 0.0, 0.0]);
+
+    // From above the top faces are hit first
+    let hit = scene
+        .pick(&Ray::new([0.003, 0.002, 1.0], [0.0, 0.0, -1.0]))
+        .unwrap();
+    assert!(hit.face_index == 2 || hit.face_index == 3);
+    assert!((hit.position[2] - 0.01).abs() < 1e-6);
+
+    // Rays pointing away or passing by miss
+    assert!(scene
+        .pick(&Ray::new([-1.0, 0.0, 0.0], [-1.0, 0.0, 0.0]))
+        .is_none());
+    assert!(scene
+        .pick(&Ray::new([-1.0, 0.5, 0.0], [1.0, 0.0, 0.0]))
+        .is_none());
+}
+
+#[test]
+fn test_pick_grid_through_bvh() {
+    let mut l3d = from_buffer(&std::fs::read(TEST_L3D_PATH).unwrap());
+    l3d.file.assets[0].content = grid_obj(20).into_bytes();
+    let scene = l3d.pick_scene();
+    assert_eq!(scene.triangle_count(), 800);
+    let (min, max) = scene.bounds().unwrap();
+    assert_eq!(min, [0.0; 3]);
+    assert!((max[0] - 0.02).abs() < 1e-6 && (max[1] - 0.02).abs() < 1e-6);
+
+    // Each quad is one OBJ face; cell (x, y) is face y * 20 + x
+    for (x, y) in [(0, 0), (5, 12), (19, 19), (7, 3)] {
+        let origin = [(x as f32 + 0.3) * 0.001, (y as f32 + 0.6) * 0.001, 0.5];
+        let hit = scene.pick(&Ray::new(origin, [0.0, 0.0, -1.0])).unwrap();
+        assert_eq!(hit.face_index, y * 20 + x);
+        assert_eq!(hit.normal, [0.0, 0.0, 1.0]);
+    }
+
+    // Screen rays unproject through the inverse view-projection matrix
+    let ray = Ray::from_screen(&MAT4_IDENTITY, [0.5, 0.25]).unwrap();
+    assert_eq!(ray.origin, [0.5, 0.25, -1.0]);
+    assert_eq!(ray.direction, [0.0, 0.0, 2.0]);
+}
+
+#[test]
+fn test_render_buffers_local_and_world() {
+    let l3d = from_buffer(&std::fs::read(TEST_L3D_PATH).unwrap());
+    let local = l3d.render_buffers(&RenderOptions::default());
+    assert_eq!(local.len(), 1);
+    let buffer = &local[0];
+    assert_eq!(buffer.name, "body");
+    // Each cube side has its own normal: 6 sides with 4 corners each
+    assert_eq!(buffer.vertex_count(), 24);
+    assert_eq!(buffer.indices.len(), 36);
+    assert_eq!(buffer.transform, l3d.model.parts[0].mat);
+    assert_eq!(buffer.vertex_bytes().len(), 24 * VERTEX_STRIDE * 4);
+    assert_eq!(buffer.index_bytes().len(), 36 * 4);
+    assert!(buffer.vertices.iter().all(|v| v.abs() <= 10.0));
+
+    // Whether each triangle's winding agrees with its vertex normal
+    let facing = |buffer: &crate::RenderBuffer| -> Vec<bool> {
+        let vertex = |i: u32| &buffer.vertices[i as usize * VERTEX_STRIDE..][..VERTEX_STRIDE];
+        buffer
+            .indices
+            .chunks(3)
+            .map(|t| {
+                let [a, b, c] = [vertex(t[0]), vertex(t[1]), vertex(t[2])];
+                let e1 = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+                let e2 = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+                let n = [
+                    e1[1] * e2[2] - e1[2] * e2[1],
+                    e1[2] * e2[0] - e1[0] * e2[2],
+                    e1[0] * e2[1] - e1[1] * e2[0],
+                ];
+                n[0] * a[3] + n[1] * a[4] + n[2] * a[5] > 0.0
+            })
+            .collect()
+    };
+
+    // World space bakes in the unit scale and keeps the orientation of
+    // faces, also for mirroring conversions
+    for coordinate_system in [
+        CoordinateSystem::ZUpRightHanded,
+        CoordinateSystem::YUpLeftHanded,
+    ] {
+        let options = BuildOptions {
+            coordinate_system,
+            ..Default::default()
+        };
+        let l3d = from_buffer_with_options(&std::fs::read(TEST_L3D_PATH).unwrap(), &options);
+        let world = l3d.render_buffers(&RenderOptions {
+            space: TransformSpace::World,
+            ..Default::default()
+        });
+        assert_eq!(world[0].transform, MAT4_IDENTITY);
+        for vertex in world[0].vertices.chunks(VERTEX_STRIDE) {
+            // Cube corners are 10mm from each side along the normal
+            let outward = vertex[0] * vertex[3] + vertex[1] * vertex[4] + vertex[2] * vertex[5];
+            assert!((outward - 0.01).abs() < 1e-6, "{coordinate_system:?}");
+        }
+        assert_eq!(facing(&world[0]), facing(buffer), "{coordinate_system:?}");
+    }
+
+    // Smooth normals keep the cube's edges sharp below 90 degrees and
+    // share one vertex per corner above
+    let smooth = |angle| {
+        l3d.render_buffers(&RenderOptions {
+            smooth_normals: Some(angle),
+            ..Default::default()
+        })
+        .remove(0)
+    };
+    assert_eq!(smooth(30.0).vertex_count(), 24);
+    let rounded = smooth(100.0);
+    assert_eq!(rounded.vertex_count(), 8);
+    assert_eq!(rounded.indices.len(), 36);
+    for vertex in rounded.vertices.chunks(VERTEX_STRIDE) {
+        // Corner normals are unit length and point outward on every axis
+        let n = &vertex[3..6];
+        assert!((n.iter().map(|c| c * c).sum::<f32>() - 1.0).abs() < 1e-5);
+        assert!(n.iter().zip(&vertex[..3]).all(|(n, p)| n * p > 0.0));
+    }
+
+    // Faces without normals are shaded flat
+    let mesh = ObjMesh::parse("v 0 0 0\nv 1 0 0\nv 0 1 0\nv 0 0 1\nf 1 2 3\nf 1 4 2\n").unwrap();
+    let (vertices, indices) = crate::render::mesh_buffers(&mesh);
+    assert_eq!(indices, [0, 1, 2, 3, 4, 5]);
+    assert_eq!(&vertices[3..6], &[0.0, 0.0, 1.0]);
+    assert_eq!(&vertices[3 * VERTEX_STRIDE + 3..][..3], &[0.0, 1.0, 0.0]);
+}
+
+#[test]
+fn test_glb_export() {
+    let l3d = from_buffer(&std::fs::read(TEST_L3D_PATH).unwrap());
+    let glb = l3d.to_glb().unwrap();
+    assert_eq!(&glb[..4], b"glTF");
+    let word = |i: usize| u32::from_le_bytes(glb[i..i + 4].try_into().unwrap()) as usize;
+    assert_eq!(word(4), 2);
+    assert_eq!(word(8), glb.len());
+
+    let json_len = word(12);
+    let doc: serde_json::Value = serde_json::from_slice(&glb[20..20 + json_len]).unwrap();
+    let bin_len = word(20 + json_len);
+    assert_eq!(bin_len % 4, 0);
+    assert_eq!(doc["buffers"][0]["byteLength"], 24 * 32 + 36 * 4);
+    assert_eq!(doc["nodes"][0]["name"], "body");
+    assert_eq!(doc["accessors"][0]["count"], 24);
+    assert_eq!(
+        doc["accessors"][0]["max"],
+        serde_json::json!([10.0, 10.0, 10.0])
+    );
+    assert_eq!(doc["accessors"][3]["count"], 36);
+
+    // Z-up millimeters become Y-up meters
+    let matrix: Vec<f32> = serde_json::from_value(doc["nodes"][0]["matrix"].clone()).unwrap();
+    let up = mat4_transform_point(&matrix.try_into().unwrap(), [0.0, 0.0, 1000.0]);
+    assert_eq!(up, [0.0, 1.0, 0.0]);
+
+    // The result does not depend on the coordinate system of the model
+    let options = BuildOptions {
+        coordinate_system: CoordinateSystem::YUpLeftHanded,
+        ..Default::default()
+    };
+    let converted = from_buffer_with_options(&std::fs::read(TEST_L3D_PATH).unwrap(), &options);
+    assert_eq!(converted.to_glb().unwrap(), glb);
+
+    assert!(crate::L3d::default().to_glb().is_err());
+}
+
+#[test]
+fn test_glb_export_skips_empty_geometry() {
+    let mut l3d = from_buffer(&std::fs::read(ROTATED_PARTS_PATH).unwrap());
+    l3d.file.assets.push(crate::BufFile {
+        name: "empty/empty.obj".to_string(),
+        content: b"# no faces\n".to_vec(),
+        ..Default::default()
+    });
+    l3d.model.parts[0].path = "empty/empty.obj".to_string();
+
+    let glb = l3d.to_glb().unwrap();
+    let json_len = u32::from_le_bytes(glb[12..16].try_into().unwrap()) as usize;
+    let doc: serde_json::Value = serde_json::from_slice(&glb[20..20 + json_len]).unwrap();
+    assert_eq!(doc["meshes"].as_array().unwrap().len(), 1);
+    assert_eq!(doc["nodes"].as_array().unwrap().len(), l3d.model.parts.len() - 1);
+    let name = &l3d.model.parts[0].name;
+    assert!(doc["nodes"].as_array().unwrap().iter().all(|n| n["name"] != *name));
+    for accessor in doc["accessors"].as_array().unwrap() {
+        assert!(accessor["count"].as_u64().unwrap() > 0);
+    }
+
+    // Nothing left to export
+    for asset in &mut l3d.file.assets {
+        asset.content = b"# no faces\n".to_vec();
+    }
+    assert!(l3d.to_glb().is_err());
+}
+
+#[test]
+fn test_header_accessors() {
+    let l3d = from_buffer(&std::fs::read(TEST_L3D_PATH).unwrap());
+    let header = l3d.luminaire().unwrap().header;
+    assert_eq!(header.created_with_application(), "l3d-rs-test");
+    assert_eq!(header.creation_time_code(), "2024-01-01T00:00:00Z");
+    assert_eq!(header.name(), None);
+    assert_eq!(header.format_version(), None);
+
+    let luminaire = Luminaire::from_xml(
+        "<Luminaire><Header><Name>Spot</Name><Description>Track spot</Description>\
+         <CreatedWithApplication>test</CreatedWithApplication>\
+         <CreationTimeCode>2024-01-01T00:00:00Z</CreationTimeCode>\
+         <FormatVersion major=\"0\" minor=\"11\" pre-release=\"2\"/></Header>\
+         <GeometryDefinitions><GeometryFileDefinition id=\"g\" filename=\"g.obj\" units=\"m\"/>\
+         </GeometryDefinitions><Structure><Geometry partName=\"a\">\
+         <Position x=\"0\" y=\"0\" z=\"0\"/><Rotation x=\"0\" y=\"0\" z=\"0\"/>\
+         <GeometryReference geometryId=\"g\"/></Geometry></Structure></Luminaire>",
+    )
+    .unwrap();
+    assert_eq!(luminaire.header.name(), Some("Spot"));
+    assert_eq!(luminaire.header.description(), Some("Track spot"));
+    assert_eq!(luminaire.header.format_version().as_deref(), Some("0.11-2"));
+}
+
+#[test]
+fn test_light_emitters() {
+    let luminaire = Luminaire::load_l3d(TEST_L3D_PATH).unwrap();
+    let emitters = luminaire.light_emitters();
+    assert_eq!(emitters.len(), 1);
+    let leo = &emitters[0];
+    assert_eq!(
+        (leo.name.as_str(), leo.geometry_part_name.as_str()),
+        ("leo", "body")
+    );
+    assert_eq!(leo.shape, crate::EmitterShape::Circle);
+    assert_eq!(leo.shape.to_string(), "circle");
+    assert_eq!((leo.size_x, leo.size_y), (10.0, 0.0));
+    assert_eq!(
+        leo.position,
+        Vec3f {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0
+        }
+    );
+}
+
+#[test]
+fn test_edit_and_write_back() {
+    let mut l3d = from_buffer(&std::fs::read(ROTATED_PARTS_PATH).unwrap());
+    let head_z = l3d
+        .model
+        .parts
+        .iter()
+        .find(|p| p.name == "head")
+        .unwrap()
+        .mat[14];
+    let mut luminaire = l3d.luminaire().unwrap();
+    assert_eq!(luminaire.part_names().len(), 10);
+    assert!(luminaire.geometry("hinge").is_none());
+    assert!(luminaire.joint("hinge").is_some());
+
+    luminaire.geometry_mut("arm").unwrap().position.z = 2.0;
+    luminaire.joint_mut("hinge").unwrap().y_axis = Some(Axis {
+        min: -45.0,
+        max: 45.0,
+        step: 5.0,
+    });
+    luminaire.rename_part("head", "spot").unwrap();
+    assert!(luminaire.rename_part("head", "lamp").is_err());
+    assert!(luminaire.rename_part("spot", "arm").is_err());
+    l3d.set_luminaire(&luminaire, &BuildOptions::default())
+        .unwrap();
+    l3d.file.set_asset(
+        "marker/marker.obj",
+        b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n".to_vec(),
+    );
+
+    let reloaded = from_buffer(&l3d.file.to_bytes().unwrap());
+    let spot = reloaded
+        .model
+        .parts
+        .iter()
+        .find(|p| p.name == "spot")
+        .unwrap();
+    // The arm moved from z = 1 to z = 2 and carries the head along
+    assert!((spot.mat[14] - head_z - 1.0).abs() < 1e-6);
+    let luminaire = reloaded.luminaire().unwrap();
+    assert_eq!(
+        luminaire
+            .joint("hinge")
+            .unwrap()
+            .y_axis
+            .as_ref()
+            .unwrap()
+            .step,
+        5.0
+    );
+    assert_eq!(
+        reloaded.asset("marker/marker.obj").unwrap().size,
+        reloaded.asset("marker/marker.obj").unwrap().content.len() as u64
+    );
+    assert_eq!(reloaded.bounds(), l3d.bounds());
+}
+
+#[test]
+fn test_joint_angles() {
+    let mut l3d = from_buffer(&std::fs::read(ROTATED_PARTS_PATH).unwrap());
+    let head = |l3d: &crate::L3d| {
+        l3d.model
+            .parts
+            .iter()
+            .find(|p| p.name == "head")
+            .unwrap()
+            .mat
+    };
+    let rest = head(&l3d);
+    let mut luminaire = l3d.luminaire().unwrap();
+
+    // Without axis ranges the joint cannot move
+    let mut options = BuildOptions::default();
+    options.joint_angles.insert(
+        "hinge".to_string(),
+        Vec3f {
+            x: 10.0,
+            y: 20.0,
+            z: 30.0,
+        },
+    );
+    l3d.set_luminaire(&luminaire, &options).unwrap();
+    assert_eq!(head(&l3d), rest);
+
+    // Angles are clamped to the range and applied after the joint rotation
+    luminaire.joint_mut("hinge").unwrap().y_axis = Some(Axis {
+        min: -45.0,
+        max: 45.0,
+        step: 5.0,
+    });
+    options.joint_angles.insert(
+        "hinge".to_string(),
+        Vec3f {
+            x: 10.0,
+            y: 90.0,
+            z: 0.0,
+        },
+    );
+    l3d.set_luminaire(&luminaire, &options).unwrap();
+    let adjusted = head(&l3d);
+
+    luminaire.joint_mut("hinge").unwrap().rotation.y = 45.0;
+    l3d.set_luminaire(&luminaire, &BuildOptions::default())
+        .unwrap();
+    for (a, b) in adjusted.iter().zip(head(&l3d).iter()) {
+        assert!((a - b).abs() < 1e-6);
+    }
+    assert_ne!(adjusted, rest);
+}
+
+#[test]
+fn test_resolve_parts() {
+    for path in [TEST_L3D_PATH, ROTATED_PARTS_PATH] {
+        let l3d = from_buffer(&std::fs::read(path).unwrap());
+        let options = BuildOptions {
+            double_precision: true,
+            ..Default::default()
+        };
+        let parts = l3d.luminaire().unwrap().resolve_parts(&options);
+        let model = crate::build_model(&l3d.luminaire().unwrap(), &options, crate::Log::default());
+        assert_eq!(parts.len(), model.parts.len());
+
+        for (resolved, part) in parts.iter().zip(&model.parts) {
+            assert_eq!(resolved.name, part.name);
+            assert_eq!(resolved.path, part.path);
+            assert_eq!(Some(resolved.world), part.mat_f64);
+            assert_eq!(
+                resolved.world_position(),
+                [resolved.world[12], resolved.world[13], resolved.world[14]]
+            );
+
+            // The world matrix is composed of its resolved pieces
+            let composed = dmat4_mul(
+                &dmat4_mul(&resolved.parent, &resolved.local),
+                &dmat4_scale(resolved.scale),
+            );
+            for (a, b) in composed.iter().zip(&resolved.world) {
+                assert!((a - b).abs() < 1e-12, "{}: {} != {}", resolved.name, a, b);
+            }
+        }
+    }
+
+    // A geometry's parent frame is the frame of the geometry owning the
+    // joint, moved by the joint
+    let luminaire = Luminaire::load_l3d(ROTATED_PARTS_PATH).unwrap();
+    let parts = luminaire.resolve_parts(&BuildOptions::default());
+    let part = |name: &str| parts.iter().find(|p| p.name == name).unwrap();
+    let (arm, head) = (part("arm"), part("head"));
+    assert_eq!(parts.last().unwrap().name, "base");
+    assert_eq!(part("base").parent, MAT4_IDENTITY.map(f64::from));
+    assert_eq!(arm.position.z, 1.0);
+    let hinge = dmat4_mul(
+        &dmat4_mul(&arm.parent, &arm.local),
+        &dmat4_translation(1.0, 0.0, 0.0),
+    );
+    assert_eq!(head.parent, hinge);
+    assert_eq!(head.rotation.x, 90.0);
+
+    // The walk visits parents first and maps every geometry to its part
+    let visits = luminaire.walk_geometries();
+    assert_eq!(visits.len(), parts.len());
+    let names: Vec<_> = visits
+        .iter()
+        .map(|v| v.geometry.part_name.as_str())
+        .collect();
+    assert_eq!(names[..2], ["base", "x90"]);
+    for visit in &visits {
+        assert_eq!(parts[visit.part_index].name, visit.geometry.part_name);
+        let parent = visit.parent.map(|p| visits[p].geometry.part_name.as_str());
+        let joint = visit.joint.map(|j| j.part_name.as_str());
+        match visit.geometry.part_name.as_str() {
+            "base" => assert_eq!((parent, joint), (None, None)),
+            "head" => assert_eq!((parent, joint), (Some("arm"), Some("hinge"))),
+            _ => assert_eq!((parent, joint), (Some("base"), Some("mount"))),
+        }
+    }
+}
+
+fn part_matrices(l3d: &crate::L3d) -> Vec<(String, crate::Mat4)> {
+    l3d.model
+        .parts
+        .iter()
+        .map(|p| (p.name.clone(), p.mat))
+        .collect()
+}
+
+/// Records log messages, synchronously or asynchronously
+#[derive(Default)]
+struct RecordingLogger(std::sync::Mutex<Vec<String>>);
+
+impl crate::Logger for RecordingLogger {
+    fn log(&self, message: &str) {
+        self.0.lock().unwrap().push(message.to_string());
+    }
+}
+
+impl crate::AsyncLogger for RecordingLogger {
+    fn log(&self, message: &str) -> impl std::future::Future<Output = ()> + Send {
+        self.0.lock().unwrap().push(message.to_string());
+        std::future::ready(())
+    }
+}
+
+#[test]
+fn test_load_progress() {
+    use crate::load::{load, LoadOptions, LoadProgress, LoadStage};
+    use std::cell::RefCell;
+
+    let bytes = std::fs::read(ROTATED_PARTS_PATH).unwrap();
+    let reports = RefCell::new(Vec::new());
+    let observer = |p: &LoadProgress| reports.borrow_mut().push(*p);
+    let logger = RecordingLogger::default();
+    let options = LoadOptions {
+        observer: Some(&observer),
+        logger: Some(&logger),
+        ..Default::default()
+    };
+    let l3d = load(&bytes, &options).unwrap();
+    assert_eq!(part_matrices(&l3d), part_matrices(&from_buffer(&bytes)));
+
+    let reports = reports.into_inner();
+    let first = reports.first().unwrap();
+    let last = reports.last().unwrap();
+    assert_eq!(first.stage, LoadStage::ReadingArchive);
+    assert_eq!((first.entries_read, first.bytes_read), (0, 0));
+    assert_eq!(first.fraction(), 0.0);
+    assert_eq!(last.stage, LoadStage::Done);
+    assert_eq!(last.entries_read, last.entry_count);
+    assert_eq!(last.bytes_read, last.total_bytes);
+    assert_eq!(last.fraction(), 1.0);
+    assert!(last.entry_count >= 2 && last.total_bytes > 0);
+
+    // One report per entry plus one per stage
+    assert_eq!(reports.len(), last.entry_count + 4);
+    let stages: Vec<_> = reports.iter().map(|p| p.stage).collect();
+    let mut sorted = stages.clone();
+    sorted.sort_by_key(|s| *s as u8);
+    assert_eq!(stages, sorted);
+    assert!(reports
+        .windows(2)
+        .all(|w| w[0].bytes_read <= w[1].bytes_read));
+
+    let messages = logger.0.into_inner().unwrap();
+    assert_eq!(messages.len(), reports.len());
+    assert!(messages.iter().any(|m| m.contains("structure.xml")));
+    assert!(messages.last().unwrap().starts_with("done"));
+}
+
+#[test]
+fn test_load_cancel() {
+    use crate::load::{load, Cancelled, LoadObserver, LoadOptions, LoadProgress};
+    use std::cell::Cell;
+
+    /// Cancels after the first archive entry
+    struct CancelAfterFirstEntry(Cell<usize>);
+    impl LoadObserver for CancelAfterFirstEntry {
+        fn on_progress(&self, progress: &LoadProgress) {
+            self.0.set(progress.entries_read);
+        }
+        fn is_cancelled(&self) -> bool {
+            self.0.get() >= 1
+        }
+    }
+
+    let bytes = std::fs::read(TEST_L3D_PATH).unwrap();
+    let observer = CancelAfterFirstEntry(Cell::new(0));
+    let logger = RecordingLogger::default();
+    let options = LoadOptions {
+        observer: Some(&observer),
+        logger: Some(&logger),
+        ..Default::default()
+    };
+    let err = load(&bytes, &options).unwrap_err();
+    assert!(err.is::<Cancelled>());
+    assert_eq!(observer.0.get(), 1);
+    let messages = logger.0.into_inner().unwrap();
+    assert_eq!(messages.last().unwrap(), "loading was cancelled");
+
+    // Broken archives are errors, not cancellations
+    let err = load(b"not a zip", &LoadOptions::default()).unwrap_err();
+    assert!(!err.is::<Cancelled>());
+}
+
+#[test]
+fn test_load_reports_chunks_of_large_entries() {
+    use crate::load::{load, Cancelled, LoadObserver, LoadOptions, LoadProgress, CHUNK_SIZE};
+    use std::cell::RefCell;
+
+    // The large asset is the last entry of the archive
+    let mut l3d = from_buffer(&std::fs::read(TEST_L3D_PATH).unwrap());
+    let large = 2 * CHUNK_SIZE + CHUNK_SIZE / 2;
+    l3d.file.set_asset("large.bin", vec![0; large as usize]);
+    let bytes = l3d.file.to_bytes().unwrap();
+
+    /// Records reports and cancels once `limit` bytes are read
+    struct Recorder(RefCell<Vec<LoadProgress>>, u64);
+    impl LoadObserver for Recorder {
+        fn on_progress(&self, progress: &LoadProgress) {
+            self.0.borrow_mut().push(*progress);
+        }
+        fn is_cancelled(&self) -> bool {
+            self.0.borrow().last().unwrap().bytes_read >= self.1
+        }
+    }
+
+    let observer = Recorder(RefCell::new(Vec::new()), u64::MAX);
+    let options = LoadOptions {
+        observer: Some(&observer),
+        ..Default::default()
+    };
+    load(&bytes, &options).unwrap();
+    let reports = observer.0.into_inner();
+    let last = *reports.last().unwrap();
+    assert_eq!(last.bytes_read, last.total_bytes);
+
+    // Two reports within the large entry, counted in bytes actually read
+    let before = last.total_bytes - large;
+    let within: Vec<_> = reports
+        .iter()
+        .filter(|p| p.entries_read == last.entry_count - 1 && p.bytes_read > before)
+        .map(|p| p.bytes_read - before)
+        .collect();
+    assert_eq!(within, [CHUNK_SIZE, 2 * CHUNK_SIZE]);
+    assert_eq!(reports.len(), last.entry_count + 4 + 2);
+
+    // Cancelling takes effect in the middle of the entry
+    let observer = Recorder(RefCell::new(Vec::new()), before + CHUNK_SIZE);
+    let options = LoadOptions {
+        observer: Some(&observer),
+        ..Default::default()
+    };
+    let err = load(&bytes, &options).unwrap_err();
+    assert!(err.is::<Cancelled>());
+    let last = *observer.0.borrow().last().unwrap();
+    assert_eq!(last.entries_read, last.entry_count - 1);
+    assert_eq!(last.bytes_read, before + CHUNK_SIZE);
+}
+
+#[test]
+fn test_load_async() {
+    use crate::load::{load, load_async, LoadOptions};
+    use std::future::Future;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    struct NoopWaker;
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let bytes = std::fs::read(ROTATED_PARTS_PATH).unwrap();
+    let sync_logger = RecordingLogger::default();
+    let options = LoadOptions {
+        logger: Some(&sync_logger),
+        ..Default::default()
+    };
+    let async_logger = RecordingLogger::default();
+    let mut future = std::pin::pin!(load_async(&bytes, &options, &async_logger));
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let Poll::Ready(l3d) = future.as_mut().poll(&mut Context::from_waker(&waker)) else {
+        panic!("load_async waits although the logger never does");
+    };
+    assert_eq!(
+        part_matrices(&l3d.unwrap()),
+        part_matrices(&load(&bytes, &LoadOptions::default()).unwrap())
+    );
+
+    // Both loggers see the same messages
+    let messages = async_logger.0.lock().unwrap().clone();
+    assert!(messages.len() > 4);
+    assert_eq!(messages, *sync_logger.0.lock().unwrap());
+}
+
+#[test]
+fn test_from_buffer_with_logger_reports_problems() {
+    use crate::from_buffer_with_logger;
+
+    let mut l3d = from_buffer(&std::fs::read(TEST_L3D_PATH).unwrap());
+    let structure = l3d.file.structure.clone();
+    let parse = |l3d: &crate::L3d| {
+        let logger = RecordingLogger::default();
+        let parsed =
+            from_buffer_with_logger(&l3d.file.to_bytes().unwrap(), &Default::default(), &logger);
+        let messages = logger.0.lock().unwrap().clone();
+        (parsed, messages)
+    };
+
+    // A clean file produces no messages
+    let (_, messages) = parse(&l3d);
+    assert_eq!(messages, Vec::<String>::new());
+
+    // Recoverable problems are warnings and the model is still built
+    l3d.file.structure = format!(
+        "\u{FEFF}{}",
+        structure.replace(r#"units="mm""#, r#"units="ft""#)
+    );
+    l3d.file.set_asset("old/structure.xml", Vec::new());
+    let (parsed, messages) = parse(&l3d);
+    assert_eq!(parsed.model.parts.len(), 1);
+    assert_eq!(messages.len(), 3, "{:?}", messages);
+    assert!(messages.iter().all(|m| m.starts_with("warning: ")));
+    assert!(messages.iter().any(|m| m.contains("byte order mark")));
+    assert!(messages
+        .iter()
+        .any(|m| m.contains(r#""geom_1" has unknown units "ft", assuming meters"#)));
+    assert!(messages
+        .iter()
+        .any(|m| m.contains(r#""old/structure.xml""#)));
+
+    l3d.file.structure = structure.replace(r#"geometryId="geom_1""#, r#"geometryId="missing""#);
+    l3d.file.assets.retain(|a| a.name != "old/structure.xml");
+    let (parsed, messages) = parse(&l3d);
+    assert_eq!(parsed.model.parts[0].path, "");
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].contains(r#"unknown geometry ID "missing""#));
+
+    // Failures are errors
+    let logger = RecordingLogger::default();
+    let parsed = from_buffer_with_logger(b"not a zip", &Default::default(), &logger);
+    assert!(parsed.model.parts.is_empty());
+    let messages = logger.0.lock().unwrap().clone();
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].starts_with("error: failed to read L3D archive"));
+}
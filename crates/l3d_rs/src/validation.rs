@@ -0,0 +1,270 @@
+//! # L3D Validation
+//!
+//! Checks a parsed [`L3d`] for problems that make it render incorrectly or
+//! not at all: references to undefined geometries, OBJ files missing from
+//! the archive, unreadable geometry, unknown units and similar issues.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use l3d_rs::{from_buffer, validate, Severity};
+//!
+//! let l3d = from_buffer(&std::fs::read("luminaire.l3d").unwrap());
+//! for diagnostic in validate(&l3d) {
+//!     if diagnostic.severity == Severity::Error {
+//!         eprintln!("{}", diagnostic);
+//!     }
+//! }
+//! ```
+
+use crate::l3d::{Geometry, L3d, Luminaire};
+use crate::obj::ObjMesh;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+
+/// Units allowed by the L3D schema for geometry files
+pub(crate) const KNOWN_UNITS: [&str; 3] = ["m", "mm", "in"];
+
+/// How serious a validation finding is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Worth knowing, but the file renders correctly
+    Info,
+    /// The file is usable but probably not what the author intended
+    Warning,
+    /// The file (or a part of it) cannot be rendered correctly
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        })
+    }
+}
+
+/// A single validation finding
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    /// How serious the finding is
+    pub severity: Severity,
+    /// Stable, machine-readable identifier (e.g. `"unknown-geometry-id"`)
+    pub code: String,
+    /// Human-readable description
+    pub message: String,
+    /// Part name from `structure.xml` the finding relates to, if any
+    pub part_name: Option<String>,
+}
+
+impl Diagnostic {
+    fn new(severity: Severity, code: &str, message: String, part_name: Option<&str>) -> Self {
+        Self {
+            severity,
+            code: code.to_string(),
+            message,
+            part_name: part_name.map(str::to_string),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}]", self.severity, self.code)?;
+        if let Some(part) = &self.part_name {
+            write!(f, " {}", part)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Validate a parsed L3D file
+///
+/// Returns all findings, ordered by where they were found (geometry
+/// definitions first, then the structure tree). An empty list means the
+/// file is valid.
+pub fn validate(l3d: &L3d) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    if l3d.file.structure.is_empty() {
+        diagnostics.push(Diagnostic::new(
+            Severity::Error,
+            "missing-structure",
+            "archive contains no structure.xml".to_string(),
+            None,
+        ));
+        return diagnostics;
+    }
+
+    let luminaire = match l3d.luminaire() {
+        Ok(luminaire) => luminaire,
+        Err(e) => {
+            diagnostics.push(Diagnostic::new(
+                Severity::Error,
+                "invalid-xml",
+                format!("{:#}", e),
+                None,
+            ));
+            return diagnostics;
+        }
+    };
+
+    validate_definitions(l3d, &luminaire, &mut diagnostics);
+
+    let mut ctx = TreeContext {
+        luminaire: &luminaire,
+        part_names: HashSet::new(),
+        used_ids: HashSet::new(),
+    };
+    ctx.validate_geometry(&luminaire.structure.geometry, &mut diagnostics);
+
+    for def in &luminaire.geometry_definitions.geometry_file_definition {
+        if !ctx.used_ids.contains(def.id.as_str()) {
+            diagnostics.push(Diagnostic::new(
+                Severity::Info,
+                "unused-geometry-definition",
+                format!("geometry definition '{}' is never referenced", def.id),
+                None,
+            ));
+        }
+    }
+
+    diagnostics
+}
+
+/// Check geometry file definitions and the OBJ files they point to
+fn validate_definitions(l3d: &L3d, luminaire: &Luminaire, diagnostics: &mut Vec<Diagnostic>) {
+    let mut ids = HashSet::new();
+    for def in &luminaire.geometry_definitions.geometry_file_definition {
+        if !ids.insert(def.id.as_str()) {
+            diagnostics.push(Diagnostic::new(
+                Severity::Error,
+                "duplicate-geometry-id",
+                format!("geometry id '{}' is defined more than once", def.id),
+                None,
+            ));
+        }
+
+        if !KNOWN_UNITS.contains(&def.units.as_str()) {
+            diagnostics.push(Diagnostic::new(
+                Severity::Warning,
+                "unknown-units",
+                format!(
+                    "geometry '{}' uses unknown units '{}', assuming meters",
+                    def.id, def.units
+                ),
+                None,
+            ));
+        }
+
+        let path = format!("{}/{}", def.id, def.filename);
+        let Some(asset) = l3d.asset(&path) else {
+            diagnostics.push(Diagnostic::new(
+                Severity::Error,
+                "missing-geometry-file",
+                format!("geometry file '{}' is not in the archive", path),
+                None,
+            ));
+            continue;
+        };
+
+        match ObjMesh::from_bytes(&asset.content) {
+            Ok(mesh) if mesh.faces.is_empty() => diagnostics.push(Diagnostic::new(
+                Severity::Warning,
+                "empty-geometry",
+                format!("geometry file '{}' contains no faces", path),
+                None,
+            )),
+            Ok(_) => {}
+            Err(e) => diagnostics.push(Diagnostic::new(
+                Severity::Error,
+                "invalid-obj",
+                format!("geometry file '{}' cannot be parsed: {:#}", path, e),
+                None,
+            )),
+        }
+    }
+}
+
+/// State shared while walking the structure tree
+struct TreeContext<'a> {
+    luminaire: &'a Luminaire,
+    part_names: HashSet<&'a str>,
+    used_ids: HashSet<&'a str>,
+}
+
+impl<'a> TreeContext<'a> {
+    fn check_part_name(&mut self, name: &'a str, diagnostics: &mut Vec<Diagnostic>) {
+        if !self.part_names.insert(name) {
+            diagnostics.push(Diagnostic::new(
+                Severity::Warning,
+                "duplicate-part-name",
+                format!("part name '{}' is used more than once", name),
+                Some(name),
+            ));
+        }
+    }
+
+    fn validate_geometry(&mut self, geo: &'a Geometry, diagnostics: &mut Vec<Diagnostic>) {
+        self.check_part_name(&geo.part_name, diagnostics);
+
+        let id = geo.geometry_reference.geometry_id.as_str();
+        self.used_ids.insert(id);
+        let defs = &self.luminaire.geometry_definitions.geometry_file_definition;
+        if !defs.iter().any(|d| d.id == id) {
+            diagnostics.push(Diagnostic::new(
+                Severity::Error,
+                "unknown-geometry-id",
+                format!("references undefined geometry id '{}'", id),
+                Some(&geo.part_name),
+            ));
+        }
+
+        if let Some(leos) = &geo.light_emitting_objects {
+            for leo in leos.objects() {
+                self.check_part_name(&leo.part_name, diagnostics);
+                if leo.rectangle.is_none() && leo.circle.is_none() {
+                    diagnostics.push(Diagnostic::new(
+                        Severity::Warning,
+                        "leo-without-shape",
+                        "light emitting object has neither a rectangle nor a circle".to_string(),
+                        Some(&leo.part_name),
+                    ));
+                }
+            }
+        }
+
+        if let Some(joints) = &geo.joints {
+            for joint in &joints.joint {
+                self.check_part_name(&joint.part_name, diagnostics);
+                let axes = [
+                    ("X", &joint.x_axis),
+                    ("Y", &joint.y_axis),
+                    ("Z", &joint.z_axis),
+                ];
+                for (label, axis) in axes {
+                    if let Some(axis) = axis {
+                        if axis.min > axis.max {
+                            diagnostics.push(Diagnostic::new(
+                                Severity::Warning,
+                                "invalid-axis-range",
+                                format!(
+                                    "{} axis minimum {} is greater than maximum {}",
+                                    label, axis.min, axis.max
+                                ),
+                                Some(&joint.part_name),
+                            ));
+                        }
+                    }
+                }
+                for child in &joint.geometries.geometry {
+                    self.validate_geometry(child, diagnostics);
+                }
+            }
+        }
+    }
+}
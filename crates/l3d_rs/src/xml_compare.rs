@@ -0,0 +1,350 @@
+//! # Structural XML Comparison
+//!
+//! Compares two XML documents by structure rather than by text, so that
+//! formatting differences introduced by a serialization round-trip do not
+//! count as changes:
+//!
+//! - attribute order is ignored
+//! - whitespace between elements and around text is ignored
+//! - numbers are compared with a tolerance (`"1"` equals `"1.0"`)
+//! - namespace declarations and empty optional elements (`<Name/>`) can be
+//!   ignored
+//!
+//! Every difference is reported with the path of the element it was found
+//! at, e.g. `/Luminaire/Structure/Geometry[@partName='body']/Position`.
+//!
+//! # Example
+//!
+//! ```
+//! use l3d_rs::xml_compare::{compare_xml, XmlCompareOptions};
+//!
+//! let a = r#"<Position x="1" y="2" z="3"/>"#;
+//! let b = r#"<Position z="3.0" y="2" x="1.5"/>"#;
+//! let diffs = compare_xml(a, b, &XmlCompareOptions::default()).unwrap();
+//! assert_eq!(diffs.len(), 1);
+//! assert_eq!(diffs[0].path, "/Position");
+//! ```
+
+use anyhow::{bail, Context, Result};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Settings for [`compare_xml`]
+#[derive(Debug, Clone)]
+pub struct XmlCompareOptions {
+    /// Relative tolerance for numeric attribute values and text
+    ///
+    /// Two numbers `a` and `b` are equal if
+    /// `|a - b| <= float_tolerance * max(1, |a|, |b|)`.
+    pub float_tolerance: f64,
+    /// Treat elements without attributes, text or children as absent
+    pub ignore_empty_elements: bool,
+    /// Ignore `xmlns` declarations and `xsi:` schema attributes
+    pub ignore_namespace_attributes: bool,
+}
+
+impl Default for XmlCompareOptions {
+    fn default() -> Self {
+        Self {
+            float_tolerance: 1e-6,
+            ignore_empty_elements: true,
+            ignore_namespace_attributes: true,
+        }
+    }
+}
+
+/// What differs at a given element
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum XmlDifferenceKind {
+    /// Root elements have different names
+    ElementName { expected: String, actual: String },
+    /// Element exists only in the expected document
+    MissingElement { name: String },
+    /// Element exists only in the actual document
+    ExtraElement { name: String },
+    /// Attribute exists only in the expected document
+    MissingAttribute { name: String, expected: String },
+    /// Attribute exists only in the actual document
+    ExtraAttribute { name: String, actual: String },
+    /// Attribute exists in both documents with different values
+    AttributeValue {
+        name: String,
+        expected: String,
+        actual: String,
+    },
+    /// Element text differs
+    Text { expected: String, actual: String },
+}
+
+/// A single difference between two XML documents
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct XmlDifference {
+    /// Path of the element the difference was found at
+    pub path: String,
+    /// What differs
+    #[serde(flatten)]
+    pub kind: XmlDifferenceKind,
+}
+
+impl fmt::Display for XmlDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.path)?;
+        match &self.kind {
+            XmlDifferenceKind::ElementName { expected, actual } => {
+                write!(f, "element <{}> became <{}>", expected, actual)
+            }
+            XmlDifferenceKind::MissingElement { name } => write!(f, "missing element <{}>", name),
+            XmlDifferenceKind::ExtraElement { name } => write!(f, "unexpected element <{}>", name),
+            XmlDifferenceKind::MissingAttribute { name, expected } => {
+                write!(f, "missing attribute {}=\"{}\"", name, expected)
+            }
+            XmlDifferenceKind::ExtraAttribute { name, actual } => {
+                write!(f, "unexpected attribute {}=\"{}\"", name, actual)
+            }
+            XmlDifferenceKind::AttributeValue {
+                name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "attribute {} changed from \"{}\" to \"{}\"",
+                name, expected, actual
+            ),
+            XmlDifferenceKind::Text { expected, actual } => {
+                write!(f, "text changed from \"{}\" to \"{}\"", expected, actual)
+            }
+        }
+    }
+}
+
+/// Compare two XML documents structurally
+///
+/// Returns the list of differences, which is empty if the documents are
+/// equivalent. Fails only if one of the documents is not well-formed XML.
+pub fn compare_xml(
+    expected: &str,
+    actual: &str,
+    options: &XmlCompareOptions,
+) -> Result<Vec<XmlDifference>> {
+    let expected = parse_tree(expected, options).context("Failed to parse expected XML")?;
+    let actual = parse_tree(actual, options).context("Failed to parse actual XML")?;
+
+    let mut diffs = Vec::new();
+    let path = format!("/{}", expected.segment(None));
+    if expected.name != actual.name {
+        diffs.push(XmlDifference {
+            path,
+            kind: XmlDifferenceKind::ElementName {
+                expected: expected.name,
+                actual: actual.name,
+            },
+        });
+    } else {
+        compare_elements(&expected, &actual, &path, options, &mut diffs);
+    }
+    Ok(diffs)
+}
+
+/// Minimal DOM used for comparison
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    attributes: BTreeMap<String, String>,
+    text: String,
+    children: Vec<Element>,
+}
+
+impl Element {
+    fn is_empty(&self) -> bool {
+        self.attributes.is_empty() && self.text.is_empty() && self.children.is_empty()
+    }
+
+    /// Path segment, using the L3D `partName` or the sibling position to
+    /// tell apart elements with the same name
+    fn segment(&self, index: Option<usize>) -> String {
+        match (self.attributes.get("partName"), index) {
+            (Some(part), _) => format!("{}[@partName='{}']", self.name, part),
+            (None, Some(i)) => format!("{}[{}]", self.name, i + 1),
+            (None, None) => self.name.clone(),
+        }
+    }
+}
+
+fn parse_tree(xml: &str, options: &XmlCompareOptions) -> Result<Element> {
+    let xml = xml.strip_prefix('\u{FEFF}').unwrap_or(xml);
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut stack: Vec<Element> = Vec::new();
+    let mut root = None;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => stack.push(start_element(&e, options)?),
+            Event::Empty(e) => {
+                let element = start_element(&e, options)?;
+                close_element(element, &mut stack, &mut root, options);
+            }
+            Event::End(_) => {
+                let element = stack.pop().context("unbalanced end tag")?;
+                close_element(element, &mut stack, &mut root, options);
+            }
+            Event::Text(t) => {
+                if let Some(top) = stack.last_mut() {
+                    top.text.push_str(t.unescape()?.trim());
+                }
+            }
+            Event::CData(t) => {
+                if let Some(top) = stack.last_mut() {
+                    top.text.push_str(String::from_utf8_lossy(&t).trim());
+                }
+            }
+            Event::Eof => break,
+            // Declarations, comments, processing instructions and doctypes
+            // carry no luminaire data
+            _ => {}
+        }
+    }
+
+    if !stack.is_empty() {
+        bail!("unclosed element <{}>", stack[stack.len() - 1].name);
+    }
+    root.context("document has no root element")
+}
+
+fn start_element(e: &BytesStart, options: &XmlCompareOptions) -> Result<Element> {
+    let mut element = Element {
+        name: String::from_utf8_lossy(e.name().as_ref()).into_owned(),
+        ..Default::default()
+    };
+    for attr in e.attributes() {
+        let attr = attr?;
+        let key = String::from_utf8_lossy(attr.key.as_ref()).into_owned();
+        if options.ignore_namespace_attributes
+            && (key == "xmlns" || key.starts_with("xmlns:") || key.starts_with("xsi:"))
+        {
+            continue;
+        }
+        element
+            .attributes
+            .insert(key, attr.unescape_value()?.into_owned());
+    }
+    Ok(element)
+}
+
+fn close_element(
+    element: Element,
+    stack: &mut [Element],
+    root: &mut Option<Element>,
+    options: &XmlCompareOptions,
+) {
+    match stack.last_mut() {
+        Some(parent) => {
+            if !(options.ignore_empty_elements && element.is_empty()) {
+                parent.children.push(element);
+            }
+        }
+        None => *root = Some(element),
+    }
+}
+
+fn compare_elements(
+    expected: &Element,
+    actual: &Element,
+    path: &str,
+    options: &XmlCompareOptions,
+    diffs: &mut Vec<XmlDifference>,
+) {
+    let mut push = |kind| {
+        diffs.push(XmlDifference {
+            path: path.to_string(),
+            kind,
+        })
+    };
+
+    for (name, exp) in &expected.attributes {
+        match actual.attributes.get(name) {
+            None => push(XmlDifferenceKind::MissingAttribute {
+                name: name.clone(),
+                expected: exp.clone(),
+            }),
+            Some(act) if !values_equal(exp, act, options.float_tolerance) => {
+                push(XmlDifferenceKind::AttributeValue {
+                    name: name.clone(),
+                    expected: exp.clone(),
+                    actual: act.clone(),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    for (name, act) in &actual.attributes {
+        if !expected.attributes.contains_key(name) {
+            push(XmlDifferenceKind::ExtraAttribute {
+                name: name.clone(),
+                actual: act.clone(),
+            });
+        }
+    }
+    if !values_equal(&expected.text, &actual.text, options.float_tolerance) {
+        push(XmlDifferenceKind::Text {
+            expected: expected.text.clone(),
+            actual: actual.text.clone(),
+        });
+    }
+
+    // Pair children by name and position among same-named siblings, so a
+    // missing element does not shift every following comparison
+    let mut names: Vec<&str> = Vec::new();
+    for child in expected.children.iter().chain(&actual.children) {
+        if !names.contains(&child.name.as_str()) {
+            names.push(&child.name);
+        }
+    }
+    for name in names {
+        let exp: Vec<_> = expected
+            .children
+            .iter()
+            .filter(|c| c.name == name)
+            .collect();
+        let act: Vec<_> = actual.children.iter().filter(|c| c.name == name).collect();
+        let multiple = exp.len().max(act.len()) > 1;
+        for i in 0..exp.len().max(act.len()) {
+            let index = multiple.then_some(i);
+            match (exp.get(i), act.get(i)) {
+                (Some(e), Some(a)) => {
+                    let child_path = format!("{}/{}", path, e.segment(index));
+                    compare_elements(e, a, &child_path, options, diffs);
+                }
+                (Some(e), None) => diffs.push(XmlDifference {
+                    path: format!("{}/{}", path, e.segment(index)),
+                    kind: XmlDifferenceKind::MissingElement {
+                        name: e.name.clone(),
+                    },
+                }),
+                (None, Some(a)) => diffs.push(XmlDifference {
+                    path: format!("{}/{}", path, a.segment(index)),
+                    kind: XmlDifferenceKind::ExtraElement {
+                        name: a.name.clone(),
+                    },
+                }),
+                (None, None) => unreachable!(),
+            }
+        }
+    }
+}
+
+/// Compare two values, numerically if both parse as numbers
+fn values_equal(a: &str, b: &str, tolerance: f64) -> bool {
+    if a == b {
+        return true;
+    }
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(x), Ok(y)) => (x - y).abs() <= tolerance * 1f64.max(x.abs()).max(y.abs()),
+        _ => false,
+    }
+}